@@ -79,34 +79,54 @@ struct RemTUIApp {
                             // Error handling - TUI will show appropriate status
                         }
                         
-                    case .globalSearch(let query):
+                    case .previewList(let listId):
+                        // Same data as selectList; the TUI keeps the lists focused
                         do {
-                            let (allReminders, listNames) = try await remindersService.searchAllReminders(query: query)
-                            try setGlobalReminders(reminders: allReminders, listNames: listNames)
+                            let reminders = try await remindersService.fetchReminders(for: listId)
+                            try setReminders(reminders: reminders)
                         } catch {
                             // Error handling - TUI will show appropriate status
                         }
                         
-                    case .toggleReminder(let reminderId):
+                    case .globalSearch(let query):
                         do {
-                            try await remindersService.toggleReminder(reminderId)
+                            let (allReminders, listNames) = try await remindersService.searchAllReminders(query: query)
+                            try setGlobalReminders(reminders: allReminders, listNames: listNames)
                         } catch {
                             // Error handling - TUI will show appropriate status
                         }
                         
-                    case .deleteReminder(let reminderId):
+                    case .loadAllReminders:
                         do {
-                            try await remindersService.deleteReminder(reminderId)
+                            let (allReminders, listNames) = try await remindersService.searchAllReminders(query: "")
+                            try setAllReminders(reminders: allReminders, listNames: listNames)
                         } catch {
                             // Error handling - TUI will show appropriate status
                         }
                         
+                    case .toggleReminder(let reminderId):
+                        // The TUI already applied the toggle locally; confirm or roll it back.
+                        // Resolving outside a do/catch keeps a failed resolve from marking a
+                        // saved change as failed or escaping the loop.
+                        let ok = (try? await remindersService.toggleReminder(reminderId)) != nil
+                        try? resolvePendingChange(reminderId: reminderId, success: ok)
+                        
+                    case .deleteReminder(let reminderId):
+                        let ok = (try? await remindersService.deleteReminder(reminderId)) != nil
+                        try? resolvePendingChange(reminderId: reminderId, success: ok)
+                        
+                    case .moveReminder(let reminderId, let listId):
+                        let ok = (try? await remindersService.moveReminder(reminderId, toListId: listId)) != nil
+                        try? resolvePendingChange(reminderId: reminderId, success: ok)
+                        
+                    case .setPriority(let reminderId, let priority):
+                        let ok = (try? await remindersService.setPriority(reminderId, priority: priority)) != nil
+                        try? resolvePendingChange(reminderId: reminderId, success: ok)
+                        
                     case .createReminder(let newReminder):
-                        do {
-                            try await remindersService.createReminder(newReminder)
-                        } catch {
-                            // Error handling - TUI will show appropriate status
-                        }
+                        // Pass the saved id back so the placeholder row can be toggled or deleted
+                        let createdId = try? await remindersService.createReminder(newReminder)
+                        try? resolvePendingCreate(success: createdId != nil, reminderId: createdId)
                         
                     case .refresh:
                         do {
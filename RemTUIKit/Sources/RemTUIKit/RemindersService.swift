@@ -60,7 +60,7 @@ public class RemindersService: ObservableObject {
                         notes: ekReminder.notes,
                         completed: ekReminder.isCompleted,
                         priority: UInt8(ekReminder.priority),
-                        dueDate: ekReminder.dueDateComponents?.date?.ISO8601Format()
+                        dueDate: Self.dueDateString(from: ekReminder.dueDateComponents)
                     )
                 }
                 
@@ -93,6 +93,28 @@ public class RemindersService: ObservableObject {
         throw RemError.DataAccessError(message: "Reminder not found")
     }
     
+    public func moveReminder(_ reminderId: String, toListId listId: String) async throws {
+        guard let calendar = eventStore.calendar(withIdentifier: listId) else {
+            throw RemError.DataAccessError(message: "List not found")
+        }
+        let reminder = try await findReminder(reminderId)
+        reminder.calendar = calendar
+        try eventStore.save(reminder, commit: true)
+    }
+    
+    public func setPriority(_ reminderId: String, priority: UInt8) async throws {
+        let reminder = try await findReminder(reminderId)
+        reminder.priority = Int(priority)
+        try eventStore.save(reminder, commit: true)
+    }
+    
+    private func findReminder(_ reminderId: String) async throws -> EKReminder {
+        if let reminder = eventStore.calendarItem(withIdentifier: reminderId) as? EKReminder {
+            return reminder
+        }
+        throw RemError.DataAccessError(message: "Reminder not found")
+    }
+    
     public func deleteReminder(_ reminderId: String) async throws {
         // Fetch reminders from all calendars to find the one with matching ID
         let calendars = eventStore.calendars(for: .reminder)
@@ -115,7 +137,9 @@ public class RemindersService: ObservableObject {
         throw RemError.DataAccessError(message: "Reminder not found")
     }
     
-    public func createReminder(_ newReminder: NewReminder) async throws {
+    /// Saves the reminder and returns its `calendarItemIdentifier`.
+    @discardableResult
+    public func createReminder(_ newReminder: NewReminder) async throws -> String {
         guard let calendar = eventStore.calendar(withIdentifier: newReminder.listId) else {
             throw RemError.DataAccessError(message: "List not found")
         }
@@ -134,10 +158,19 @@ public class RemindersService: ObservableObject {
             if let date = formatter.date(from: dueDateString) {
                 let components = Calendar.current.dateComponents([.year, .month, .day, .hour, .minute], from: date)
                 reminder.dueDateComponents = components
+            } else {
+                // A plain YYYY-MM-DD date (e.g. from the agenda) makes an all-day reminder
+                let dayFormatter = ISO8601DateFormatter()
+                dayFormatter.formatOptions = [.withFullDate]
+                dayFormatter.timeZone = TimeZone.current
+                if let date = dayFormatter.date(from: dueDateString) {
+                    reminder.dueDateComponents = Calendar.current.dateComponents([.year, .month, .day], from: date)
+                }
             }
         }
         
         try eventStore.save(reminder, commit: true)
+        return reminder.calendarItemIdentifier
     }
     
     public func searchAllReminders(query: String) async throws -> ([Reminder], [String]) {
@@ -166,7 +199,7 @@ public class RemindersService: ObservableObject {
                         notes: ekReminder.notes,
                         completed: ekReminder.isCompleted,
                         priority: UInt8(ekReminder.priority),
-                        dueDate: ekReminder.dueDateComponents?.date?.ISO8601Format()
+                        dueDate: Self.dueDateString(from: ekReminder.dueDateComponents)
                     )
                     
                     allReminders.append(reminder)
@@ -178,6 +211,17 @@ public class RemindersService: ObservableObject {
         return (allReminders, listNames)
     }
     
+    // All-day reminders have no hour and go over as a plain date; as a timestamp
+    // they would read as due at midnight
+    private static func dueDateString(from components: DateComponents?) -> String? {
+        guard let components = components else { return nil }
+        if components.hour == nil,
+           let year = components.year, let month = components.month, let day = components.day {
+            return String(format: "%04d-%02d-%02d", year, month, day)
+        }
+        return components.date?.ISO8601Format()
+    }
+    
     private func getReminderCount(for calendar: EKCalendar) async throws -> Int {
         let predicate = eventStore.predicateForReminders(in: [calendar])
         
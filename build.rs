@@ -11,10 +11,12 @@ fn main() -> Result<()> {
         .add_instructions(&cargo)?
         .emit()?;
 
-    // Link EventKit framework on macOS
-    println!("cargo:rustc-link-lib=framework=EventKit");
-    println!("cargo:rustc-link-lib=framework=Foundation");
-    println!("cargo:rustc-link-lib=framework=CoreFoundation");
+    // Link EventKit framework on macOS; other platforms use the local reminders file
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("macos") {
+        println!("cargo:rustc-link-lib=framework=EventKit");
+        println!("cargo:rustc-link-lib=framework=Foundation");
+        println!("cargo:rustc-link-lib=framework=CoreFoundation");
+    }
 
     Ok(())
 }
@@ -0,0 +1,90 @@
+//! Filtering and rendering huge lists. Run with `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use rem_core::tui::TUIApp;
+use rem_core::{Reminder, ReminderList};
+
+const SIZES: [usize; 2] = [10_000, 100_000];
+
+fn reminders(count: usize) -> Vec<Reminder> {
+    (0..count)
+        .map(|i| Reminder {
+            id: format!("r-{i}"),
+            title: format!("Reminder {i}"),
+            notes: (i % 3 == 0).then(|| format!("Notes for reminder {i}")),
+            completed: i % 2 == 0,
+            priority: (i % 4) as u8,
+            due_date: None,
+        })
+        .collect()
+}
+
+/// An app with a list of `count` reminders open.
+fn open_list(count: usize) -> TUIApp {
+    let list = ReminderList {
+        id: "huge".to_string(),
+        name: "Huge".to_string(),
+        color: "#007AFF".to_string(),
+        count: count as u32,
+    };
+    let mut app = TUIApp::new(vec![list]).expect("Failed to create TUI app");
+    app.handle_key_event_for_test(key(KeyCode::Enter));
+    app.set_reminders(reminders(count));
+    app.take_actions();
+    app
+}
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+fn filtering(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter");
+    for count in SIZES {
+        // Flipping the completed filter makes every iteration refilter
+        let mut app = open_list(count);
+        group.bench_function(BenchmarkId::new("completed", count), |b| {
+            b.iter(|| {
+                app.set_show_completed_todos(!app.show_completed_todos());
+                app.get_filtered_reminders_for_test().len()
+            })
+        });
+
+        let mut app = open_list(count);
+        for c in "/notes 7".chars() {
+            app.handle_key_event_for_test(key(KeyCode::Char(c)));
+        }
+        app.handle_key_event_for_test(key(KeyCode::Enter));
+        group.bench_function(BenchmarkId::new("search", count), |b| {
+            b.iter(|| {
+                app.set_show_completed_todos(!app.show_completed_todos());
+                app.get_filtered_reminders_for_test().len()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn rendering(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    for count in SIZES {
+        let mut app = open_list(count);
+        let mut terminal =
+            Terminal::new(TestBackend::new(120, 40)).expect("Failed to create terminal");
+        group.bench_function(BenchmarkId::new("frame", count), |b| {
+            b.iter(|| app.draw(&mut terminal).expect("Failed to draw"))
+        });
+        group.bench_function(BenchmarkId::new("scroll", count), |b| {
+            b.iter(|| {
+                app.handle_key_event_for_test(key(KeyCode::Down));
+                app.draw(&mut terminal).expect("Failed to draw")
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, filtering, rendering);
+criterion_main!(benches);
@@ -7,6 +7,7 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::sync::Mutex;
 
+pub mod logging;
 pub mod tui;
 pub mod types;
 
@@ -20,7 +21,7 @@ struct GlobalTuiState {
 
 static TUI_STATE: Mutex<Option<GlobalTuiState>> = Mutex::new(None);
 
-#[derive(uniffi::Record)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ReminderList {
     pub id: String,
     pub name: String,
@@ -28,7 +29,7 @@ pub struct ReminderList {
     pub count: u32,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Reminder {
     pub id: String,
     pub title: String,
@@ -50,15 +51,39 @@ pub struct NewReminder {
 #[derive(uniffi::Enum, Clone, Debug)]
 pub enum TuiAction {
     Quit,
-    SelectList { list_id: String },
-    ToggleReminder { reminder_id: String },
-    DeleteReminder { reminder_id: String },
-    CreateReminder { new_reminder: NewReminder },
+    SelectList {
+        list_id: String,
+    },
+    PreviewList {
+        list_id: String,
+    },
+    ToggleReminder {
+        reminder_id: String,
+    },
+    DeleteReminder {
+        reminder_id: String,
+    },
+    CreateReminder {
+        new_reminder: NewReminder,
+    },
+    MoveReminder {
+        reminder_id: String,
+        list_id: String,
+    },
+    SetPriority {
+        reminder_id: String,
+        priority: u8,
+    },
     Back,
     Refresh,
     ToggleCompletedVisibility,
-    GlobalSearch { query: String },
-    ShowLoading { message: String },
+    GlobalSearch {
+        query: String,
+    },
+    LoadAllReminders,
+    ShowLoading {
+        message: String,
+    },
     DataLoaded,
 }
 
@@ -73,8 +98,10 @@ pub enum RemError {
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn start_tui(lists: Vec<ReminderList>) -> Result<Vec<TuiAction>, RemError> {
     let mut tui_app = TUIApp::new(lists)?;
+    tui_app.set_log_buffer(logging::init());
     let actions = tui_app.run()?;
 
     // Store the app state for subsequent calls
@@ -88,11 +115,16 @@ pub fn start_tui(lists: Vec<ReminderList>) -> Result<Vec<TuiAction>, RemError> {
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn run_persistent_tui(lists: Vec<ReminderList>) -> Result<Vec<TuiAction>, RemError> {
     let mut global_state = TUI_STATE.lock().unwrap();
 
-    // Initialize the TUI app
+    // Initialize the TUI app where the last session left off
     let mut tui_app = TUIApp::new(lists)?;
+    tui_app.set_log_buffer(logging::init());
+    tui_app.restore_cache(tui::cache::cache_path());
+    tui_app.restore_session(tui::session::session_path());
+    tui_app.load_due_dates();
 
     // Setup terminal
     enable_raw_mode().map_err(|e| RemError::TUIError {
@@ -127,6 +159,7 @@ pub fn run_persistent_tui(lists: Vec<ReminderList>) -> Result<Vec<TuiAction>, Re
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn continue_persistent_tui() -> Result<Vec<TuiAction>, RemError> {
     let mut global_state = TUI_STATE.lock().unwrap();
 
@@ -154,10 +187,14 @@ pub fn continue_persistent_tui() -> Result<Vec<TuiAction>, RemError> {
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn shutdown_tui() -> Result<(), RemError> {
     let mut global_state = TUI_STATE.lock().unwrap();
 
     if let Some(mut state) = global_state.take() {
+        state.app.save_session();
+        state.app.save_cache();
+
         // Restore terminal
         disable_raw_mode().map_err(|e| RemError::TUIError {
             message: e.to_string(),
@@ -180,6 +217,7 @@ pub fn shutdown_tui() -> Result<(), RemError> {
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn render_reminders_view(reminders: Vec<Reminder>) -> Result<Vec<TuiAction>, RemError> {
     let mut global_state = TUI_STATE.lock().unwrap();
 
@@ -203,6 +241,7 @@ pub fn render_reminders_view(reminders: Vec<Reminder>) -> Result<Vec<TuiAction>,
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn set_reminders(reminders: Vec<Reminder>) -> Result<(), RemError> {
     let mut global_state = TUI_STATE.lock().unwrap();
 
@@ -221,6 +260,7 @@ pub fn set_reminders(reminders: Vec<Reminder>) -> Result<(), RemError> {
 }
 
 #[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
 pub fn set_global_reminders(
     reminders: Vec<Reminder>,
     list_names: Vec<String>,
@@ -251,12 +291,76 @@ pub fn set_global_reminders(
     }
 }
 
-uniffi::setup_scaffolding!();
+#[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub fn set_all_reminders(
+    reminders: Vec<Reminder>,
+    list_names: Vec<String>,
+) -> Result<(), RemError> {
+    let mut global_state = TUI_STATE.lock().unwrap();
+
+    if let Some(ref mut state) = global_state.as_mut() {
+        state
+            .app
+            .add_status_log("✅ All reminders loaded".to_string());
+        state
+            .app
+            .set_all_reminders(reminders.into_iter().zip(list_names).collect());
+        Ok(())
+    } else {
+        Err(RemError::TUIError {
+            message: "TUI not initialized".to_string(),
+        })
+    }
+}
+
+#[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub fn resolve_pending_change(reminder_id: String, success: bool) -> Result<(), RemError> {
+    let mut global_state = TUI_STATE.lock().unwrap();
+
+    if let Some(ref mut state) = global_state.as_mut() {
+        // Confirm the optimistic toggle/delete, or roll it back if Swift failed
+        state.app.resolve_pending_change(&reminder_id, success);
+        Ok(())
+    } else {
+        Err(RemError::TUIError {
+            message: "TUI not initialized".to_string(),
+        })
+    }
+}
+
+#[uniffi::export]
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub fn resolve_pending_create(success: bool, reminder_id: Option<String>) -> Result<(), RemError> {
+    let mut global_state = TUI_STATE.lock().unwrap();
+
+    if let Some(ref mut state) = global_state.as_mut() {
+        // Confirm the optimistic create under its saved id, or remove it if Swift failed
+        state.app.resolve_pending_create(success, reminder_id);
+        Ok(())
+    } else {
+        Err(RemError::TUIError {
+            message: "TUI not initialized".to_string(),
+        })
+    }
+}
+
+// The scaffolding's continuation-callback setter compares function pointers, which
+// newer toolchains lint on. A lint attribute on the macro call itself is ignored, so
+// the scaffolding lives in its own module and is re-exported at the crate root, where
+// the `#[uniffi::export]` code looks for it.
+#[allow(unpredictable_function_pointer_comparisons)]
+mod scaffolding {
+    uniffi::setup_scaffolding!();
+}
+pub use scaffolding::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tui::app::AppView;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     #[test]
     fn test_toggle_completed_visibility_action() {
@@ -1400,4 +1504,199 @@ mod tests {
 
         println!("✅ Global search navigation back test passed!");
     }
+
+    fn optimistic_test_app() -> TUIApp {
+        let lists = vec![ReminderList {
+            id: "test-list".to_string(),
+            name: "Test List".to_string(),
+            color: "#FF0000".to_string(),
+            count: 3,
+        }];
+
+        let reminders = vec![
+            Reminder {
+                id: "rem-1".to_string(),
+                title: "First task".to_string(),
+                notes: None,
+                completed: false,
+                priority: 0,
+                due_date: None,
+            },
+            Reminder {
+                id: "rem-2".to_string(),
+                title: "Second task".to_string(),
+                notes: None,
+                completed: false,
+                priority: 0,
+                due_date: None,
+            },
+            Reminder {
+                id: "rem-3".to_string(),
+                title: "Third task".to_string(),
+                notes: None,
+                completed: false,
+                priority: 0,
+                due_date: None,
+            },
+        ];
+
+        let mut tui_app = TUIApp::new(lists).expect("Failed to create TUI app");
+        tui_app.set_current_view(AppView::Reminders {
+            list_id: "test-list".to_string(),
+        });
+        tui_app.set_reminders(reminders);
+        tui_app
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_optimistic_toggle_updates_local_state() {
+        let mut tui_app = optimistic_test_app();
+
+        // Move to the second reminder and toggle it
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Char(' ')));
+
+        // Completed reminders are hidden, so it disappears immediately
+        let visible: Vec<&str> = tui_app
+            .get_filtered_reminders_for_test()
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(visible, vec!["rem-1", "rem-3"]);
+        assert!(
+            tui_app.is_reminder_pending("rem-2"),
+            "Toggle should be pending until confirmed"
+        );
+        assert_eq!(
+            tui_app.get_selected_index(),
+            1,
+            "Selection should stay on the same row"
+        );
+
+        // Confirmation clears the pending marker but keeps the change
+        tui_app.resolve_pending_change("rem-2", true);
+        assert!(!tui_app.is_reminder_pending("rem-2"));
+        assert_eq!(tui_app.get_filtered_reminders_for_test().len(), 2);
+
+        println!("✅ Optimistic toggle test passed!");
+    }
+
+    #[test]
+    fn test_optimistic_toggle_keeps_selection_on_same_reminder() {
+        let mut tui_app = optimistic_test_app();
+        tui_app.set_show_completed_todos(true);
+
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Enter));
+
+        let filtered = tui_app.get_filtered_reminders_for_test();
+        let selected = filtered[tui_app.get_selected_index()];
+        assert_eq!(selected.id, "rem-2", "Selection should follow the reminder");
+        assert!(selected.completed, "Reminder should be completed locally");
+
+        // A failed toggle is rolled back
+        tui_app.resolve_pending_change("rem-2", false);
+        let filtered = tui_app.get_filtered_reminders_for_test();
+        assert!(
+            !filtered[1].completed,
+            "Failed toggle should be rolled back"
+        );
+        assert!(!tui_app.is_reminder_pending("rem-2"));
+
+        println!("✅ Optimistic toggle selection test passed!");
+    }
+
+    #[test]
+    fn test_optimistic_delete_and_rollback() {
+        let mut tui_app = optimistic_test_app();
+
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Delete));
+
+        let visible: Vec<&str> = tui_app
+            .get_filtered_reminders_for_test()
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(visible, vec!["rem-1", "rem-3"]);
+        assert_eq!(tui_app.get_lists()[0].count, 2, "List count should drop");
+        assert!(tui_app.is_reminder_pending("rem-2"));
+
+        // Failure puts the reminder back where it was
+        tui_app.resolve_pending_change("rem-2", false);
+        let visible: Vec<&str> = tui_app
+            .get_filtered_reminders_for_test()
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(visible, vec!["rem-1", "rem-2", "rem-3"]);
+        assert_eq!(tui_app.get_lists()[0].count, 3, "List count should recover");
+
+        println!("✅ Optimistic delete rollback test passed!");
+    }
+
+    #[test]
+    fn test_optimistic_create_adds_pending_reminder() {
+        let mut tui_app = optimistic_test_app();
+
+        tui_app.handle_key_event_for_test(key(KeyCode::Char('c')));
+        for c in "Buy milk".chars() {
+            tui_app.handle_key_event_for_test(key(KeyCode::Char(c)));
+        }
+        tui_app.handle_key_event_for_test(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+
+        assert!(
+            matches!(tui_app.get_current_view(), AppView::Reminders { list_id } if list_id == "test-list"),
+            "Should return to the reminders view"
+        );
+        let filtered = tui_app.get_filtered_reminders_for_test();
+        assert_eq!(filtered.len(), 4, "New reminder should show up immediately");
+        let created_id = filtered[3].id.clone();
+        assert_eq!(filtered[3].title, "Buy milk");
+        assert!(tui_app.is_reminder_pending(&created_id));
+        assert_eq!(tui_app.get_lists()[0].count, 4);
+
+        // Failure removes the placeholder again
+        tui_app.resolve_pending_create(false, None);
+        assert_eq!(tui_app.get_filtered_reminders_for_test().len(), 3);
+        assert_eq!(tui_app.get_lists()[0].count, 3);
+
+        println!("✅ Optimistic create test passed!");
+    }
+
+    #[test]
+    fn test_toggle_right_after_create_uses_saved_id() {
+        let mut tui_app = optimistic_test_app();
+
+        tui_app.handle_key_event_for_test(key(KeyCode::Char('c')));
+        for c in "Buy milk".chars() {
+            tui_app.handle_key_event_for_test(key(KeyCode::Char(c)));
+        }
+        tui_app.handle_key_event_for_test(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        tui_app.take_actions();
+        tui_app.resolve_pending_create(true, Some("rem-saved".to_string()));
+
+        let filtered = tui_app.get_filtered_reminders_for_test();
+        assert_eq!(
+            filtered[3].id, "rem-saved",
+            "Placeholder should take the saved id"
+        );
+        assert!(!tui_app.is_reminder_pending("rem-saved"));
+
+        // Toggling the new row right away goes to Swift under the saved id
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Down));
+        tui_app.handle_key_event_for_test(key(KeyCode::Enter));
+        let actions = tui_app.take_actions();
+        assert!(
+            matches!(actions.as_slice(), [TuiAction::ToggleReminder { reminder_id }] if reminder_id == "rem-saved"),
+            "Toggle should target the saved id, got {actions:?}"
+        );
+        assert!(tui_app.is_reminder_pending("rem-saved"));
+    }
 }
@@ -0,0 +1,234 @@
+//! Structured logging. Events go to a daily-rotated file in the data directory and
+//! into an in-memory buffer the TUI's log pane reads from. Spans, like the ones around
+//! FFI calls, log how long they took when they close.
+
+use crate::tui::session::data_dir;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{fmt, EnvFilter};
+
+/// Overrides the default `debug` filter, like `RUST_LOG` (which takes precedence).
+pub const LOG_ENV: &str = "REM_CORE_LOG";
+const LOG_PREFIX: &str = "rem-core";
+/// Days of log files kept before the oldest is deleted.
+const KEEP_FILES: usize = 7;
+/// Records kept in memory for the log pane.
+const BUFFER_CAPACITY: usize = 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    /// The message followed by the event's fields as `key=value`.
+    pub message: String,
+}
+
+/// The most recent log records, oldest first. Clones share the same records.
+#[derive(Clone, Debug, Default)]
+pub struct LogBuffer(Arc<Mutex<VecDeque<LogRecord>>>);
+
+impl LogBuffer {
+    pub fn push(&self, record: LogRecord) {
+        let mut records = self.0.lock().unwrap();
+        if records.len() == BUFFER_CAPACITY {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Records at `level` or more severe, oldest first.
+    pub fn records(&self, level: Level) -> Vec<LogRecord> {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|record| record.level <= level)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Copies every event, and every span with its duration when it closes, into a
+/// `LogBuffer`.
+pub struct BufferLayer(LogBuffer);
+
+impl BufferLayer {
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self(buffer)
+    }
+}
+
+/// When the span was created.
+struct Started(Instant);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for BufferLayer {
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Started(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(elapsed) = span
+            .extensions()
+            .get::<Started>()
+            .map(|started| started.0.elapsed())
+        else {
+            return;
+        };
+        let metadata = span.metadata();
+        self.0.push(LogRecord {
+            time: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: format!("{} elapsed_ms={}", span.name(), elapsed.as_millis()),
+        });
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.0.push(LogRecord {
+            time: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.finish(),
+        });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        match (self.message.is_empty(), self.fields.is_empty()) {
+            (_, true) => self.message,
+            (true, false) => self.fields,
+            (false, false) => format!("{} {}", self.message, self.fields),
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={value:?}", field.name());
+    }
+}
+
+static BUFFER: OnceLock<LogBuffer> = OnceLock::new();
+
+/// Start logging to `rem-core.<date>.log` in the data directory, once per process,
+/// and return the buffer every event is also copied into. Logging setup failures
+/// leave the file sink out rather than failing the TUI.
+pub fn init() -> LogBuffer {
+    BUFFER
+        .get_or_init(|| {
+            let buffer = LogBuffer::default();
+            let filter = EnvFilter::try_from_default_env()
+                .or_else(|_| EnvFilter::try_from_env(LOG_ENV))
+                .unwrap_or_else(|_| EnvFilter::new("debug"));
+            let file = tracing_appender::rolling::Builder::new()
+                .rotation(tracing_appender::rolling::Rotation::DAILY)
+                .filename_prefix(LOG_PREFIX)
+                .filename_suffix("log")
+                .max_log_files(KEEP_FILES)
+                .build(data_dir())
+                .ok()
+                .map(|appender| {
+                    fmt::layer()
+                        .with_writer(appender)
+                        .with_ansi(false)
+                        .with_target(true)
+                        .with_span_events(FmtSpan::CLOSE)
+                });
+            let _ = tracing_subscriber::registry()
+                .with(filter)
+                .with(file)
+                .with(BufferLayer::new(buffer.clone()))
+                .try_init();
+            buffer
+        })
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_layer_records_message_and_fields() {
+        let buffer = LogBuffer::default();
+        let subscriber = tracing_subscriber::registry().with(BufferLayer::new(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(list_id = "work", count = 3, "reminders loaded");
+            tracing::debug!("frame drawn");
+            tracing::error!(error = "boom");
+            let _span = tracing::debug_span!("set_reminders").entered();
+        });
+
+        let records = buffer.records(Level::TRACE);
+        let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                r#"reminders loaded list_id="work" count=3"#,
+                "frame drawn",
+                r#"error="boom""#,
+                "set_reminders elapsed_ms=0",
+            ]
+        );
+        // Filtering keeps the given level and anything more severe
+        assert_eq!(buffer.records(Level::INFO).len(), 2);
+        assert_eq!(buffer.records(Level::ERROR)[0].level, Level::ERROR);
+    }
+
+    #[test]
+    fn test_buffer_drops_oldest_records() {
+        let buffer = LogBuffer::default();
+        for i in 0..BUFFER_CAPACITY + 5 {
+            buffer.push(LogRecord {
+                time: Local::now(),
+                level: Level::INFO,
+                target: "test".to_string(),
+                message: i.to_string(),
+            });
+        }
+        let records = buffer.records(Level::TRACE);
+        assert_eq!(records.len(), BUFFER_CAPACITY);
+        assert_eq!(records[0].message, "5");
+    }
+}
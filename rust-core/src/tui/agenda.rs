@@ -0,0 +1,287 @@
+//! Calendar/agenda view: a month grid next to a week or day list of due reminders.
+
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveTime};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+use serde::{Deserialize, Serialize};
+
+/// Width of the month grid including borders and padding.
+pub const MONTH_GRID_WIDTH: u16 = 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgendaMode {
+    Week,
+    Day,
+}
+
+#[derive(Clone, Debug)]
+pub struct AgendaState {
+    pub selected: NaiveDate,
+    pub today: NaiveDate,
+    pub mode: AgendaMode,
+}
+
+impl AgendaState {
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            selected: today,
+            today,
+            mode: AgendaMode::Week,
+        }
+    }
+
+    pub fn move_days(&mut self, days: i64) {
+        let moved = if days >= 0 {
+            self.selected
+                .checked_add_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.selected
+                .checked_sub_days(Days::new(days.unsigned_abs()))
+        };
+        if let Some(date) = moved {
+            self.selected = date;
+        }
+    }
+
+    /// Move by whole months, clamping the day (31 Jan + 1 month = 28/29 Feb).
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months >= 0 {
+            self.selected
+                .checked_add_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.selected
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        };
+        if let Some(date) = moved {
+            self.selected = date;
+        }
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            AgendaMode::Week => AgendaMode::Day,
+            AgendaMode::Day => AgendaMode::Week,
+        };
+    }
+
+    /// Monday to Sunday of the selected week.
+    pub fn week(&self) -> Vec<NaiveDate> {
+        let monday = self.selected.week(chrono::Weekday::Mon).first_day();
+        monday.iter_days().take(7).collect()
+    }
+
+    /// The days shown in the list: the selected week or just the selected day.
+    pub fn visible_days(&self) -> Vec<NaiveDate> {
+        match self.mode {
+            AgendaMode::Week => self.week(),
+            AgendaMode::Day => vec![self.selected],
+        }
+    }
+}
+
+/// A due reminder as shown in the agenda.
+#[derive(Clone, Debug)]
+pub struct AgendaEntry {
+    pub date: NaiveDate,
+    pub time: Option<NaiveTime>,
+    pub title: String,
+    pub list_name: String,
+    pub color: Color,
+    pub completed: bool,
+    pub pending: bool,
+}
+
+/// Weeks (Monday first) covering the month of `date`; days of other months are `None`.
+pub fn month_weeks(date: NaiveDate) -> Vec<[Option<NaiveDate>; 7]> {
+    let first = date.with_day(1).unwrap_or(date);
+    let mut weeks = Vec::new();
+    let mut week = [None; 7];
+    for day in first.iter_days().take_while(|d| d.month() == first.month()) {
+        let column = day.weekday().num_days_from_monday() as usize;
+        week[column] = Some(day);
+        if column == 6 {
+            weeks.push(week);
+            week = [None; 7];
+        }
+    }
+    if week.iter().any(Option::is_some) {
+        weeks.push(week);
+    }
+    weeks
+}
+
+pub fn month_grid<'a>(state: &AgendaState, entries: &[AgendaEntry]) -> Paragraph<'a> {
+    let mut lines = vec![Line::from(Span::styled(
+        "Mo Tu We Th Fr Sa Su",
+        Style::default()
+            .fg(Color::Gray)
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    for week in month_weeks(state.selected) {
+        let mut spans = Vec::new();
+        for (column, day) in week.iter().enumerate() {
+            if column > 0 {
+                spans.push(Span::raw(" "));
+            }
+            let Some(day) = day else {
+                spans.push(Span::raw("  "));
+                continue;
+            };
+
+            let first_due = entries.iter().find(|e| e.date == *day && !e.completed);
+            let mut style = match first_due {
+                Some(entry) => Style::default()
+                    .fg(entry.color)
+                    .add_modifier(Modifier::BOLD),
+                None => Style::default().fg(Color::DarkGray),
+            };
+            if *day == state.today {
+                style = style.fg(Color::Yellow).add_modifier(Modifier::UNDERLINED);
+            }
+            if *day == state.selected {
+                style = style
+                    .bg(Color::Cyan)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD);
+            }
+            spans.push(Span::styled(format!("{:>2}", day.day()), style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(Span::styled(
+                format!(" {} ", state.selected.format("%B %Y")),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .style(Style::default().fg(Color::Blue))
+            .padding(Padding::horizontal(1)),
+    )
+}
+
+fn entry_line<'a>(entry: &AgendaEntry) -> Line<'a> {
+    let time = entry
+        .time
+        .map(|t| t.format("%H:%M").to_string())
+        .unwrap_or_else(|| "all day".to_string());
+    let title_style = if entry.completed {
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::CROSSED_OUT)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut spans = vec![
+        Span::raw("    "),
+        Span::styled("● ", Style::default().fg(entry.color)),
+        Span::styled(format!("{time:<7} "), Style::default().fg(Color::Gray)),
+        Span::styled(entry.title.clone(), title_style),
+        Span::styled(
+            format!("  {}", entry.list_name),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if entry.pending {
+        spans.push(Span::styled(
+            " ⏳ saving…",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// The week or day list for an area `height` rows tall. `entries` must be sorted by
+/// date and time.
+pub fn agenda_list<'a>(state: &AgendaState, entries: &[AgendaEntry], height: u16) -> Paragraph<'a> {
+    let mut lines = Vec::new();
+    let mut selected_line = 0;
+    for day in state.visible_days() {
+        let is_selected = day == state.selected;
+        if is_selected {
+            selected_line = lines.len();
+        }
+        let mut heading = vec![
+            Span::styled(
+                if is_selected && state.mode == AgendaMode::Week {
+                    "▶ "
+                } else {
+                    "  "
+                },
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                day.format(match state.mode {
+                    AgendaMode::Week => "%a %e %b",
+                    AgendaMode::Day => "%A %e %B %Y",
+                })
+                .to_string(),
+                Style::default()
+                    .fg(if is_selected {
+                        Color::Cyan
+                    } else {
+                        Color::LightBlue
+                    })
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if day == state.today {
+            heading.push(Span::styled(" · today", Style::default().fg(Color::Yellow)));
+        }
+        lines.push(Line::from(heading));
+
+        let day_entries: Vec<&AgendaEntry> = entries.iter().filter(|e| e.date == day).collect();
+        if day_entries.is_empty() && (is_selected || state.mode == AgendaMode::Day) {
+            lines.push(Line::from(Span::styled(
+                "    Nothing due",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        lines.extend(day_entries.into_iter().map(entry_line));
+    }
+
+    let title = match state.mode {
+        AgendaMode::Week => format!(
+            " 📅 Week of {} ",
+            state.week()[0].format("%e %b").to_string().trim()
+        ),
+        AgendaMode::Day => " 📅 Day ".to_string(),
+    };
+
+    // Scroll a busy week so the selected day's heading stays in view
+    let visible = usize::from(height.saturating_sub(2));
+    let scroll = if lines.len() > visible {
+        selected_line.min(lines.len() - visible)
+    } else {
+        0
+    };
+
+    Paragraph::new(lines)
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .style(Style::default().fg(Color::Blue)),
+        )
+}
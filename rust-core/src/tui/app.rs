@@ -1,20 +1,41 @@
+use super::agenda::{self, AgendaEntry, AgendaState};
+use super::board::{self, BoardColumn, BoardState, ColumnKind};
+use super::cache::{self, ReminderCache};
+use super::log_pane::{self, LogPane};
+use super::mouse::{self, HitMap, Target};
+use super::responsive::{truncate, Areas, Screen, SIDEBAR_WIDTH};
+use super::session::{self, Session};
+use super::viewport;
+use crate::logging::LogBuffer;
+use crate::types::{Due, DueStatus, Priority};
 use crate::{RemError, Reminder, ReminderList, TuiAction};
+use chrono::{DateTime, Local, NaiveDate};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton,
+        MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Margin},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, List, ListItem, ListState, Padding, Paragraph},
     Frame, Terminal,
 };
+use std::cell::{Ref, RefCell};
+use std::collections::{BTreeMap, HashMap};
 use std::io;
+use std::path::PathBuf;
 use std::thread;
 use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+/// Frames drawn slower than this are logged.
+const SLOW_FRAME: Duration = Duration::from_millis(16);
 
 pub struct TUIApp {
     lists: Vec<ReminderList>,
@@ -36,6 +57,95 @@ pub struct TUIApp {
     show_completed_todos: bool,
     search_state: SearchState,
     all_reminders: Vec<(Reminder, String)>, // (reminder, list_name) for global search
+    current_list_id: Option<String>,
+    pending_changes: Vec<PendingChange>,
+    next_pending_id: u32,
+    agenda: AgendaState,
+    board: BoardState,
+    hit_map: HitMap,
+    last_click: Option<(Instant, Target)>,
+    resized: bool,
+    two_pane: bool,
+    /// The highlighted list while the reminders have focus.
+    lists_index: usize,
+    /// The list whose reminders were last requested for the two-pane preview.
+    preview_list_id: Option<String>,
+    /// Where the session is saved; `None` keeps the UI state in memory only.
+    session_path: Option<PathBuf>,
+    /// Selected reminder per list, restored when the list is opened again.
+    list_selection: BTreeMap<String, usize>,
+    /// Global searches, most recent first.
+    recent_searches: Vec<String>,
+    /// Searches within each list, most recent first, per list id.
+    list_searches: BTreeMap<String, Vec<String>>,
+    /// Fixed "now" for relative dates; `None` follows the system clock.
+    pinned_now: Option<DateTime<Local>>,
+    /// Reminders as last fetched per list, shown while a list is fetched again.
+    cache: ReminderCache,
+    /// Where the cache is saved; `None` keeps it in memory only.
+    cache_path: Option<PathBuf>,
+    /// Log records for the log pane.
+    log: LogBuffer,
+    log_pane: LogPane,
+    /// Bumped whenever `current_reminders` or `all_reminders` change.
+    reminders_version: u64,
+    /// The shown reminders as indices into the reminders they were filtered from,
+    /// recomputed when their `FilterKey` changes.
+    filtered: RefCell<Option<(FilterKey, Vec<usize>)>>,
+    /// Like `filtered`, for the shown reminders that match the search query, which
+    /// `n`/`N` and the match counter use while the whole list is shown.
+    matches: RefCell<Option<(FilterKey, Vec<usize>)>>,
+}
+
+/// Everything the filtered reminders depend on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FilterKey {
+    reminders_version: u64,
+    global: bool,
+    show_completed: bool,
+    /// The lowercased search query, when it filters.
+    query: Option<String>,
+}
+
+/// A mutation that has been applied to the local model but not yet confirmed by Swift.
+#[derive(Clone, Debug)]
+enum PendingChange {
+    Toggle {
+        reminder_id: String,
+    },
+    Delete {
+        reminder: Reminder,
+        list_id: Option<String>,
+        list_name: Option<String>,
+        current_index: Option<usize>,
+        global_index: Option<usize>,
+    },
+    Create {
+        temp_id: String,
+        list_id: String,
+    },
+    Move {
+        reminder_id: String,
+        from_list_id: Option<String>,
+        from_list_name: String,
+        to_list_id: String,
+    },
+    Priority {
+        reminder_id: String,
+        previous: u8,
+    },
+}
+
+impl PendingChange {
+    fn reminder_id(&self) -> &str {
+        match self {
+            PendingChange::Toggle { reminder_id } => reminder_id,
+            PendingChange::Delete { reminder, .. } => &reminder.id,
+            PendingChange::Create { temp_id, .. } => temp_id,
+            PendingChange::Move { reminder_id, .. } => reminder_id,
+            PendingChange::Priority { reminder_id, .. } => reminder_id,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -45,6 +155,12 @@ struct SearchState {
     is_global: bool,   // true = search all lists, false = search current list
     has_results: bool, // track if we have filtered results to show
     last_escape_time: Option<Instant>, // for double-escape behavior
+    /// `n`/`N` jump between matches of `query` in the unfiltered list.
+    jumping: bool,
+    /// Position in the search history while browsing it with Up/Down, and the
+    /// query typed before browsing started.
+    history_index: Option<usize>,
+    draft: String,
 }
 
 impl SearchState {
@@ -55,6 +171,9 @@ impl SearchState {
             is_global: false,
             has_results: false,
             last_escape_time: None,
+            jumping: false,
+            history_index: None,
+            draft: String::new(),
         }
     }
 
@@ -64,12 +183,15 @@ impl SearchState {
         self.query.clear();
         self.has_results = false;
         self.last_escape_time = None;
+        self.jumping = false;
+        self.history_index = None;
     }
 
     fn add_char(&mut self, c: char) {
         if self.is_active {
             self.query.push(c);
             self.has_results = !self.query.is_empty();
+            self.history_index = None;
         }
     }
 
@@ -77,6 +199,7 @@ impl SearchState {
         if self.is_active && !self.query.is_empty() {
             self.query.pop();
             self.has_results = !self.query.is_empty();
+            self.history_index = None;
         }
     }
 
@@ -91,7 +214,44 @@ impl SearchState {
         self.has_results = false;
         self.is_global = false;
         self.last_escape_time = None;
+        self.jumping = false;
+        self.history_index = None;
+    }
+
+    /// Step through `history` (most recent first): `older` goes back in time, and
+    /// stepping forward past the newest entry brings back the typed query.
+    fn browse_history(&mut self, history: &[String], older: bool) {
+        let index = match (self.history_index, older) {
+            (None, true) if !history.is_empty() => {
+                self.draft = self.query.clone();
+                Some(0)
+            }
+            (None, _) => return,
+            (Some(i), true) => Some((i + 1).min(history.len().saturating_sub(1))),
+            (Some(0), false) => None,
+            (Some(i), false) => Some(i - 1),
+        };
+        self.history_index = index;
+        self.query = match index.and_then(|i| history.get(i)) {
+            Some(query) => query.clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.has_results = !self.query.is_empty();
     }
+
+    /// Whether the search bar is on screen.
+    fn is_shown(&self) -> bool {
+        self.is_active || self.has_results || self.jumping
+    }
+}
+
+/// Whether a reminder's title or notes contain `query`, which must be lowercase.
+fn matches_query(reminder: &Reminder, query: &str) -> bool {
+    reminder.title.to_lowercase().contains(query)
+        || reminder
+            .notes
+            .as_ref()
+            .is_some_and(|notes| notes.to_lowercase().contains(query))
 }
 
 #[derive(Clone, Debug)]
@@ -100,6 +260,8 @@ pub enum AppView {
     Lists,
     Reminders { list_id: String },
     CreateReminder,
+    Agenda,
+    Board,
 }
 
 #[derive(Clone, Debug)]
@@ -108,7 +270,7 @@ struct CreateReminderForm {
     notes: String,
     due_date: String,
     selected_list_id: String,
-    priority: u8,
+    priority: Priority,
     current_field: usize,
 }
 
@@ -122,7 +284,7 @@ impl CreateReminderForm {
             notes: String::new(),
             due_date: String::new(),
             selected_list_id,
-            priority: 0,
+            priority: Priority::None,
             current_field: 0,
         }
     }
@@ -161,23 +323,75 @@ impl TUIApp {
             show_completed_todos: false,
             search_state: SearchState::new(),
             all_reminders: Vec::new(),
+            current_list_id: None,
+            pending_changes: Vec::new(),
+            next_pending_id: 0,
+            agenda: AgendaState::new(Local::now().date_naive()),
+            board: BoardState::new(),
+            hit_map: HitMap::default(),
+            last_click: None,
+            resized: false,
+            two_pane: false,
+            lists_index: 0,
+            preview_list_id: None,
+            session_path: None,
+            list_selection: BTreeMap::new(),
+            recent_searches: Vec::new(),
+            list_searches: BTreeMap::new(),
+            pinned_now: None,
+            cache: ReminderCache::default(),
+            cache_path: None,
+            log: LogBuffer::default(),
+            log_pane: LogPane::new(),
+            reminders_version: 0,
+            filtered: RefCell::new(None),
+            matches: RefCell::new(None),
         })
     }
 
     pub fn set_reminders(&mut self, reminders: Vec<Reminder>) {
+        // Freshly loaded data is authoritative, so nothing is pending anymore
+        self.pending_changes.clear();
+        self.cache_fetched(&reminders);
+
+        // A two-pane preview: the lists keep focus and their selection
+        if matches!(self.current_view, AppView::Lists) {
+            self.current_reminders = reminders;
+            self.reminders_changed();
+            return;
+        }
+
+        // Revalidating a list opened from the cache: merge and keep the cursor
+        if matches!(&self.current_view, AppView::Reminders { list_id } if list_id != "global")
+            && !self.search_state.is_global
+        {
+            let selected = self.selected_reminder_id();
+            let changed = cache::merge(&mut self.current_reminders, reminders);
+            self.reminders_changed();
+            self.restore_selection(selected.as_deref());
+            if changed > 0 {
+                self.add_status_log(format!("🔄 {changed} reminders changed since last time"));
+            }
+            return;
+        }
         self.current_reminders = reminders.clone();
+        self.reminders_changed();
+        let opened = matches!(self.current_view, AppView::Loading);
 
         // Transition from loading to reminders view
-        if matches!(self.current_view, AppView::Loading) {
+        if opened {
             // Determine the appropriate view based on the current context
             if self.search_state.is_global {
                 self.current_view = AppView::Reminders {
                     list_id: "global".to_string(),
                 };
             } else {
-                // For regular list selection, use a generic identifier
+                // For regular list selection, use the list the user picked
                 self.current_view = AppView::Reminders {
-                    list_id: "selected".to_string(),
+                    list_id: self
+                        .current_list_id
+                        .clone()
+                        .unwrap_or_else(|| "selected".to_string()),
                 };
             }
             self.is_loading = false;
@@ -192,6 +406,7 @@ impl TUIApp {
                 .into_iter()
                 .map(|r| (r, "Unknown List".to_string()))
                 .collect();
+            self.reminders_changed();
         }
 
         self.selected_index = 0;
@@ -201,6 +416,10 @@ impl TUIApp {
             } else {
                 Some(0)
             });
+        if opened && !self.is_in_global_search_view() {
+            self.selected_index = self.remembered_selection();
+            self.reset_selection_for_filtered_reminders();
+        }
     }
 
     pub fn set_reminders_with_global_data(
@@ -210,6 +429,8 @@ impl TUIApp {
     ) {
         self.current_reminders = reminders;
         self.all_reminders = all_reminders;
+        self.pending_changes.clear();
+        self.reminders_changed();
 
         // Transition from loading to global reminders view
         if matches!(self.current_view, AppView::Loading) {
@@ -230,10 +451,371 @@ impl TUIApp {
     }
 
     pub fn set_all_reminders(&mut self, all_reminders: Vec<(Reminder, String)>) {
+        // Every list's reminders, so any list opens from the cache
+        let fetched_at = self.now().timestamp();
+        let mut by_list: BTreeMap<String, Vec<Reminder>> = self
+            .lists
+            .iter()
+            .map(|list| (list.id.clone(), Vec::new()))
+            .collect();
+        for (reminder, list_name) in &all_reminders {
+            if let Some(list) = self.lists.iter().find(|l| &l.name == list_name) {
+                if let Some(reminders) = by_list.get_mut(&list.id) {
+                    reminders.push(reminder.clone());
+                }
+            }
+        }
+        for (list_id, reminders) in by_list {
+            self.cache.insert(&list_id, reminders, fetched_at);
+        }
         self.all_reminders = all_reminders;
+        self.reminders_changed();
+    }
+
+    // Optimistic local updates. Mutations are applied to the local model right away and
+    // tracked as pending until Swift reports back through `resolve_pending_change` or
+    // `resolve_pending_create`, so the screen never waits on a full reload.
+
+    pub fn is_reminder_pending(&self, reminder_id: &str) -> bool {
+        self.pending_changes
+            .iter()
+            .any(|change| change.reminder_id() == reminder_id)
+    }
+
+    fn is_pending_create(&self, reminder_id: &str) -> bool {
+        self.pending_changes.iter().any(
+            |change| matches!(change, PendingChange::Create { temp_id, .. } if temp_id == reminder_id),
+        )
+    }
+
+    fn selected_reminder_id(&self) -> Option<String> {
+        let index = *self.filtered_indices().get(self.selected_index)?;
+        Some(self.filtered_source(index).id.clone())
+    }
+
+    /// Keep the cursor on `reminder_id` if it is still visible, otherwise stay at the
+    /// same position clamped to the filtered list.
+    fn restore_selection(&mut self, reminder_id: Option<&str>) {
+        if let Some(id) = reminder_id {
+            if let Some(index) = self
+                .get_filtered_reminders()
+                .iter()
+                .position(|r| r.id == id)
+            {
+                self.selected_index = index;
+                self.list_state.select(Some(index));
+                return;
+            }
+        }
+        self.reset_selection_for_filtered_reminders();
+    }
+
+    /// The real list backing the reminders view, if any.
+    fn active_list_id(&self) -> Option<String> {
+        match &self.current_view {
+            AppView::Reminders { list_id } if self.lists.iter().any(|l| &l.id == list_id) => {
+                Some(list_id.clone())
+            }
+            _ => self.current_list_id.clone(),
+        }
+    }
+
+    fn list_id_for_reminder(&self, reminder_id: &str) -> Option<String> {
+        if !self.is_in_global_search_view()
+            && self.current_reminders.iter().any(|r| r.id == reminder_id)
+        {
+            return self.active_list_id();
+        }
+        let list_name = self.get_list_name_for_reminder(reminder_id)?;
+        self.lists
+            .iter()
+            .find(|l| l.name == list_name)
+            .map(|l| l.id.clone())
+    }
+
+    fn adjust_list_count(&mut self, list_id: Option<&str>, delta: i64) {
+        if let Some(list) = list_id.and_then(|id| self.lists.iter_mut().find(|l| l.id == id)) {
+            list.count = (i64::from(list.count) + delta).max(0) as u32;
+        }
+    }
+
+    fn flip_completed(&mut self, reminder_id: &str) {
+        for reminder in self
+            .current_reminders
+            .iter_mut()
+            .chain(self.all_reminders.iter_mut().map(|(r, _)| r))
+            .filter(|r| r.id == reminder_id)
+        {
+            reminder.completed = !reminder.completed;
+        }
+        self.reminders_changed();
+    }
+
+    fn apply_optimistic_toggle(&mut self, reminder_id: &str) {
+        let selected_id = self.selected_reminder_id();
+        self.flip_completed(reminder_id);
+        self.pending_changes.push(PendingChange::Toggle {
+            reminder_id: reminder_id.to_string(),
+        });
+        self.restore_selection(selected_id.as_deref());
+    }
+
+    fn apply_optimistic_delete(&mut self, reminder_id: &str) {
+        let list_id = self.list_id_for_reminder(reminder_id);
+        let list_name = self
+            .get_list_name_for_reminder(reminder_id)
+            .map(str::to_string);
+
+        let current_index = self
+            .current_reminders
+            .iter()
+            .position(|r| r.id == reminder_id);
+        let global_index = self
+            .all_reminders
+            .iter()
+            .position(|(r, _)| r.id == reminder_id);
+
+        let mut removed = None;
+        if let Some(index) = current_index {
+            removed = Some(self.current_reminders.remove(index));
+        }
+        if let Some(index) = global_index {
+            let (reminder, _) = self.all_reminders.remove(index);
+            removed.get_or_insert(reminder);
+        }
+        self.reminders_changed();
+
+        if let Some(reminder) = removed {
+            self.adjust_list_count(list_id.as_deref(), -1);
+            self.pending_changes.push(PendingChange::Delete {
+                reminder,
+                list_id,
+                list_name,
+                current_index,
+                global_index,
+            });
+        }
+
+        // The cursor stays at the same row, which now shows the next reminder
+        self.reset_selection_for_filtered_reminders();
+    }
+
+    fn set_priority(&mut self, reminder_id: &str, priority: u8) {
+        for reminder in self
+            .current_reminders
+            .iter_mut()
+            .chain(self.all_reminders.iter_mut().map(|(r, _)| r))
+            .filter(|r| r.id == reminder_id)
+        {
+            reminder.priority = priority;
+        }
+    }
+
+    /// The `0`-`3` quick keys: as many `!` as the key says.
+    fn set_selected_priority(&mut self, priority: Priority) {
+        let Some(reminder) = self
+            .get_filtered_reminders()
+            .get(self.selected_index)
+            .map(|r| (*r).clone())
+        else {
+            return;
+        };
+        if self.is_pending_create(&reminder.id) {
+            self.add_status_log("⏳ Reminder is still being saved".to_string());
+            return;
+        }
+        if Priority::from_eventkit(reminder.priority) == priority {
+            return;
+        }
+        self.change_priority(&reminder, priority);
+        self.add_status_log(format!("❗ Priority set to {}", priority.label()));
+    }
+
+    /// Set a reminder's priority right away and ask Swift to save it.
+    fn change_priority(&mut self, reminder: &Reminder, priority: Priority) {
+        let value = priority.to_eventkit();
+        self.set_priority(&reminder.id, value);
+        self.pending_changes.push(PendingChange::Priority {
+            reminder_id: reminder.id.clone(),
+            previous: reminder.priority,
+        });
+        self.actions.push(TuiAction::SetPriority {
+            reminder_id: reminder.id.clone(),
+            priority: value,
+        });
+    }
+
+    fn set_list_name(&mut self, reminder_id: &str, list_name: &str) {
+        for (_, name) in self
+            .all_reminders
+            .iter_mut()
+            .filter(|(r, _)| r.id == reminder_id)
+        {
+            *name = list_name.to_string();
+        }
+    }
+
+    fn apply_optimistic_create(&mut self, new_reminder: &crate::NewReminder) {
+        self.next_pending_id += 1;
+        let temp_id = format!("pending-{}", self.next_pending_id);
+        let reminder = Reminder {
+            id: temp_id.clone(),
+            title: new_reminder.title.clone(),
+            notes: new_reminder.notes.clone(),
+            completed: false,
+            priority: new_reminder.priority,
+            due_date: new_reminder.due_date.clone(),
+        };
+
+        let selected_id = self.selected_reminder_id();
+        if self.active_list_id().as_deref() == Some(new_reminder.list_id.as_str()) {
+            self.current_reminders.push(reminder.clone());
+        }
+        if self.is_in_global_search_view()
+            || matches!(self.current_view, AppView::Agenda | AppView::Board)
+        {
+            if let Some(list) = self.lists.iter().find(|l| l.id == new_reminder.list_id) {
+                self.all_reminders.push((reminder, list.name.clone()));
+            }
+        }
+        self.reminders_changed();
+        self.adjust_list_count(Some(&new_reminder.list_id), 1);
+        self.pending_changes.push(PendingChange::Create {
+            temp_id,
+            list_id: new_reminder.list_id.clone(),
+        });
+        self.restore_selection(selected_id.as_deref());
+    }
+
+    /// Confirm or roll back the oldest pending toggle/delete for `reminder_id`.
+    pub fn resolve_pending_change(&mut self, reminder_id: &str, success: bool) {
+        let Some(position) = self.pending_changes.iter().position(|change| {
+            !matches!(change, PendingChange::Create { .. }) && change.reminder_id() == reminder_id
+        }) else {
+            return;
+        };
+        let change = self.pending_changes.remove(position);
+        if success {
+            return;
+        }
+
+        tracing::warn!(
+            reminder_id,
+            ?change,
+            "Swift rejected the change, rolling back"
+        );
+        let selected_id = self.selected_reminder_id();
+        match change {
+            PendingChange::Toggle { reminder_id } => {
+                self.flip_completed(&reminder_id);
+                self.add_status_log("❌ Failed to update reminder".to_string());
+            }
+            PendingChange::Delete {
+                reminder,
+                list_id,
+                list_name,
+                current_index,
+                global_index,
+            } => {
+                if let Some(index) = current_index {
+                    let index = index.min(self.current_reminders.len());
+                    self.current_reminders.insert(index, reminder.clone());
+                }
+                if let Some(index) = global_index {
+                    let index = index.min(self.all_reminders.len());
+                    self.all_reminders.insert(
+                        index,
+                        (
+                            reminder,
+                            list_name.unwrap_or_else(|| "Unknown List".to_string()),
+                        ),
+                    );
+                }
+                self.reminders_changed();
+                self.adjust_list_count(list_id.as_deref(), 1);
+                self.add_status_log("❌ Failed to delete reminder".to_string());
+            }
+            PendingChange::Move {
+                reminder_id,
+                from_list_id,
+                from_list_name,
+                to_list_id,
+            } => {
+                self.set_list_name(&reminder_id, &from_list_name);
+                if let (Some(from_list_id), Some((reminder, _))) = (
+                    from_list_id.as_deref(),
+                    self.all_reminders.iter().find(|(r, _)| r.id == reminder_id),
+                ) {
+                    self.cache
+                        .move_reminder(reminder, Some(&to_list_id), from_list_id);
+                }
+                self.adjust_list_count(Some(&to_list_id), -1);
+                self.adjust_list_count(from_list_id.as_deref(), 1);
+                self.add_status_log("❌ Failed to move reminder".to_string());
+            }
+            PendingChange::Priority {
+                reminder_id,
+                previous,
+            } => {
+                self.set_priority(&reminder_id, previous);
+                self.add_status_log("❌ Failed to change priority".to_string());
+            }
+            PendingChange::Create { .. } => unreachable!("creates are resolved separately"),
+        }
+        self.restore_selection(selected_id.as_deref());
+    }
+
+    /// Confirm or roll back the oldest pending create. Swift handles actions in order,
+    /// so creates are resolved first-in, first-out. A confirmed create swaps the
+    /// placeholder's temporary id for `reminder_id`, the id Swift saved it under, so
+    /// later toggles and deletes reach the real reminder.
+    pub fn resolve_pending_create(&mut self, success: bool, reminder_id: Option<String>) {
+        let Some(position) = self
+            .pending_changes
+            .iter()
+            .position(|change| matches!(change, PendingChange::Create { .. }))
+        else {
+            return;
+        };
+        let PendingChange::Create { temp_id, list_id } = self.pending_changes.remove(position)
+        else {
+            return;
+        };
+        if success {
+            if let Some(reminder_id) = reminder_id {
+                for reminder in self
+                    .current_reminders
+                    .iter_mut()
+                    .chain(self.all_reminders.iter_mut().map(|(r, _)| r))
+                    .filter(|r| r.id == temp_id)
+                {
+                    reminder.id = reminder_id.clone();
+                }
+                self.reminders_changed();
+            }
+            return;
+        }
+
+        tracing::warn!(
+            temp_id,
+            list_id,
+            "Swift rejected the new reminder, removing it"
+        );
+        let selected_id = self.selected_reminder_id();
+        self.current_reminders.retain(|r| r.id != temp_id);
+        self.all_reminders.retain(|(r, _)| r.id != temp_id);
+        self.reminders_changed();
+        self.adjust_list_count(Some(&list_id), -1);
+        self.add_status_log("❌ Failed to create reminder".to_string());
+        self.restore_selection(selected_id.as_deref());
+    }
+
+    pub fn get_lists(&self) -> &[ReminderList] {
+        &self.lists
     }
 
     pub fn add_status_log(&mut self, message: String) {
+        tracing::info!(target: "rem_core::status", "{message}");
         self.status_log.push(message);
         // Keep only last 5 messages to avoid UI clutter
         if self.status_log.len() > 5 {
@@ -250,6 +832,7 @@ impl TUIApp {
     }
 
     pub fn set_lists(&mut self, lists: Vec<ReminderList>) {
+        self.cache.set_lists(&lists);
         self.lists = lists;
         if !self.lists.is_empty() && matches!(self.current_view, AppView::Loading) {
             self.current_view = AppView::Lists;
@@ -258,7 +841,7 @@ impl TUIApp {
     }
 
     fn reset_selection_for_filtered_reminders(&mut self) {
-        let filtered_count = self.get_filtered_reminders().len();
+        let filtered_count = self.filtered_indices().len();
         if filtered_count == 0 {
             self.selected_index = 0;
             self.list_state.select(None);
@@ -270,47 +853,95 @@ impl TUIApp {
         }
     }
 
-    fn get_filtered_reminders(&self) -> Vec<&Reminder> {
-        // For global search, we need to search across all reminders
-        let base_reminders: Vec<&Reminder> = if self.search_state.is_global {
-            // Use all_reminders for global search (regardless of has_results)
-            if self.show_completed_todos {
-                self.all_reminders.iter().map(|(r, _)| r).collect()
-            } else {
+    /// Note that the reminders changed, so the filtered ones are worked out again.
+    fn reminders_changed(&mut self) {
+        self.reminders_version += 1;
+    }
+
+    fn filter_key(&self) -> FilterKey {
+        let search = &self.search_state;
+        FilterKey {
+            reminders_version: self.reminders_version,
+            // For global search, we need to search across all reminders
+            global: search.is_global,
+            show_completed: self.show_completed_todos,
+            query: (search.has_results && !search.query.is_empty())
+                .then(|| search.query.to_lowercase()),
+        }
+    }
+
+    /// The shown reminders as indices into `all_reminders` for global search, or
+    /// `current_reminders` otherwise.
+    fn filtered_indices(&self) -> Ref<'_, [usize]> {
+        self.indices(self.filter_key(), &self.filtered)
+    }
+
+    /// The shown reminders matching the search query, as indices like those of
+    /// `filtered_indices`, whether or not the query filters the list.
+    fn match_indices(&self) -> Ref<'_, [usize]> {
+        let key = FilterKey {
+            query: Some(self.search_state.query.to_lowercase()),
+            ..self.filter_key()
+        };
+        self.indices(key, &self.matches)
+    }
+
+    /// The reminders `key` keeps, from `cache` unless it was worked out for another key.
+    fn indices<'a>(
+        &'a self,
+        key: FilterKey,
+        cache: &'a RefCell<Option<(FilterKey, Vec<usize>)>>,
+    ) -> Ref<'a, [usize]> {
+        let stale = cache
+            .borrow()
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != key);
+        if stale {
+            let keep = |reminder: &Reminder| {
+                (key.show_completed || !reminder.completed)
+                    && key
+                        .query
+                        .as_deref()
+                        .is_none_or(|query| matches_query(reminder, query))
+            };
+            let indices: Vec<usize> = if key.global {
                 self.all_reminders
                     .iter()
-                    .map(|(r, _)| r)
-                    .filter(|r| !r.completed)
+                    .enumerate()
+                    .filter(|(_, (reminder, _))| keep(reminder))
+                    .map(|(index, _)| index)
                     .collect()
-            }
-        } else {
-            // Use current_reminders for list-specific view
-            if self.show_completed_todos {
-                self.current_reminders.iter().collect()
             } else {
                 self.current_reminders
                     .iter()
-                    .filter(|r| !r.completed)
+                    .enumerate()
+                    .filter(|(_, reminder)| keep(reminder))
+                    .map(|(index, _)| index)
                     .collect()
-            }
-        };
+            };
+            *cache.borrow_mut() = Some((key, indices));
+        }
+        Ref::map(cache.borrow(), |cached| {
+            cached
+                .as_ref()
+                .map_or(&[][..], |(_, indices)| indices.as_slice())
+        })
+    }
 
-        let mut reminders = base_reminders;
-
-        // Apply search filter if active
-        if self.search_state.has_results && !self.search_state.query.is_empty() {
-            let query = self.search_state.query.to_lowercase();
-            reminders.retain(|reminder| {
-                // Search in title and notes
-                reminder.title.to_lowercase().contains(&query)
-                    || reminder
-                        .notes
-                        .as_ref()
-                        .is_some_and(|notes| notes.to_lowercase().contains(&query))
-            });
+    /// A reminder by its index from `filtered_indices`.
+    fn filtered_source(&self, index: usize) -> &Reminder {
+        if self.search_state.is_global {
+            &self.all_reminders[index].0
+        } else {
+            &self.current_reminders[index]
         }
+    }
 
-        reminders
+    fn get_filtered_reminders(&self) -> Vec<&Reminder> {
+        self.filtered_indices()
+            .iter()
+            .map(|&index| self.filtered_source(index))
+            .collect()
     }
 
     // Public method for testing
@@ -318,6 +949,22 @@ impl TUIApp {
         self.get_filtered_reminders()
     }
 
+    pub fn handle_key_event_for_test(&mut self, key: crossterm::event::KeyEvent) {
+        self.handle_key_event(key);
+    }
+
+    pub fn handle_mouse_event_for_test(&mut self, mouse: MouseEvent) {
+        self.handle_mouse_event(mouse);
+    }
+
+    pub fn handle_event_for_test(&mut self, event: Event) {
+        self.handle_event(event);
+    }
+
+    pub fn get_selected_index(&self) -> usize {
+        self.selected_index
+    }
+
     // Public field access for testing
     pub fn show_completed_todos(&self) -> bool {
         self.show_completed_todos
@@ -450,13 +1097,10 @@ impl TUIApp {
             if event::poll(Duration::from_millis(50)).map_err(|e| RemError::TUIError {
                 message: e.to_string(),
             })? {
-                if let Event::Key(key) = event::read().map_err(|e| RemError::TUIError {
+                let event = event::read().map_err(|e| RemError::TUIError {
                     message: e.to_string(),
-                })? {
-                    if key.kind == KeyEventKind::Press {
-                        self.handle_key_event(key);
-                    }
-                }
+                })?;
+                self.handle_event(event);
             }
 
             if !self.actions.is_empty() {
@@ -558,11 +1202,7 @@ impl TUIApp {
         self.actions.clear();
 
         loop {
-            terminal
-                .draw(|f| self.ui(f))
-                .map_err(|e| RemError::TUIError {
-                    message: e.to_string(),
-                })?;
+            self.draw(terminal)?;
 
             if self.should_exit {
                 break;
@@ -571,13 +1211,10 @@ impl TUIApp {
             if event::poll(Duration::from_millis(50)).map_err(|e| RemError::TUIError {
                 message: e.to_string(),
             })? {
-                if let Event::Key(key) = event::read().map_err(|e| RemError::TUIError {
+                let event = event::read().map_err(|e| RemError::TUIError {
                     message: e.to_string(),
-                })? {
-                    if key.kind == KeyEventKind::Press {
-                        self.handle_key_event(key);
-                    }
-                }
+                })?;
+                self.handle_event(event);
             }
 
             if !self.actions.is_empty() {
@@ -598,11 +1235,7 @@ impl TUIApp {
 
             // Inner loop for handling user input and displaying UI
             loop {
-                terminal
-                    .draw(|f| self.ui(f))
-                    .map_err(|e| RemError::TUIError {
-                        message: e.to_string(),
-                    })?;
+                self.draw(terminal)?;
 
                 if self.should_exit {
                     return Ok(vec![TuiAction::Quit]);
@@ -611,17 +1244,15 @@ impl TUIApp {
                 if event::poll(Duration::from_millis(50)).map_err(|e| RemError::TUIError {
                     message: e.to_string(),
                 })? {
-                    if let Event::Key(key) = event::read().map_err(|e| RemError::TUIError {
+                    let event = event::read().map_err(|e| RemError::TUIError {
                         message: e.to_string(),
-                    })? {
-                        if key.kind == KeyEventKind::Press {
-                            self.handle_key_event(key);
-                        }
-                    }
+                    })?;
+                    self.handle_event(event);
                 }
 
                 // If we have actions, process them
                 if !self.actions.is_empty() {
+                    tracing::info!(actions = ?self.actions, "actions emitted");
                     break;
                 }
             }
@@ -644,11 +1275,7 @@ impl TUIApp {
                 let mut frames_shown = 0;
                 while frames_shown < 5 {
                     // Show loading for at least 5 frames (~250ms)
-                    terminal
-                        .draw(|f| self.ui(f))
-                        .map_err(|e| RemError::TUIError {
-                            message: e.to_string(),
-                        })?;
+                    self.draw(terminal)?;
                     frames_shown += 1;
                     thread::sleep(Duration::from_millis(50));
                 }
@@ -656,11 +1283,7 @@ impl TUIApp {
                 // Continue showing loading screen until data is loaded
                 // This keeps the TUI visible while Swift processes the action
                 while matches!(self.current_view, AppView::Loading) {
-                    terminal
-                        .draw(|f| self.ui(f))
-                        .map_err(|e| RemError::TUIError {
-                            message: e.to_string(),
-                        })?;
+                    self.draw(terminal)?;
                     thread::sleep(Duration::from_millis(50));
 
                     // Check if we should exit while waiting
@@ -695,34 +1318,58 @@ impl TUIApp {
         &mut self,
         terminal: &mut Terminal<B>,
     ) -> Result<Vec<TuiAction>, RemError> {
-        self.actions.clear();
+        self.iterate(terminal, || {
+            let to_tui_error = |e: io::Error| RemError::TUIError {
+                message: e.to_string(),
+            };
+            if event::poll(Duration::from_millis(50)).map_err(to_tui_error)? {
+                event::read().map(Some).map_err(to_tui_error)
+            } else {
+                Ok(None)
+            }
+        })
+    }
 
-        // Handle the display and input for one iteration
+    /// `run_persistent_iteration` with scripted events instead of the terminal's;
+    /// running out of them before any action is emitted is an error.
+    pub fn run_persistent_iteration_for_test<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Result<Vec<TuiAction>, RemError> {
+        let mut events = events.into_iter();
+        self.iterate(terminal, || {
+            events.next().map(Some).ok_or_else(|| RemError::TUIError {
+                message: "Ran out of events".to_string(),
+            })
+        })
+    }
+
+    /// Draw and handle events from `next_event` until there are actions for Swift.
+    fn iterate<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        mut next_event: impl FnMut() -> Result<Option<Event>, RemError>,
+    ) -> Result<Vec<TuiAction>, RemError> {
+        // Actions queued in between, like reopening the restored list, go out with
+        // this iteration's. Handle the display and input for one iteration
         loop {
-            terminal
-                .draw(|f| self.ui(f))
-                .map_err(|e| RemError::TUIError {
-                    message: e.to_string(),
-                })?;
+            self.draw(terminal)?;
 
             if self.should_exit {
                 return Ok(vec![TuiAction::Quit]);
             }
 
-            if event::poll(Duration::from_millis(50)).map_err(|e| RemError::TUIError {
-                message: e.to_string(),
-            })? {
-                if let Event::Key(key) = event::read().map_err(|e| RemError::TUIError {
-                    message: e.to_string(),
-                })? {
-                    if key.kind == KeyEventKind::Press {
-                        self.handle_key_event(key);
-                    }
-                }
+            if let Some(event) = next_event()? {
+                self.handle_event(event);
             }
 
-            // If we have actions to process, return them to Swift
+            // If we have actions to process, return them to Swift. Draw first: Swift
+            // holds on to the TUI until they are done, and what the event changed,
+            // like a cached list's rows, should be on screen meanwhile
             if !self.actions.is_empty() {
+                tracing::info!(actions = ?self.actions, "actions emitted");
+                self.draw(terminal)?;
                 break;
             }
 
@@ -739,10 +1386,110 @@ impl TUIApp {
             }
         }
 
-        Ok(self.actions.clone())
+        Ok(std::mem::take(&mut self.actions))
+    }
+
+    /// Render a single frame without polling for input. Used by headless drivers
+    /// such as the snapshot test harness.
+    pub fn draw<B: ratatui::backend::Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+    ) -> Result<(), RemError> {
+        let to_tui_error = |e: io::Error| RemError::TUIError {
+            message: e.to_string(),
+        };
+        // After a resize, start from a blank screen so no stale cells survive the
+        // switch to a different layout
+        if std::mem::take(&mut self.resized) {
+            terminal.autoresize().map_err(to_tui_error)?;
+            terminal.clear().map_err(to_tui_error)?;
+        }
+        let started = Instant::now();
+        terminal.draw(|f| self.ui(f)).map_err(to_tui_error)?;
+        let elapsed = started.elapsed();
+        if elapsed > SLOW_FRAME {
+            tracing::debug!(elapsed_ms = elapsed.as_millis() as u64, "slow frame");
+        }
+        Ok(())
+    }
+
+    /// Drain the actions emitted since the last call.
+    pub fn take_actions(&mut self) -> Vec<TuiAction> {
+        std::mem::take(&mut self.actions)
+    }
+
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key_event(key),
+            Event::Mouse(mouse) => self.handle_mouse_event(mouse),
+            Event::Resize(..) => self.resized = true,
+            _ => {}
+        }
+    }
+
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        let scroll_key = match mouse.kind {
+            MouseEventKind::ScrollDown => Some(KeyCode::Down),
+            MouseEventKind::ScrollUp => Some(KeyCode::Up),
+            _ => None,
+        };
+        if let Some(code) = scroll_key {
+            // Arrows change field values in the form, so the wheel only moves selections
+            if !self.search_state.is_active
+                && !matches!(
+                    self.current_view,
+                    AppView::Loading | AppView::CreateReminder
+                )
+            {
+                self.handle_key_event(crossterm::event::KeyEvent::from(code));
+            }
+            return;
+        }
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return;
+        }
+
+        let Some(target) = self.hit_map.target_at(mouse.column, mouse.row) else {
+            self.last_click = None;
+            return;
+        };
+        let double_click = self.last_click.is_some_and(|(at, previous)| {
+            previous == target && at.elapsed() <= mouse::DOUBLE_CLICK
+        });
+        // A third click starts a new double-click rather than extending this one
+        self.last_click = (!double_click).then(|| (Instant::now(), target));
+
+        match target {
+            Target::Row(index) => {
+                self.selected_index = index;
+                self.list_state.select(Some(index));
+                if self.two_pane && matches!(self.current_view, AppView::Lists) {
+                    self.request_preview();
+                }
+                if double_click && matches!(self.current_view, AppView::Lists) {
+                    self.handle_key_event(crossterm::event::KeyEvent::from(KeyCode::Enter));
+                }
+            }
+            Target::Checkbox(index) => {
+                self.selected_index = index;
+                self.list_state.select(Some(index));
+                self.handle_key_event(crossterm::event::KeyEvent::from(KeyCode::Char(' ')));
+            }
+            Target::Hint(key) => {
+                if !self.search_state.is_active {
+                    self.handle_key_event(key);
+                }
+            }
+        }
     }
 
     fn handle_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        // The open log pane takes the keys until it is closed
+        if self.log_pane.open {
+            self.handle_log_key_event(key);
+            return;
+        }
+
         // Handle search mode first
         if self.search_state.is_active {
             self.handle_search_key_event(key);
@@ -770,6 +1517,12 @@ impl TUIApp {
             }
         }
 
+        if key.code == KeyCode::Char('`') && !matches!(self.current_view, AppView::CreateReminder) {
+            self.log_pane.open = true;
+            self.log_pane.scroll = 0;
+            return;
+        }
+
         // Handle search activation
         if key.code == KeyCode::Char('/') {
             match &self.current_view {
@@ -808,6 +1561,8 @@ impl TUIApp {
             AppView::Lists => self.handle_lists_key_event(key),
             AppView::Reminders { list_id } => self.handle_reminders_key_event(key, list_id.clone()),
             AppView::CreateReminder => self.handle_create_reminder_key_event(key),
+            AppView::Agenda => self.handle_agenda_key_event(key),
+            AppView::Board => self.handle_board_key_event(key),
         }
 
         // Update last key for sequence tracking with timing
@@ -815,12 +1570,43 @@ impl TUIApp {
         self.last_key_time = Some(Instant::now());
     }
 
-    fn handle_search_key_event(&mut self, key: crossterm::event::KeyEvent) {
+    fn handle_log_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        let total = self.log.records(self.log_pane.level).len();
+        let page = log_pane::PAGE as isize;
         match key.code {
-            KeyCode::Esc => {
-                let now = Instant::now();
-                if let Some(last_escape) = self.search_state.last_escape_time {
-                    // Double escape within 1 second = clear search completely
+            KeyCode::Char('`') | KeyCode::Esc | KeyCode::Char('q') => self.log_pane.open = false,
+            KeyCode::Up | KeyCode::Char('k') => self.log_pane.scroll_by(1, total),
+            KeyCode::Down | KeyCode::Char('j') => self.log_pane.scroll_by(-1, total),
+            KeyCode::PageUp => self.log_pane.scroll_by(page, total),
+            KeyCode::PageDown => self.log_pane.scroll_by(-page, total),
+            KeyCode::Char('g') | KeyCode::Home => self.log_pane.scroll_to_oldest(total),
+            KeyCode::Char('G') | KeyCode::End => self.log_pane.scroll = 0,
+            KeyCode::Char('l') => self.log_pane.cycle_level(),
+            _ => {}
+        }
+    }
+
+    /// The records the log pane reads; events reach it once `logging::init`'s buffer is
+    /// set with `set_log_buffer`.
+    pub fn log_buffer(&self) -> &LogBuffer {
+        &self.log
+    }
+
+    pub fn set_log_buffer(&mut self, buffer: LogBuffer) {
+        self.log = buffer;
+    }
+
+    pub fn is_log_pane_open(&self) -> bool {
+        self.log_pane.open
+    }
+
+    fn handle_search_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.remember_search();
+                let now = Instant::now();
+                if let Some(last_escape) = self.search_state.last_escape_time {
+                    // Double escape within 1 second = clear search completely
                     if now.duration_since(last_escape) < Duration::from_millis(1000) {
                         self.search_state.clear_search();
                         self.reset_selection_for_filtered_reminders();
@@ -862,7 +1648,8 @@ impl TUIApp {
             KeyCode::Enter => {
                 // Enter confirms search and exits search mode
                 self.search_state.exit_search();
-                let query = &self.search_state.query;
+                self.remember_search();
+                let query = self.search_state.query.clone();
                 if !query.is_empty() {
                     self.add_status_log(format!("🔍 Search for '{query}' applied"));
                 } else {
@@ -871,12 +1658,116 @@ impl TUIApp {
                 }
                 self.reset_selection_for_filtered_reminders();
             }
+            KeyCode::Up | KeyCode::Down => {
+                let history = self.search_history().to_vec();
+                self.search_state
+                    .browse_history(&history, key.code == KeyCode::Up);
+                self.reset_selection_for_filtered_reminders();
+            }
             _ => {
                 // Ignore other keys in search mode
             }
         }
     }
 
+    /// Past searches in the current scope: global, or the open list's.
+    fn search_history(&self) -> &[String] {
+        if self.search_state.is_global {
+            return &self.recent_searches;
+        }
+        self.active_list_id()
+            .and_then(|id| self.list_searches.get(&id))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    fn remember_search(&mut self) {
+        let query = self.search_state.query.clone();
+        if query.is_empty() {
+            return;
+        }
+        let history = if self.search_state.is_global {
+            &mut self.recent_searches
+        } else if let Some(list_id) = self.active_list_id() {
+            self.list_searches.entry(list_id).or_default()
+        } else {
+            return;
+        };
+        session::remember_search(history, &query);
+    }
+
+    /// The query `n`/`N` look for: the one on screen, or the last one searched here.
+    fn jump_query(&self) -> Option<String> {
+        if !self.search_state.query.is_empty() {
+            return Some(self.search_state.query.clone());
+        }
+        self.search_history().first().cloned()
+    }
+
+    /// Move the selection to the next (or previous) match, wrapping around, with the
+    /// whole list shown rather than just the matches.
+    fn jump_to_match(&mut self, forward: bool) {
+        let Some(query) = self.jump_query() else {
+            self.add_status_log("🔍 No previous search".to_string());
+            return;
+        };
+        // Keep the cursor on the same reminder when the filter comes off
+        let current = self.selected_reminder_id();
+        self.search_state.query = query.clone();
+        self.search_state.has_results = false;
+        self.search_state.jumping = true;
+
+        let (selected, next) = {
+            // Both are in source order, and the matches are among the shown rows
+            let shown = self.filtered_indices();
+            let matches = self.match_indices();
+            let position = current.and_then(|id| {
+                shown
+                    .iter()
+                    .position(|&index| self.filtered_source(index).id == id)
+            });
+            let selected = position.unwrap_or(self.selected_index);
+            let next = match shown.get(selected) {
+                Some(&at) if forward => matches[matches.partition_point(|&i| i <= at)..]
+                    .first()
+                    .or(matches.first()),
+                Some(&at) => matches[..matches.partition_point(|&i| i < at)]
+                    .last()
+                    .or(matches.last()),
+                None if forward => matches.first(),
+                None => matches.last(),
+            };
+            let next = next.and_then(|index| shown.binary_search(index).ok());
+            (selected, next)
+        };
+
+        self.selected_index = selected;
+        match next {
+            Some(index) => {
+                self.selected_index = index;
+                self.list_state.select(Some(index));
+            }
+            None => self.add_status_log(format!("🔍 No matches for '{query}'")),
+        }
+    }
+
+    /// "3/17": which match is selected, out of how many in the shown reminders.
+    fn match_counter(&self) -> Option<String> {
+        if self.search_state.query.is_empty() {
+            return None;
+        }
+        let matches = self.match_indices();
+        let total = matches.len();
+        let current = self
+            .filtered_indices()
+            .get(self.selected_index)
+            .and_then(|index| matches.binary_search(index).ok())
+            .map(|position| position + 1);
+        Some(match current {
+            Some(current) => format!("{current}/{total}"),
+            None => format!("-/{total}"),
+        })
+    }
+
     fn update_search_results(&mut self) {
         let query = &self.search_state.query;
         self.search_state.has_results = !query.is_empty();
@@ -892,46 +1783,29 @@ impl TUIApp {
     }
 
     fn handle_lists_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        if self.two_pane {
+            match key.code {
+                KeyCode::Tab | KeyCode::Enter => {
+                    self.focus_reminders_pane();
+                    return;
+                }
+                KeyCode::Up | KeyCode::Char('k') | KeyCode::Down | KeyCode::Char('j') => {
+                    self.move_list_selection(key.code);
+                    self.request_preview();
+                    return;
+                }
+                _ => {}
+            }
+        }
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.actions.push(TuiAction::Quit);
                 self.should_exit = true;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if !self.lists.is_empty() {
-                    if self.selected_index > 0 {
-                        self.selected_index -= 1;
-                    } else {
-                        self.selected_index = self.lists.len() - 1;
-                    }
-                    self.list_state.select(Some(self.selected_index));
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if !self.lists.is_empty() {
-                    if self.selected_index < self.lists.len() - 1 {
-                        self.selected_index += 1;
-                    } else {
-                        self.selected_index = 0;
-                    }
-                    self.list_state.select(Some(self.selected_index));
-                }
-            }
-            KeyCode::Enter => {
-                if let Some(list) = self.lists.get(self.selected_index) {
-                    let list_id = list.id.clone();
-                    let list_name = list.name.clone();
-
-                    // Show loading screen immediately before any network call
-                    self.is_loading = true;
-                    self.loading_message = format!("Loading {list_name} reminders...");
-                    self.current_view = AppView::Loading;
-                    self.add_status_log(format!("📋 Loading {list_name} reminders..."));
-
-                    // Push action for Swift to handle
-                    self.actions.push(TuiAction::SelectList { list_id });
-                }
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Down | KeyCode::Char('j') => {
+                self.move_list_selection(key.code);
             }
+            KeyCode::Enter => self.open_list(),
             KeyCode::Char('c') => {
                 let default_list_id = if !self.lists.is_empty() {
                     Some(self.lists[self.selected_index].id.clone())
@@ -956,10 +1830,464 @@ impl TUIApp {
                 // Note: We don't push the action here as this is for lists view
                 // The action would cause the app to exit this view
             }
+            KeyCode::Char('a') => self.open_agenda(Local::now().date_naive()),
+            KeyCode::Char('b') => self.open_board(),
+            KeyCode::Char('v') => {
+                self.two_pane = !self.two_pane;
+                if self.two_pane {
+                    self.add_status_log("🪟 Two-pane layout".to_string());
+                    self.request_preview();
+                } else {
+                    self.add_status_log("🪟 Single-pane layout".to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn open_list(&mut self) {
+        if let Some(list) = self.lists.get(self.selected_index) {
+            self.lists_index = self.selected_index;
+            let list_id = list.id.clone();
+            let list_name = list.name.clone();
+
+            if let Some(entry) = self.cache.get(&list_id) {
+                // Show what the list had last time while Swift fetches it again
+                let age = cache::age_label(entry.fetched_at, self.now().timestamp());
+                self.current_reminders = entry.reminders.clone();
+                self.reminders_changed();
+                self.pending_changes.clear();
+                self.is_loading = false;
+                self.current_view = AppView::Reminders {
+                    list_id: list_id.clone(),
+                };
+                self.selected_index = self.remembered_selection();
+                self.reset_selection_for_filtered_reminders();
+                self.add_status_log(format!("📋 {list_name} (cached {age}), refreshing..."));
+            } else {
+                // Show loading screen immediately before any network call
+                self.is_loading = true;
+                self.loading_message = format!("Loading {list_name} reminders...");
+                self.current_view = AppView::Loading;
+                self.add_status_log(format!("📋 Loading {list_name} reminders..."));
+            }
+
+            // Push action for Swift to handle
+            self.current_list_id = Some(list_id.clone());
+            self.preview_list_id = Some(list_id.clone());
+            self.actions.push(TuiAction::SelectList { list_id });
+        }
+    }
+
+    fn move_list_selection(&mut self, code: KeyCode) {
+        if self.lists.is_empty() {
+            return;
+        }
+        let last = self.lists.len() - 1;
+        self.selected_index = match code {
+            KeyCode::Up | KeyCode::Char('k') if self.selected_index == 0 => last,
+            KeyCode::Up | KeyCode::Char('k') => self.selected_index - 1,
+            _ if self.selected_index >= last => 0,
+            _ => self.selected_index + 1,
+        };
+        self.list_state.select(Some(self.selected_index));
+    }
+
+    /// Ask Swift for the highlighted list's reminders to show in the right pane.
+    fn request_preview(&mut self) {
+        let Some(list) = self.lists.get(self.selected_index) else {
+            return;
+        };
+        if self.preview_list_id.as_deref() == Some(list.id.as_str()) {
+            return;
+        }
+        let list_id = list.id.clone();
+        if let Some(entry) = self.cache.get(&list_id) {
+            self.current_reminders = entry.reminders.clone();
+            self.reminders_changed();
+        }
+        self.preview_list_id = Some(list_id.clone());
+        self.current_list_id = Some(list_id.clone());
+        self.actions.push(TuiAction::PreviewList { list_id });
+    }
+
+    fn focus_reminders_pane(&mut self) {
+        let Some(list) = self.lists.get(self.selected_index) else {
+            return;
+        };
+        if self.preview_list_id.as_deref() != Some(list.id.as_str()) {
+            // Nothing previewed yet; load it the single-pane way
+            self.open_list();
+            return;
+        }
+        self.lists_index = self.selected_index;
+        self.current_view = AppView::Reminders {
+            list_id: list.id.clone(),
+        };
+        self.selected_index = self.remembered_selection();
+        self.reset_selection_for_filtered_reminders();
+    }
+
+    /// Leave the reminders with the list they came from highlighted again.
+    fn return_to_lists(&mut self) {
+        if self.is_in_global_search_view() {
+            // The search results replaced the preview
+            self.preview_list_id = None;
+        }
+        self.remember_selection();
+        self.current_view = AppView::Lists;
+        self.selected_index = self.lists_index.min(self.lists.len().saturating_sub(1));
+        self.list_state.select(Some(self.selected_index));
+        if self.two_pane {
+            self.request_preview();
+        }
+    }
+
+    /// The selection last used in the open list.
+    fn remembered_selection(&self) -> usize {
+        match &self.current_view {
+            AppView::Reminders { list_id } => {
+                self.list_selection.get(list_id).copied().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    fn remember_selection(&mut self) {
+        if let AppView::Reminders { list_id } = &self.current_view {
+            if list_id != "global" {
+                self.list_selection
+                    .insert(list_id.clone(), self.selected_index);
+            }
+        }
+    }
+
+    /// Restore the UI state saved at `path` and keep saving there. The last open list
+    /// is opened again (or previewed in the two-pane layout) if it still exists.
+    pub fn restore_session(&mut self, path: PathBuf) {
+        let saved = Session::load(&path).unwrap_or_default();
+        self.session_path = Some(path);
+        self.show_completed_todos = saved.show_completed;
+        self.board.grouping = saved.board_grouping;
+        self.agenda.mode = saved.agenda_mode;
+        self.two_pane = saved.two_pane;
+        self.list_selection = saved.selection;
+        self.recent_searches = saved.recent_searches;
+        self.list_searches = saved.list_searches;
+
+        if !matches!(self.current_view, AppView::Lists) {
+            return;
+        }
+        let Some(index) = saved
+            .last_list_id
+            .and_then(|id| self.lists.iter().position(|l| l.id == id))
+        else {
+            return;
+        };
+        self.selected_index = index;
+        self.lists_index = index;
+        self.list_state.select(Some(index));
+        if self.two_pane {
+            self.request_preview();
+        } else if saved.list_open {
+            self.open_list();
+        }
+    }
+
+    /// The UI state as it would be restored on the next launch.
+    pub fn session(&self) -> Session {
+        let mut selection = self.list_selection.clone();
+        let (last_list, list_open) = match &self.current_view {
+            AppView::Reminders { list_id } if list_id != "global" => {
+                selection.insert(list_id.clone(), self.selected_index);
+                (Some(list_id.clone()), true)
+            }
+            AppView::Lists => (
+                self.lists.get(self.selected_index).map(|l| l.id.clone()),
+                false,
+            ),
+            _ => (
+                self.lists.get(self.lists_index).map(|l| l.id.clone()),
+                false,
+            ),
+        };
+        Session {
+            last_list_id: last_list,
+            list_open,
+            selection,
+            show_completed: self.show_completed_todos,
+            board_grouping: self.board.grouping,
+            agenda_mode: self.agenda.mode,
+            two_pane: self.two_pane,
+            recent_searches: self.recent_searches.clone(),
+            list_searches: self.list_searches.clone(),
+            ..Session::default()
+        }
+    }
+
+    /// Save the UI state for the next launch, if it was restored from a session.
+    pub fn save_session(&self) {
+        if let Some(path) = &self.session_path {
+            if let Err(e) = self.session().save(path) {
+                tracing::warn!("Failed to save session to {}: {e}", path.display());
+            }
+        }
+    }
+
+    /// Load the reminders cached at `path` and keep saving there. Call before
+    /// `restore_session` so the reopened list shows its cached reminders. Without lists
+    /// yet, the cached lists are shown until Swift sends fresh ones.
+    pub fn restore_cache(&mut self, path: PathBuf) {
+        self.cache = ReminderCache::load(&path).unwrap_or_default();
+        self.cache_path = Some(path);
+        if self.lists.is_empty() {
+            let lists = self.cache.lists.clone();
+            self.set_lists(lists);
+        } else {
+            self.cache.set_lists(&self.lists);
+        }
+    }
+
+    /// Save the cache for the next launch, if it was restored from a file.
+    pub fn save_cache(&self) {
+        if let Some(path) = &self.cache_path {
+            if let Err(e) = self.cache.save(path) {
+                tracing::warn!("Failed to save reminder cache to {}: {e}", path.display());
+            }
+        }
+    }
+
+    /// Remember a list fetched by Swift. Search results are not a list and stay out.
+    fn cache_fetched(&mut self, reminders: &[Reminder]) {
+        if self.search_state.is_global || self.is_in_global_search_view() {
+            return;
+        }
+        if let Some(list_id) = self.current_list_id.clone() {
+            let fetched_at = self.now().timestamp();
+            self.cache.insert(&list_id, reminders.to_vec(), fetched_at);
+        }
+    }
+
+    /// Freeze the clock relative due dates are measured from.
+    pub fn pin_clock(&mut self, now: DateTime<Local>) {
+        self.pinned_now = Some(now);
+    }
+
+    fn now(&self) -> DateTime<Local> {
+        self.pinned_now.unwrap_or_else(Local::now)
+    }
+
+    /// Ask Swift for every list's reminders, so the lists can show overdue counts.
+    pub fn load_due_dates(&mut self) {
+        self.actions.push(TuiAction::LoadAllReminders);
+    }
+
+    /// Incomplete overdue reminders per list name, from the reminders of every list.
+    fn overdue_counts(&self) -> HashMap<&str, usize> {
+        let now = self.now();
+        let mut counts = HashMap::new();
+        for (reminder, list_name) in &self.all_reminders {
+            let overdue = !reminder.completed
+                && reminder
+                    .due_date
+                    .as_deref()
+                    .and_then(Due::parse)
+                    .is_some_and(|due| due.status(now) == DueStatus::Overdue);
+            if overdue {
+                *counts.entry(list_name.as_str()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Switch to the agenda with `today` selected and ask Swift for every list's reminders.
+    pub fn open_agenda(&mut self, today: NaiveDate) {
+        // Back on today, in the week or day mode it was left in
+        self.agenda = AgendaState {
+            mode: self.agenda.mode,
+            ..AgendaState::new(today)
+        };
+        self.current_view = AppView::Agenda;
+        self.add_status_log("⏳ Loading due reminders...".to_string());
+        self.actions.push(TuiAction::LoadAllReminders);
+    }
+
+    fn handle_agenda_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.actions.push(TuiAction::Back);
+                self.current_view = AppView::Lists;
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.agenda.move_days(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.agenda.move_days(1),
+            KeyCode::Up | KeyCode::Char('k') => self.agenda.move_days(-7),
+            KeyCode::Down | KeyCode::Char('j') => self.agenda.move_days(7),
+            KeyCode::Char('[') => self.agenda.move_months(-1),
+            KeyCode::Char(']') => self.agenda.move_months(1),
+            KeyCode::Char('t') => self.agenda.selected = self.agenda.today,
+            KeyCode::Char('w') => self.agenda.toggle_mode(),
+            KeyCode::Char('c') => {
+                self.previous_view = Some(self.current_view.clone());
+                let mut form = CreateReminderForm::new(&self.lists, self.current_list_id.clone());
+                // A plain date makes an all-day reminder
+                form.due_date = self.agenda.selected.format("%Y-%m-%d").to_string();
+                self.create_form = Some(form);
+                self.current_view = AppView::CreateReminder;
+            }
+            _ => {}
+        }
+    }
+
+    /// Switch to the board and ask Swift for every list's reminders.
+    pub fn open_board(&mut self) {
+        // Focus starts over; the grouping is kept
+        self.board = BoardState {
+            grouping: self.board.grouping,
+            ..BoardState::new()
+        };
+        self.current_view = AppView::Board;
+        self.add_status_log("⏳ Loading board...".to_string());
+        self.actions.push(TuiAction::LoadAllReminders);
+    }
+
+    fn board_columns(&self) -> Vec<BoardColumn> {
+        board::columns(
+            self.board.grouping,
+            &self.lists,
+            &self.all_reminders,
+            self.show_completed_todos,
+            self.now().date_naive(),
+        )
+    }
+
+    fn handle_board_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        let columns = self.board_columns();
+        self.board.clamp(&columns);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.actions.push(TuiAction::Back);
+                self.current_view = AppView::Lists;
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.board.move_column(-1, &columns),
+            KeyCode::Right | KeyCode::Char('l') => self.board.move_column(1, &columns),
+            KeyCode::Up | KeyCode::Char('k') => self.board.move_card(-1, &columns),
+            KeyCode::Down | KeyCode::Char('j') => self.board.move_card(1, &columns),
+            KeyCode::Char('H') => self.move_board_card(-1, &columns),
+            KeyCode::Char('L') => self.move_board_card(1, &columns),
+            KeyCode::Char('g') => {
+                self.board.cycle_grouping();
+                let label = self.board.grouping.label();
+                self.add_status_log(format!("🗂️ Grouped by {label}"));
+            }
+            KeyCode::Char(' ') => {
+                if let Some(index) = self.board.selected(&columns) {
+                    let reminder_id = self.all_reminders[index].0.id.clone();
+                    if !self.is_pending_create(&reminder_id) {
+                        self.actions.push(TuiAction::ToggleReminder {
+                            reminder_id: reminder_id.clone(),
+                        });
+                        self.apply_optimistic_toggle(&reminder_id);
+                    }
+                }
+            }
             _ => {}
         }
     }
 
+    /// Move the focused card to the neighbouring column, changing its list or priority.
+    fn move_board_card(&mut self, delta: isize, columns: &[BoardColumn]) {
+        let Some(index) = self.board.selected(columns) else {
+            return;
+        };
+        let Some(target) = self
+            .board
+            .column
+            .checked_add_signed(delta)
+            .filter(|&c| c < columns.len())
+        else {
+            return;
+        };
+        let (reminder, list_name) = self.all_reminders[index].clone();
+        if self.is_pending_create(&reminder.id) {
+            self.add_status_log("⏳ Wait for the new reminder to be saved".to_string());
+            return;
+        }
+
+        match &columns[target].kind {
+            ColumnKind::List {
+                list_id,
+                list_name: to_list_name,
+            } => {
+                let from_list_id = self
+                    .lists
+                    .iter()
+                    .find(|l| l.name == list_name)
+                    .map(|l| l.id.clone());
+                self.set_list_name(&reminder.id, to_list_name);
+                self.cache
+                    .move_reminder(&reminder, from_list_id.as_deref(), list_id);
+                self.adjust_list_count(from_list_id.as_deref(), -1);
+                self.adjust_list_count(Some(list_id), 1);
+                self.pending_changes.push(PendingChange::Move {
+                    reminder_id: reminder.id.clone(),
+                    from_list_id,
+                    from_list_name: list_name,
+                    to_list_id: list_id.clone(),
+                });
+                self.actions.push(TuiAction::MoveReminder {
+                    reminder_id: reminder.id,
+                    list_id: list_id.clone(),
+                });
+            }
+            ColumnKind::Priority(priority) => {
+                self.change_priority(&reminder, *priority);
+            }
+            ColumnKind::Due(_) => {
+                self.add_status_log("📅 Due dates can't be changed from the board".to_string());
+                return;
+            }
+        }
+
+        // Follow the card into its new column
+        let columns = self.board_columns();
+        self.board.column = target;
+        self.board.card = columns[target]
+            .cards
+            .iter()
+            .position(|&i| i == index)
+            .unwrap_or(0);
+    }
+
+    /// Due reminders across all lists, sorted by day with all-day ones first.
+    fn agenda_entries(&self) -> Vec<AgendaEntry> {
+        let mut entries: Vec<AgendaEntry> = self
+            .all_reminders
+            .iter()
+            .filter(|(r, _)| self.show_completed_todos || !r.completed)
+            .filter_map(|(reminder, list_name)| {
+                let due = Due::parse(reminder.due_date.as_deref()?)?;
+                let (date, time) = (due.date(), due.time());
+                let color = self
+                    .lists
+                    .iter()
+                    .find(|l| &l.name == list_name)
+                    .map(|l| parse_color(&l.color))
+                    .unwrap_or(Color::Blue);
+                Some(AgendaEntry {
+                    date,
+                    time,
+                    title: reminder.title.clone(),
+                    list_name: list_name.clone(),
+                    color,
+                    completed: reminder.completed,
+                    pending: self.is_reminder_pending(&reminder.id),
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| (a.date, a.time, &a.title).cmp(&(b.date, b.time, &b.title)));
+        entries
+    }
+
     fn handle_reminders_key_event(&mut self, key: crossterm::event::KeyEvent, list_id: String) {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -969,23 +2297,30 @@ impl TUIApp {
                     self.add_status_log("🔍 Global search closed".to_string());
                 }
                 self.actions.push(TuiAction::Back);
-                self.current_view = AppView::Lists;
+                self.return_to_lists();
             }
+            KeyCode::Tab if self.two_pane && list_id != "global" => self.return_to_lists(),
+            KeyCode::Char(c @ '0'..='3') => {
+                let priority = Priority::ALL[usize::from(c as u8 - b'0')];
+                self.set_selected_priority(priority);
+            }
+            KeyCode::Char('n') => self.jump_to_match(true),
+            KeyCode::Char('N') => self.jump_to_match(false),
             KeyCode::Up | KeyCode::Char('k') => {
-                let filtered_reminders = self.get_filtered_reminders();
-                if !filtered_reminders.is_empty() {
+                let shown = self.filtered_indices().len();
+                if shown > 0 {
                     if self.selected_index > 0 {
                         self.selected_index -= 1;
                     } else {
-                        self.selected_index = filtered_reminders.len() - 1;
+                        self.selected_index = shown - 1;
                     }
                     self.list_state.select(Some(self.selected_index));
                 }
             }
             KeyCode::Down | KeyCode::Char('j') => {
-                let filtered_reminders = self.get_filtered_reminders();
-                if !filtered_reminders.is_empty() {
-                    if self.selected_index < filtered_reminders.len() - 1 {
+                let shown = self.filtered_indices().len();
+                if shown > 0 {
+                    if self.selected_index < shown - 1 {
                         self.selected_index += 1;
                     } else {
                         self.selected_index = 0;
@@ -994,10 +2329,13 @@ impl TUIApp {
                 }
             }
             KeyCode::Enter | KeyCode::Char(' ') => {
-                let filtered_reminders = self.get_filtered_reminders();
-                if let Some(reminder) = filtered_reminders.get(self.selected_index) {
-                    let reminder_id = reminder.id.clone();
+                if let Some(reminder_id) = self.selected_reminder_id() {
+                    if self.is_pending_create(&reminder_id) {
+                        self.add_status_log("⏳ Reminder is still being saved".to_string());
+                        return;
+                    }
                     self.add_status_log("✅ Toggling reminder...".to_string());
+                    self.apply_optimistic_toggle(&reminder_id);
                     self.actions.push(TuiAction::ToggleReminder { reminder_id });
                 }
             }
@@ -1013,23 +2351,13 @@ impl TUIApp {
                 };
 
                 if is_dd_sequence {
-                    let filtered_reminders = self.get_filtered_reminders();
-                    if let Some(reminder) = filtered_reminders.get(self.selected_index) {
-                        self.actions.push(TuiAction::DeleteReminder {
-                            reminder_id: reminder.id.clone(),
-                        });
-                    }
+                    self.delete_selected_reminder();
                 }
                 // Note: last_key will be updated after this function returns
             }
             KeyCode::Delete => {
                 // Alternative: Use Delete key for immediate deletion (no sequence needed)
-                let filtered_reminders = self.get_filtered_reminders();
-                if let Some(reminder) = filtered_reminders.get(self.selected_index) {
-                    self.actions.push(TuiAction::DeleteReminder {
-                        reminder_id: reminder.id.clone(),
-                    });
-                }
+                self.delete_selected_reminder();
             }
             KeyCode::Char('c') => {
                 self.previous_view = Some(self.current_view.clone());
@@ -1053,6 +2381,18 @@ impl TUIApp {
         }
     }
 
+    fn delete_selected_reminder(&mut self) {
+        if let Some(reminder_id) = self.selected_reminder_id() {
+            if self.is_pending_create(&reminder_id) {
+                self.add_status_log("⏳ Reminder is still being saved".to_string());
+                return;
+            }
+            self.add_status_log("🗑️ Deleting reminder...".to_string());
+            self.apply_optimistic_delete(&reminder_id);
+            self.actions.push(TuiAction::DeleteReminder { reminder_id });
+        }
+    }
+
     fn handle_create_reminder_key_event(&mut self, key: crossterm::event::KeyEvent) {
         if let Some(ref mut form) = self.create_form {
             match key.code {
@@ -1078,28 +2418,30 @@ impl TUIApp {
                         .contains(crossterm::event::KeyModifiers::CONTROL) =>
                 {
                     // Ctrl+S to save/submit
-                    if !form.title.trim().is_empty() {
-                        let new_reminder = crate::NewReminder {
-                            title: form.title.clone(),
-                            notes: if form.notes.trim().is_empty() {
-                                None
-                            } else {
-                                Some(form.notes.clone())
-                            },
-                            due_date: if form.due_date.trim().is_empty() {
-                                None
-                            } else {
-                                Some(form.due_date.clone())
-                            },
-                            list_id: form.selected_list_id.clone(),
-                            priority: form.priority,
-                        };
-                        self.actions
-                            .push(TuiAction::CreateReminder { new_reminder });
-                        self.create_form = None;
-                        // Return to previous view or Lists as fallback
-                        self.current_view = self.previous_view.take().unwrap_or(AppView::Lists);
+                    if form.title.trim().is_empty() {
+                        return;
                     }
+                    let new_reminder = crate::NewReminder {
+                        title: form.title.clone(),
+                        notes: if form.notes.trim().is_empty() {
+                            None
+                        } else {
+                            Some(form.notes.clone())
+                        },
+                        due_date: if form.due_date.trim().is_empty() {
+                            None
+                        } else {
+                            Some(form.due_date.clone())
+                        },
+                        list_id: form.selected_list_id.clone(),
+                        priority: form.priority.to_eventkit(),
+                    };
+                    self.create_form = None;
+                    // Return to previous view or Lists as fallback
+                    self.current_view = self.previous_view.take().unwrap_or(AppView::Lists);
+                    self.apply_optimistic_create(&new_reminder);
+                    self.actions
+                        .push(TuiAction::CreateReminder { new_reminder });
                 }
                 KeyCode::Char(c) => {
                     match form.current_field {
@@ -1149,10 +2491,10 @@ impl TUIApp {
                         }
                         4 => {
                             // Priority field
-                            if key.code == KeyCode::Up && form.priority < 9 {
-                                form.priority += 1;
-                            } else if key.code == KeyCode::Down && form.priority > 0 {
-                                form.priority -= 1;
+                            if key.code == KeyCode::Up {
+                                form.priority = form.priority.raise();
+                            } else if key.code == KeyCode::Down {
+                                form.priority = form.priority.lower();
                             }
                         }
                         _ => {}
@@ -1164,11 +2506,19 @@ impl TUIApp {
     }
 
     fn ui(&mut self, f: &mut Frame) {
+        // Hit regions are rebuilt by whichever view draws this frame
+        self.hit_map.clear();
         match &self.current_view {
             AppView::Loading => self.render_loading(f),
+            AppView::Lists if self.two_pane => self.render_two_pane(f),
             AppView::Lists => self.render_lists(f),
+            AppView::Reminders { list_id } if self.two_pane && list_id != "global" => {
+                self.render_two_pane(f)
+            }
             AppView::Reminders { .. } => self.render_reminders(f),
             AppView::CreateReminder => self.render_create_reminder(f),
+            AppView::Agenda => self.render_agenda(f),
+            AppView::Board => self.render_board(f),
         }
     }
 
@@ -1190,15 +2540,7 @@ impl TUIApp {
 
         let area = f.area();
 
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Min(0),    // Loading content
-                Constraint::Length(4), // Controls
-                Constraint::Length(3), // Status log
-            ])
-            .margin(1)
-            .split(area);
+        let areas = self.areas(area, 1, false, 1);
 
         // Create gradient-like effect with different colors
         let dots = "•".repeat((self.loading_animation_state % 4) + 1);
@@ -1285,10 +2627,10 @@ impl TUIApp {
             )
             .alignment(Alignment::Center);
 
-        f.render_widget(paragraph, main_layout[0]);
+        f.render_widget(paragraph, areas.content);
 
         // Loading controls
-        let instructions = Paragraph::new(vec![Line::from(vec![
+        let hints = vec![Line::from(vec![
             Span::styled(
                 "q",
                 Style::default()
@@ -1304,26 +2646,12 @@ impl TUIApp {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" loading...", Style::default().fg(Color::Gray)),
-        ])])
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(Span::styled(
-                    " Controls ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ))
-                .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow)),
-        )
-        .alignment(Alignment::Center);
+        ])];
 
-        f.render_widget(instructions, main_layout[1]);
+        self.render_controls(f, hints, &areas);
 
         // Status log
-        self.render_status_log(f, main_layout[2]);
+        self.render_status_log(f, areas.status);
     }
 
     fn render_lists(&mut self, f: &mut Frame) {
@@ -1366,85 +2694,27 @@ impl TUIApp {
             return;
         }
 
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Min(0),    // List content
-                Constraint::Length(4), // Controls
-                Constraint::Length(3), // Status log
-            ])
-            .margin(1)
-            .split(area);
+        let areas = self.areas(area, 1, false, 2);
 
         // Create list items
+        let overdue = self.overdue_counts();
+        let row = RowLayout::new(list_inner(areas.content), Screen::of(area).compact());
         let items: Vec<ListItem> = self
             .lists
             .iter()
             .enumerate()
             .map(|(i, list)| {
-                let is_selected = i == self.selected_index;
-                let color = parse_color(&list.color);
-
-                let count_text = if list.count == 0 {
-                    "Empty".to_string()
-                } else if list.count == 1 {
-                    "1 reminder".to_string()
-                } else {
-                    format!("{} reminders", list.count)
-                };
-
-                let mut lines = vec![
-                    Line::from(vec![
-                        Span::styled(
-                            if is_selected { "▶ " } else { "  " },
-                            Style::default()
-                                .fg(Color::Cyan)
-                                .add_modifier(Modifier::BOLD),
-                        ),
-                        Span::styled("●", Style::default().fg(color).add_modifier(Modifier::BOLD)),
-                        Span::raw("  "),
-                        Span::styled(
-                            &list.name,
-                            Style::default()
-                                .fg(if is_selected {
-                                    Color::White
-                                } else {
-                                    Color::LightBlue
-                                })
-                                .add_modifier(if is_selected {
-                                    Modifier::BOLD | Modifier::UNDERLINED
-                                } else {
-                                    Modifier::BOLD
-                                }),
-                        ),
-                    ]),
-                    Line::from(vec![
-                        Span::raw("    "),
-                        Span::styled(
-                            count_text,
-                            Style::default().fg(if list.count == 0 {
-                                Color::DarkGray
-                            } else {
-                                Color::Gray
-                            }),
-                        ),
-                    ]),
-                ];
-
-                if i < self.lists.len() - 1 {
-                    lines.push(Line::from(""));
-                }
-
-                let style = if is_selected {
-                    Style::default().bg(Color::DarkGray).fg(Color::White)
-                } else {
-                    Style::default()
-                };
-
-                ListItem::new(lines).style(style)
+                list_item(
+                    list,
+                    i == self.selected_index,
+                    overdue.get(list.name.as_str()).copied().unwrap_or(0),
+                    row,
+                    i < self.lists.len() - 1,
+                )
             })
             .collect();
 
+        let heights: Vec<usize> = items.iter().map(ListItem::height).collect();
         let list_widget = List::new(items)
             .block(
                 Block::default()
@@ -1466,7 +2736,13 @@ impl TUIApp {
                     .add_modifier(Modifier::BOLD),
             );
 
-        f.render_stateful_widget(list_widget, main_layout[0], &mut self.list_state);
+        f.render_stateful_widget(list_widget, areas.content, &mut self.list_state);
+        self.hit_map.record_rows(
+            list_inner(areas.content),
+            self.list_state.offset(),
+            &heights,
+            None,
+        );
 
         // Instructions
         let visibility_text = if self.show_completed_todos {
@@ -1475,7 +2751,7 @@ impl TUIApp {
             "show completed"
         };
         let visibility_display = format!(" {visibility_text}  ");
-        let instructions = Paragraph::new(vec![
+        let hints = vec![
             Line::from(vec![
                 Span::styled(
                     "↑↓",
@@ -1519,6 +2795,30 @@ impl TUIApp {
                         .bg(Color::DarkGray),
                 ),
                 Span::styled(visibility_display, Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "a",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(Color::DarkGray),
+                ),
+                Span::styled(" agenda  ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "b",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(Color::DarkGray),
+                ),
+                Span::styled(" board  ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    "v",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(Color::DarkGray),
+                ),
+                Span::styled(" two panes  ", Style::default().fg(Color::Gray)),
                 Span::styled(
                     "q",
                     Style::default()
@@ -1528,35 +2828,209 @@ impl TUIApp {
                 ),
                 Span::styled(" quit", Style::default().fg(Color::Gray)),
             ]),
-        ])
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(Span::styled(
-                    " Controls ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ))
-                .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow)),
-        )
-        .alignment(Alignment::Center);
+        ];
 
-        f.render_widget(instructions, main_layout[1]);
+        self.render_controls(f, hints, &areas);
 
         // Status log
-        self.render_status_log(f, main_layout[2]);
+        self.render_status_log(f, areas.status);
     }
 
-    fn render_reminders(&mut self, f: &mut Frame) {
+    fn render_agenda(&mut self, f: &mut Frame) {
         let area = f.area();
+        let areas = self.areas(area, 1, false, 2);
+        let entries = self.agenda_entries();
+
+        // Narrow terminals only get the list; the month grid needs its full width
+        let list_area = if Screen::of(area).narrow {
+            areas.content
+        } else {
+            let content_layout = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Length(agenda::MONTH_GRID_WIDTH),
+                    Constraint::Min(0),
+                ])
+                .split(areas.content);
+            f.render_widget(
+                agenda::month_grid(&self.agenda, &entries),
+                content_layout[0],
+            );
+            content_layout[1]
+        };
+        f.render_widget(
+            agenda::agenda_list(&self.agenda, &entries, list_area.height),
+            list_area,
+        );
+
+        let key = |label: &'static str, color: Color| {
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(color)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::DarkGray),
+            )
+        };
+        let hint = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let mode_hint = match self.agenda.mode {
+            agenda::AgendaMode::Week => " day view  ",
+            agenda::AgendaMode::Day => " week view  ",
+        };
+        let hints = vec![
+            Line::from(vec![
+                key("←→", Color::Cyan),
+                hint(" day  "),
+                key("↑↓", Color::Cyan),
+                hint(" week  "),
+                key("[]", Color::Cyan),
+                hint(" month  "),
+                key("t", Color::Yellow),
+                hint(" today"),
+            ]),
+            Line::from(vec![
+                key("w", Color::Blue),
+                hint(mode_hint),
+                key("c", Color::Magenta),
+                hint(" create on day  "),
+                key("q", Color::Red),
+                hint(" back"),
+            ]),
+        ];
+
+        self.render_controls(f, hints, &areas);
+
+        // Status log
+        self.render_status_log(f, areas.status);
+    }
+
+    fn render_board(&mut self, f: &mut Frame) {
+        let area = f.area();
+        let areas = self.areas(area, 1, false, 2);
+
+        let columns = self.board_columns();
+        self.board.clamp(&columns);
+        let visible = usize::from(areas.content.width / board::MIN_COLUMN_WIDTH)
+            .clamp(1, columns.len().max(1));
+        self.board.scroll_into_view(visible, columns.len());
+
+        let column_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, visible as u32); visible])
+            .split(areas.content);
+
+        let shown = columns
+            .iter()
+            .enumerate()
+            .skip(self.board.scroll)
+            .take(visible);
+        let now = self.now();
+        for ((column_index, column), column_area) in shown.zip(column_areas.iter()) {
+            let focused = column_index == self.board.column;
+            let row = RowLayout::new(
+                column_area.inner(Margin::new(1, 1)),
+                Screen::of(area).compact(),
+            );
+            let items: Vec<ListItem> = column
+                .cards
+                .iter()
+                .enumerate()
+                .map(|(card_index, &reminder_index)| {
+                    let reminder = &self.all_reminders[reminder_index].0;
+                    reminder_item(
+                        reminder,
+                        focused && card_index == self.board.card,
+                        None,
+                        self.is_reminder_pending(&reminder.id),
+                        row,
+                        card_index < column.cards.len() - 1,
+                        now,
+                    )
+                })
+                .collect();
+
+            // Arrows on the outer columns when more are scrolled off-screen
+            let more_left = column_index == self.board.scroll && self.board.scroll > 0;
+            let more_right =
+                column_index + 1 == self.board.scroll + visible && column_index + 1 < columns.len();
+            let title = format!(
+                " {}{} ({}){} ",
+                if more_left { "‹ " } else { "" },
+                column.title,
+                column.cards.len(),
+                if more_right { " ›" } else { "" },
+            );
+            let border_style = if focused {
+                Style::default().fg(column.color)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+
+            let list_widget = List::new(items).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(if focused {
+                        BorderType::Thick
+                    } else {
+                        BorderType::Rounded
+                    })
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(column.color)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .style(border_style),
+            );
+            let mut state = ListState::default();
+            if focused && !column.cards.is_empty() {
+                state.select(Some(self.board.card));
+            }
+            f.render_stateful_widget(list_widget, *column_area, &mut state);
+        }
+
+        let key = |label: &'static str, color: Color| {
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(color)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::DarkGray),
+            )
+        };
+        let hint = |text: String| Span::styled(text, Style::default().fg(Color::Gray));
+        let hints = vec![
+            Line::from(vec![
+                key("←→", Color::Cyan),
+                hint(" column  ".to_string()),
+                key("↑↓", Color::Cyan),
+                hint(" card  ".to_string()),
+                key("H/L", Color::Magenta),
+                hint(" move card  ".to_string()),
+                key("␣", Color::Green),
+                hint(" toggle".to_string()),
+            ]),
+            Line::from(vec![
+                key("g", Color::Blue),
+                hint(format!(
+                    " group by {}  ",
+                    self.board.grouping.next().label()
+                )),
+                key("q", Color::Red),
+                hint(" back".to_string()),
+            ]),
+        ];
 
-        let filtered_reminders: Vec<Reminder> =
-            self.get_filtered_reminders().into_iter().cloned().collect();
+        self.render_controls(f, hints, &areas);
 
-        if filtered_reminders.is_empty() {
+        // Status log
+        self.render_status_log(f, areas.status);
+    }
+
+    fn render_reminders(&mut self, f: &mut Frame) {
+        let area = f.area();
+
+        if self.filtered_indices().is_empty() {
             let message = if self.current_reminders.is_empty() {
                 "📭 No reminders in this list"
             } else {
@@ -1603,160 +3077,43 @@ impl TUIApp {
             return;
         }
 
-        let main_layout = if self.search_state.is_active || self.search_state.has_results {
-            Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(3), // Search bar
-                    Constraint::Min(0),    // Reminders content
-                    Constraint::Length(4), // Controls
-                    Constraint::Length(3), // Status log
-                ])
-                .margin(1)
-                .split(area)
-        } else {
-            Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Min(0),    // Reminders content
-                    Constraint::Length(4), // Controls
-                    Constraint::Length(3), // Status log
-                ])
-                .margin(1)
-                .split(area)
-        };
+        let areas = self.areas(area, 1, self.search_state.is_shown(), 2);
 
         // Render search bar if active or has results
-        let content_index = if self.search_state.is_active || self.search_state.has_results {
-            self.render_search_bar(f, main_layout[0]);
-            1 // Content area index when search bar is shown
+        if let Some(search_area) = areas.search {
+            self.render_search_bar(f, search_area);
+        }
+
+        // Wide terminals keep the lists in view beside the reminders
+        let content = if Screen::of(area).wide {
+            let split = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(0)])
+                .split(areas.content);
+            self.render_list_sidebar(f, split[0]);
+            split[1]
         } else {
-            0 // Content area index when no search bar
+            areas.content
         };
 
-        // Create reminder items
-        let items: Vec<ListItem> = filtered_reminders
-            .iter()
-            .enumerate()
-            .map(|(i, reminder)| {
-                let is_selected = i == self.selected_index;
-
-                let checkbox = if reminder.completed { "☑" } else { "☐" };
-                let title_color = if reminder.completed {
-                    if is_selected {
-                        Color::LightBlue
-                    } else {
-                        Color::Gray
-                    }
-                } else {
-                    Color::White
-                };
-                let title_modifier = if reminder.completed {
-                    Modifier::CROSSED_OUT
-                } else {
-                    Modifier::empty()
-                };
-
-                // Build the title line with optional list name for global search
-                let mut title_spans = vec![
-                    Span::styled(
-                        if is_selected { "▶ " } else { "  " },
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(
-                        checkbox,
-                        Style::default()
-                            .fg(if reminder.completed {
-                                Color::Green
-                            } else {
-                                Color::Gray
-                            })
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw("  "),
-                ];
-
-                // Add list name for global search
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(Span::styled(
                 if self.is_in_global_search_view() {
-                    if let Some(list_name) = self.get_list_name_for_reminder(&reminder.id) {
-                        title_spans.push(Span::styled(
-                            format!("[{list_name}] "),
-                            Style::default()
-                                .fg(Color::LightBlue)
-                                .add_modifier(Modifier::BOLD),
-                        ));
-                    }
-                }
-
-                title_spans.push(Span::styled(
-                    &reminder.title,
-                    Style::default().fg(title_color).add_modifier(
-                        title_modifier
-                            | if is_selected {
-                                Modifier::UNDERLINED
-                            } else {
-                                Modifier::empty()
-                            },
-                    ),
-                ));
-
-                let mut lines = vec![Line::from(title_spans)];
-
-                if let Some(notes) = &reminder.notes {
-                    if !notes.is_empty() {
-                        lines.push(Line::from(vec![
-                            Span::raw("      "),
-                            Span::styled(notes, Style::default().fg(Color::DarkGray)),
-                        ]));
-                    }
-                }
-
-                if i < filtered_reminders.len() - 1 {
-                    lines.push(Line::from(""));
-                }
-
-                let style = if is_selected {
-                    Style::default().bg(Color::DarkGray).fg(Color::White)
+                    " 🔍 Global Search "
                 } else {
-                    Style::default()
-                };
-
-                ListItem::new(lines).style(style)
-            })
-            .collect();
-
-        let list_widget = List::new(items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(Span::styled(
-                        if self.is_in_global_search_view() {
-                            " 🔍 Global Search "
-                        } else {
-                            " 📝 Reminders "
-                        },
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Blue))
-                    .padding(Padding::horizontal(1)),
-            )
-            .highlight_style(
+                    " 📝 Reminders "
+                },
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
-            );
-
-        f.render_stateful_widget(
-            list_widget,
-            main_layout[content_index],
-            &mut self.list_state,
-        );
+            ))
+            .title_alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Blue))
+            .padding(Padding::horizontal(1));
+        let compact = Screen::of(area).compact();
+        self.render_reminder_rows(f, content, block, compact, true);
 
         // Instructions
         let visibility_text = if self.show_completed_todos {
@@ -1767,7 +3124,7 @@ impl TUIApp {
         let visibility_display = format!(" {visibility_text}  ");
 
         // Different instructions for global search
-        let instructions = if self.is_in_global_search_view() {
+        let hints = if self.is_in_global_search_view() {
             let mut lines = vec![
                 Line::from(vec![
                     Span::styled(
@@ -1853,9 +3210,9 @@ impl TUIApp {
                 ]));
             }
 
-            Paragraph::new(lines)
+            lines
         } else {
-            Paragraph::new(vec![
+            vec![
                 Line::from(vec![
                     Span::styled(
                         "↑↓",
@@ -1907,6 +3264,14 @@ impl TUIApp {
                             .bg(Color::DarkGray),
                     ),
                     Span::styled(visibility_display, Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        "0-3",
+                        Style::default()
+                            .fg(Color::Red)
+                            .add_modifier(Modifier::BOLD)
+                            .bg(Color::DarkGray),
+                    ),
+                    Span::styled(" priority  ", Style::default().fg(Color::Gray)),
                     Span::styled(
                         "q",
                         Style::default()
@@ -1916,27 +3281,294 @@ impl TUIApp {
                     ),
                     Span::styled(" back", Style::default().fg(Color::Gray)),
                 ]),
-            ])
+            ]
+        };
+
+        self.render_controls(f, hints, &areas);
+
+        // Status log
+        self.render_status_log(f, areas.status);
+    }
+
+    /// Lists on the left and the highlighted list's reminders on the right. Tab moves
+    /// the focus between them; the unfocused pane keeps its content but not its cursor.
+    fn render_two_pane(&mut self, f: &mut Frame) {
+        let area = f.area();
+        let reminders_focused = matches!(self.current_view, AppView::Reminders { .. });
+        let areas = self.areas(
+            area,
+            1,
+            reminders_focused && self.search_state.is_shown(),
+            2,
+        );
+        if let Some(search_area) = areas.search {
+            self.render_search_bar(f, search_area);
         }
-        .block(
+
+        let split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(SIDEBAR_WIDTH), Constraint::Min(0)])
+            .split(areas.content);
+        let pane_block = |title: String, focused: bool| {
             Block::default()
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
+                .border_type(if focused {
+                    BorderType::Thick
+                } else {
+                    BorderType::Rounded
+                })
                 .title(Span::styled(
-                    " Controls ",
+                    title,
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(Color::Blue)
                         .add_modifier(Modifier::BOLD),
                 ))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow)),
-        )
-        .alignment(Alignment::Center);
+                .style(Style::default().fg(if focused { Color::Cyan } else { Color::Blue }))
+                .padding(Padding::horizontal(1))
+        };
+
+        // Lists pane
+        let highlighted = if reminders_focused {
+            self.lists_index
+        } else {
+            self.selected_index
+        };
+        let overdue = self.overdue_counts();
+        let row = RowLayout::new(list_inner(split[0]), true);
+        let items: Vec<ListItem> = self
+            .lists
+            .iter()
+            .enumerate()
+            .map(|(i, list)| {
+                let overdue = overdue.get(list.name.as_str()).copied().unwrap_or(0);
+                list_item(list, i == highlighted, overdue, row, false)
+            })
+            .collect();
+        let heights = vec![1; items.len()];
+        let lists_widget = List::new(items)
+            .block(pane_block(" Lists ".to_string(), !reminders_focused))
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+        if reminders_focused {
+            let mut state = ListState::default().with_selected(Some(highlighted));
+            f.render_stateful_widget(lists_widget, split[0], &mut state);
+        } else {
+            f.render_stateful_widget(lists_widget, split[0], &mut self.list_state);
+            self.hit_map.record_rows(
+                list_inner(split[0]),
+                self.list_state.offset(),
+                &heights,
+                None,
+            );
+        }
 
-        f.render_widget(instructions, main_layout[content_index + 1]);
+        // Reminders pane
+        let title = self
+            .lists
+            .get(highlighted)
+            .map_or_else(|| " Reminders ".to_string(), |l| format!(" {} ", l.name));
+        if self.filtered_indices().is_empty() {
+            let message = if self.current_reminders.is_empty() {
+                "📭 No reminders in this list"
+            } else {
+                "📭 No incomplete reminders"
+            };
+            let empty = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    message,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )),
+            ])
+            .block(pane_block(title, reminders_focused))
+            .alignment(Alignment::Center);
+            f.render_widget(empty, split[1]);
+        } else {
+            let block = pane_block(title, reminders_focused);
+            let compact = Screen::of(area).compact();
+            self.render_reminder_rows(f, split[1], block, compact, reminders_focused);
+        }
 
-        // Status log
-        self.render_status_log(f, main_layout[content_index + 2]);
+        let key = |label: &'static str, color: Color| {
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(color)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(Color::DarkGray),
+            )
+        };
+        let hint = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+        let visibility_hint = if self.show_completed_todos {
+            " hide completed  "
+        } else {
+            " show completed  "
+        };
+        let lines = if reminders_focused {
+            vec![
+                Line::from(vec![
+                    key("↑↓", Color::Cyan),
+                    hint(" or "),
+                    key("j/k", Color::Cyan),
+                    hint(" navigate  "),
+                    key("⏎/space", Color::Green),
+                    hint(" toggle  "),
+                    key("dd/Del", Color::Red),
+                    hint(" delete"),
+                ]),
+                Line::from(vec![
+                    key("Tab", Color::Blue),
+                    hint(" lists  "),
+                    key("c", Color::Magenta),
+                    hint(" create  "),
+                    key("h", Color::Yellow),
+                    hint(visibility_hint),
+                    key("0-3", Color::Red),
+                    hint(" priority  "),
+                    key("q", Color::Red),
+                    hint(" back"),
+                ]),
+            ]
+        } else {
+            vec![
+                Line::from(vec![
+                    key("↑↓", Color::Cyan),
+                    hint(" or "),
+                    key("j/k", Color::Cyan),
+                    hint(" navigate  "),
+                    key("Tab", Color::Blue),
+                    hint(" reminders  "),
+                    key("c", Color::Magenta),
+                    hint(" create"),
+                ]),
+                Line::from(vec![
+                    key("h", Color::Yellow),
+                    hint(visibility_hint),
+                    key("v", Color::Blue),
+                    hint(" single pane  "),
+                    key("q", Color::Red),
+                    hint(" quit"),
+                ]),
+            ]
+        };
+        self.render_controls(f, lines, &areas);
+
+        self.render_status_log(f, areas.status);
+    }
+
+    /// Draw the filtered reminders into `area` inside `block`, scrolled like a `List`
+    /// but building only the rows on screen. Unfocused, the list shows its top without
+    /// a selection and takes no clicks.
+    fn render_reminder_rows(
+        &mut self,
+        f: &mut Frame,
+        area: ratatui::layout::Rect,
+        block: Block,
+        compact: bool,
+        focused: bool,
+    ) {
+        let inner = list_inner(area);
+        let row = RowLayout::new(inner, compact);
+        let now = self.now();
+        let global = self.is_in_global_search_view();
+        let indices = self.filtered_indices();
+        let len = indices.len();
+        let selected = if focused {
+            self.list_state
+                .selected()
+                .map(|s| s.min(len.saturating_sub(1)))
+        } else {
+            None
+        };
+        let offset = if focused { self.list_state.offset() } else { 0 };
+
+        let build = |i: usize| {
+            let reminder = self.filtered_source(indices[i]);
+            let list_name = if !global {
+                None
+            } else if self.search_state.is_global {
+                Some(self.all_reminders[indices[i]].1.as_str())
+            } else {
+                self.get_list_name_for_reminder(&reminder.id)
+            };
+            reminder_item(
+                reminder,
+                focused && i == self.selected_index,
+                list_name,
+                self.is_reminder_pending(&reminder.id),
+                row,
+                i < len - 1,
+                now,
+            )
+        };
+        // Items measured while finding the window are kept for drawing
+        let mut built = BTreeMap::new();
+        let range =
+            viewport::visible_range(len, offset, selected, usize::from(inner.height), |i| {
+                built.entry(i).or_insert_with(|| build(i)).height()
+            });
+        let items: Vec<ListItem> = range
+            .clone()
+            .map(|i| built.remove(&i).unwrap_or_else(|| build(i)))
+            .collect();
+        let heights: Vec<usize> = items.iter().map(ListItem::height).collect();
+        drop(indices);
+
+        let mut state = ListState::default().with_selected(
+            selected
+                .filter(|s| range.contains(s))
+                .map(|s| s - range.start),
+        );
+        let widget = List::new(items).block(block).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_stateful_widget(widget, area, &mut state);
+
+        if focused {
+            *self.list_state.offset_mut() = range.start;
+            if selected != self.list_state.selected() {
+                self.list_state.select(selected);
+            }
+            // The checkbox comes after the two-column selection marker
+            self.hit_map
+                .record_window(inner, range.start, &heights, Some(2));
+        }
+    }
+
+    /// The lists, one line each, beside the reminders on wide terminals. The list
+    /// being shown is highlighted.
+    fn render_list_sidebar(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let active = self.active_list_id();
+        let overdue = self.overdue_counts();
+        let row = RowLayout::new(list_inner(area), true);
+        let items: Vec<ListItem> = self
+            .lists
+            .iter()
+            .map(|list| {
+                let overdue = overdue.get(list.name.as_str()).copied().unwrap_or(0);
+                list_item(list, active.as_ref() == Some(&list.id), overdue, row, false)
+            })
+            .collect();
+
+        let sidebar = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(
+                    " Lists ",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Blue))
+                .padding(Padding::horizontal(1)),
+        );
+        f.render_widget(sidebar, area);
     }
 
     fn render_search_bar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
@@ -1945,7 +3577,11 @@ impl TUIApp {
         } else {
             "List"
         };
-        let title = format!(" 🔍 {search_type} Search ");
+        let title = if self.search_state.jumping {
+            format!(" 🔍 {search_type} Search · n/N ")
+        } else {
+            format!(" 🔍 {search_type} Search ")
+        };
 
         let search_text = if self.search_state.is_active {
             format!("{}_", self.search_state.query) // Show cursor with underscore
@@ -1983,20 +3619,31 @@ impl TUIApp {
             Color::Blue
         };
 
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(Span::styled(
+                title,
+                Style::default()
+                    .fg(border_color)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Left)
+            .style(Style::default().fg(border_color));
+        if let Some(counter) = self.match_counter() {
+            block = block.title(
+                Line::from(Span::styled(
+                    format!(" {counter} "),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .right_aligned(),
+            );
+        }
+
         let search_paragraph = Paragraph::new(display_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(Span::styled(
-                        title,
-                        Style::default()
-                            .fg(border_color)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .title_alignment(Alignment::Left)
-                    .style(Style::default().fg(border_color)),
-            )
+            .block(block)
             .style(Style::default().fg(text_color))
             .alignment(Alignment::Left);
 
@@ -2007,15 +3654,7 @@ impl TUIApp {
         let area = f.area();
 
         if let Some(ref form) = self.create_form {
-            let main_layout = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Min(0),    // Form content
-                    Constraint::Length(3), // Controls
-                    Constraint::Length(3), // Status log
-                ])
-                .margin(2)
-                .split(area);
+            let areas = self.areas(area, 2, false, 1);
 
             // Form fields layout
             let form_layout = Layout::default()
@@ -2027,7 +3666,7 @@ impl TUIApp {
                     Constraint::Length(3), // List
                     Constraint::Length(3), // Priority
                 ])
-                .split(main_layout[0]);
+                .split(areas.content);
 
             // Title field
             let title_style = if form.current_field == 0 {
@@ -2133,11 +3772,20 @@ impl TUIApp {
                 Style::default().fg(Color::Gray)
             };
 
-            let priority_text = if form.priority == 0 {
-                "None".to_string()
-            } else {
-                form.priority.to_string()
-            };
+            let priority_text = Line::from(vec![
+                Span::styled(
+                    form.priority.marker(),
+                    Style::default()
+                        .fg(board::priority_color(form.priority))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(if form.priority == Priority::None {
+                    ""
+                } else {
+                    " "
+                }),
+                Span::raw(form.priority.label()),
+            ]);
             let priority_paragraph = Paragraph::new(priority_text).block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -2148,7 +3796,7 @@ impl TUIApp {
             f.render_widget(priority_paragraph, form_layout[4]);
 
             // Instructions
-            let instructions = Paragraph::new(vec![Line::from(vec![
+            let hints = vec![Line::from(vec![
                 Span::styled(
                     "Tab",
                     Style::default()
@@ -2173,30 +3821,81 @@ impl TUIApp {
                         .bg(Color::DarkGray),
                 ),
                 Span::styled(" cancel", Style::default().fg(Color::Gray)),
-            ])])
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(Span::styled(
-                        " Controls ",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                    .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Yellow)),
-            )
-            .alignment(Alignment::Center);
+            ])];
 
-            f.render_widget(instructions, main_layout[1]);
+            self.render_controls(f, hints, &areas);
 
             // Status log
-            self.render_status_log(f, main_layout[2]);
+            self.render_status_log(f, areas.status);
+        }
+    }
+
+    /// Draw a view's hint lines, centered, into the controls bar. A collapsed bar is
+    /// just the hint lines, without the border.
+    fn render_controls(&mut self, f: &mut Frame, hints: Vec<Line>, areas: &Areas) {
+        let Some(area) = areas.controls else {
+            return;
+        };
+        let (block, inner) = if areas.collapsed {
+            (Block::default(), area)
+        } else {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(
+                    " Controls ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .title_alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Yellow));
+            (block, area.inner(Margin::new(1, 1)))
+        };
+        self.hit_map.record_hints(&hints, inner);
+        let instructions = Paragraph::new(hints)
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(instructions, area);
+    }
+
+    /// The standard screen regions, with the status area grown for the log pane while
+    /// it is open.
+    fn areas(
+        &self,
+        area: ratatui::layout::Rect,
+        margin: u16,
+        search: bool,
+        hint_lines: u16,
+    ) -> Areas {
+        let areas = Areas::new(area, margin, search, hint_lines);
+        if self.log_pane.open {
+            areas.expand_status(log_pane::PANE_HEIGHT)
+        } else {
+            areas
         }
     }
 
-    fn render_status_log(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+    fn render_status_log(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.log_pane.open {
+            self.render_log_pane(f, area);
+            return;
+        }
+
+        // A collapsed status bar only has room for the latest message
+        if area.height < 3 {
+            let message = self.status_log.last().map_or("Ready", String::as_str);
+            let text = truncate(message, usize::from(area.width));
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    text.into_owned(),
+                    Style::default().fg(Color::Cyan),
+                )),
+                area,
+            );
+            return;
+        }
+
         let log_lines: Vec<Line> = if self.status_log.is_empty() {
             vec![Line::from(Span::styled(
                 "Ready",
@@ -2247,9 +3946,348 @@ impl TUIApp {
 
         f.render_widget(status_paragraph, area);
     }
+
+    /// The log records at the pane's level or above, newest at the bottom, in place of
+    /// the status box.
+    fn render_log_pane(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let bordered = area.height >= 3;
+        let rows = usize::from(if bordered {
+            area.height - 2
+        } else {
+            area.height
+        });
+        self.log_pane.rows = rows.max(1);
+        let records = self.log.records(self.log_pane.level);
+        let width = usize::from(area.width.saturating_sub(2));
+
+        let lines: Vec<Line> = if records.is_empty() {
+            vec![Line::from(Span::styled(
+                "No log records at this level",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            self.log_pane
+                .visible(&records, rows)
+                .iter()
+                .map(|record| {
+                    let prefix = format!(
+                        "{} {:<5} {} ",
+                        record.time.format("%H:%M:%S"),
+                        record.level,
+                        log_pane::short_target(&record.target)
+                    );
+                    let message = truncate(&record.message, width.saturating_sub(prefix.width()));
+                    Line::from(vec![
+                        Span::styled(
+                            prefix,
+                            Style::default().fg(log_pane::level_color(record.level)),
+                        ),
+                        Span::styled(message.into_owned(), Style::default().fg(Color::White)),
+                    ])
+                })
+                .collect()
+        };
+
+        if !bordered {
+            f.render_widget(Paragraph::new(lines), area);
+            return;
+        }
+        let position = if self.log_pane.scroll > 0 {
+            format!(" ↓ {} newer ", self.log_pane.scroll.min(records.len()))
+        } else {
+            String::new()
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(Span::styled(
+                format!(" Log · {} and above ", self.log_pane.level),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_alignment(Alignment::Center)
+            .title_bottom(Line::from(position).left_aligned())
+            .title_bottom(
+                Line::from(" l level  ↑↓ PgUp PgDn scroll  g/G oldest/newest  ` close ")
+                    .right_aligned(),
+            )
+            .style(Style::default().fg(Color::Cyan));
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Where the rows of the lists and reminders lists are drawn: inside the border and
+/// one column of horizontal padding.
+fn list_inner(area: ratatui::layout::Rect) -> ratatui::layout::Rect {
+    Block::default()
+        .borders(Borders::ALL)
+        .padding(Padding::horizontal(1))
+        .inner(area)
+}
+
+const PENDING_MARKER: &str = " ⏳ saving…";
+
+fn item_style(is_selected: bool) -> Style {
+    if is_selected {
+        Style::default().bg(Color::DarkGray).fg(Color::White)
+    } else {
+        Style::default()
+    }
+}
+
+/// How much room a row of the lists or reminders list has.
+#[derive(Clone, Copy, Debug)]
+struct RowLayout {
+    width: usize,
+    /// One line per item, without notes or blank lines in between.
+    compact: bool,
+}
+
+impl RowLayout {
+    fn new(inner: ratatui::layout::Rect, compact: bool) -> Self {
+        Self {
+            width: usize::from(inner.width),
+            compact,
+        }
+    }
+}
+
+/// One list row: color dot, name, reminder count and how many are overdue. Compact
+/// rows put the counts on the same line.
+fn list_item<'a>(
+    list: &ReminderList,
+    is_selected: bool,
+    overdue: usize,
+    row: RowLayout,
+    spaced: bool,
+) -> ListItem<'a> {
+    let color = parse_color(&list.color);
+
+    let count_text = if list.count == 0 {
+        "Empty".to_string()
+    } else if list.count == 1 {
+        "1 reminder".to_string()
+    } else {
+        format!("{} reminders", list.count)
+    };
+    let name_style = Style::default()
+        .fg(if is_selected {
+            Color::White
+        } else {
+            Color::LightBlue
+        })
+        .add_modifier(if is_selected {
+            Modifier::BOLD | Modifier::UNDERLINED
+        } else {
+            Modifier::BOLD
+        });
+    let count_style = Style::default().fg(if list.count == 0 {
+        Color::DarkGray
+    } else {
+        Color::Gray
+    });
+
+    let mut name_line = vec![
+        Span::styled(
+            if is_selected { "▶ " } else { "  " },
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("●", Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::raw("  "),
+    ];
+
+    let overdue_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+
+    let lines = if row.compact {
+        let count = format!("  {}", list.count);
+        let badge = if overdue > 0 {
+            format!(" ⚠{overdue}")
+        } else {
+            String::new()
+        };
+        let room = row.width.saturating_sub(5 + count.len() + badge.width());
+        name_line.push(Span::styled(
+            truncate(&list.name, room).into_owned(),
+            name_style,
+        ));
+        name_line.push(Span::styled(count, count_style));
+        if overdue > 0 {
+            name_line.push(Span::styled(badge, overdue_style));
+        }
+        vec![Line::from(name_line)]
+    } else {
+        name_line.push(Span::styled(
+            truncate(&list.name, row.width.saturating_sub(5)).into_owned(),
+            name_style,
+        ));
+        let mut count_line = vec![Span::raw("    "), Span::styled(count_text, count_style)];
+        if overdue > 0 {
+            count_line.push(Span::styled(
+                format!("  ⚠ {overdue} overdue"),
+                overdue_style,
+            ));
+        }
+        let mut lines = vec![Line::from(name_line), Line::from(count_line)];
+        if spaced {
+            lines.push(Line::from(""));
+        }
+        lines
+    };
+
+    ListItem::new(lines).style(item_style(is_selected))
+}
+
+/// One reminder row: checkbox, title (with its list name in global search), relative
+/// due date, pending marker and notes. `spaced` adds a blank line after it; compact
+/// rows drop the notes and the spacing.
+fn reminder_item<'a>(
+    reminder: &'a Reminder,
+    is_selected: bool,
+    list_name: Option<&str>,
+    pending: bool,
+    row: RowLayout,
+    spaced: bool,
+    now: DateTime<Local>,
+) -> ListItem<'a> {
+    let checkbox = if reminder.completed { "☑" } else { "☐" };
+    let due = reminder.due_date.as_deref().and_then(Due::parse);
+    let status = due
+        .filter(|_| !reminder.completed)
+        .map(|due| due.status(now));
+    let title_color = if reminder.completed {
+        if is_selected {
+            Color::LightBlue
+        } else {
+            Color::Gray
+        }
+    } else {
+        match status {
+            Some(DueStatus::Overdue) => Color::Red,
+            Some(DueStatus::Today) => Color::Yellow,
+            _ => Color::White,
+        }
+    };
+    let title_modifier = if reminder.completed {
+        Modifier::CROSSED_OUT
+    } else {
+        Modifier::empty()
+    };
+
+    let mut title_spans = vec![
+        Span::styled(
+            if is_selected { "▶ " } else { "  " },
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            checkbox,
+            Style::default()
+                .fg(if reminder.completed {
+                    Color::Green
+                } else {
+                    Color::Gray
+                })
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+    ];
+
+    // Marker, checkbox and gap take five columns
+    let mut room = row.width.saturating_sub(5);
+    if pending {
+        room = room.saturating_sub(PENDING_MARKER.width());
+    }
+    let due_label = due.map(|due| format!("  {}", due.relative(now)));
+    if let Some(label) = &due_label {
+        room = room.saturating_sub(label.width());
+    }
+
+    if let Some(list_name) = list_name {
+        let tag = truncate(&format!("[{list_name}] "), room / 2).into_owned();
+        room = room.saturating_sub(tag.width());
+        title_spans.push(Span::styled(
+            tag,
+            Style::default()
+                .fg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let priority = Priority::from_eventkit(reminder.priority);
+    if priority != Priority::None {
+        let marker = format!("{} ", priority.marker());
+        room = room.saturating_sub(marker.width());
+        title_spans.push(Span::styled(
+            marker,
+            Style::default()
+                .fg(board::priority_color(priority))
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    title_spans.push(Span::styled(
+        truncate(&reminder.title, room),
+        Style::default().fg(title_color).add_modifier(
+            title_modifier
+                | if is_selected {
+                    Modifier::UNDERLINED
+                } else {
+                    Modifier::empty()
+                },
+        ),
+    ));
+
+    if let Some(label) = due_label {
+        let style = match status {
+            None => Style::default().fg(Color::DarkGray),
+            Some(DueStatus::Overdue) => {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            }
+            Some(DueStatus::Today) => Style::default().fg(Color::Yellow),
+            Some(DueStatus::Upcoming) => Style::default().fg(Color::Gray),
+        };
+        title_spans.push(Span::styled(label, style));
+    }
+
+    // Mark changes that Swift hasn't confirmed yet
+    if pending {
+        title_spans.push(Span::styled(
+            PENDING_MARKER,
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        ));
+    }
+
+    let mut lines = vec![Line::from(title_spans)];
+    if row.compact {
+        return ListItem::new(lines).style(item_style(is_selected));
+    }
+
+    if let Some(notes) = &reminder.notes {
+        if !notes.is_empty() {
+            lines.push(Line::from(vec![
+                Span::raw("      "),
+                Span::styled(
+                    truncate(notes, row.width.saturating_sub(6)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+    }
+
+    if spaced {
+        lines.push(Line::from(""));
+    }
+
+    ListItem::new(lines).style(item_style(is_selected))
 }
 
-fn parse_color(color_str: &str) -> Color {
+pub(crate) fn parse_color(color_str: &str) -> Color {
     match color_str {
         s if s.starts_with("#") => {
             if let Ok(hex) = u32::from_str_radix(&s[1..], 16) {
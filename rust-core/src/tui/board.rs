@@ -0,0 +1,269 @@
+//! Kanban board: reminders as cards in columns grouped by list, priority band or due date.
+
+use super::app::parse_color;
+use crate::types::{Due, Priority};
+use crate::{Reminder, ReminderList};
+use chrono::{Days, NaiveDate};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+/// Narrowest a column gets before the board scrolls sideways.
+pub const MIN_COLUMN_WIDTH: u16 = 28;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardGrouping {
+    List,
+    Priority,
+    Due,
+}
+
+impl BoardGrouping {
+    pub fn next(self) -> Self {
+        match self {
+            BoardGrouping::List => BoardGrouping::Priority,
+            BoardGrouping::Priority => BoardGrouping::Due,
+            BoardGrouping::Due => BoardGrouping::List,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BoardGrouping::List => "list",
+            BoardGrouping::Priority => "priority",
+            BoardGrouping::Due => "due date",
+        }
+    }
+}
+
+/// Priority columns, most urgent first.
+pub const PRIORITY_COLUMNS: [Priority; 4] = [
+    Priority::High,
+    Priority::Medium,
+    Priority::Low,
+    Priority::None,
+];
+
+fn priority_title(priority: Priority) -> String {
+    match priority {
+        Priority::None => "No priority".to_string(),
+        _ => format!("{} {}", priority.marker(), priority.label()),
+    }
+}
+
+/// The color priority markers and columns are drawn in.
+pub fn priority_color(priority: Priority) -> Color {
+    match priority {
+        Priority::High => Color::Red,
+        Priority::Medium => Color::Yellow,
+        Priority::Low => Color::Blue,
+        Priority::None => Color::Gray,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DueBucket {
+    Overdue,
+    Today,
+    ThisWeek,
+    Later,
+    NoDate,
+}
+
+impl DueBucket {
+    pub const ALL: [DueBucket; 5] = [
+        DueBucket::Overdue,
+        DueBucket::Today,
+        DueBucket::ThisWeek,
+        DueBucket::Later,
+        DueBucket::NoDate,
+    ];
+
+    /// "This week" means the next seven days, not the calendar week.
+    pub fn of(due_date: Option<&str>, today: NaiveDate) -> Self {
+        let Some(date) = due_date.and_then(Due::parse).map(Due::date) else {
+            return DueBucket::NoDate;
+        };
+        let week_end = today.checked_add_days(Days::new(6)).unwrap_or(today);
+        if date < today {
+            DueBucket::Overdue
+        } else if date == today {
+            DueBucket::Today
+        } else if date <= week_end {
+            DueBucket::ThisWeek
+        } else {
+            DueBucket::Later
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            DueBucket::Overdue => "Overdue",
+            DueBucket::Today => "Today",
+            DueBucket::ThisWeek => "Next 7 days",
+            DueBucket::Later => "Later",
+            DueBucket::NoDate => "No date",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            DueBucket::Overdue => Color::Red,
+            DueBucket::Today => Color::Yellow,
+            DueBucket::ThisWeek => Color::Cyan,
+            DueBucket::Later => Color::Blue,
+            DueBucket::NoDate => Color::Gray,
+        }
+    }
+}
+
+/// What a column stands for, and so what moving a card into it changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnKind {
+    List { list_id: String, list_name: String },
+    Priority(Priority),
+    Due(DueBucket),
+}
+
+#[derive(Clone, Debug)]
+pub struct BoardColumn {
+    pub kind: ColumnKind,
+    pub title: String,
+    pub color: Color,
+    /// Indices into the `(reminder, list_name)` slice the board was built from.
+    pub cards: Vec<usize>,
+}
+
+/// Group `reminders` into columns. Every list, band or bucket gets a column, even an
+/// empty one, so cards can be moved into it.
+pub fn columns(
+    grouping: BoardGrouping,
+    lists: &[ReminderList],
+    reminders: &[(Reminder, String)],
+    show_completed: bool,
+    today: NaiveDate,
+) -> Vec<BoardColumn> {
+    let visible = || {
+        reminders
+            .iter()
+            .enumerate()
+            .filter(move |(_, (r, _))| show_completed || !r.completed)
+    };
+
+    match grouping {
+        BoardGrouping::List => lists
+            .iter()
+            .map(|list| BoardColumn {
+                kind: ColumnKind::List {
+                    list_id: list.id.clone(),
+                    list_name: list.name.clone(),
+                },
+                title: list.name.clone(),
+                color: parse_color(&list.color),
+                cards: visible()
+                    .filter(|(_, (_, list_name))| *list_name == list.name)
+                    .map(|(i, _)| i)
+                    .collect(),
+            })
+            .collect(),
+        BoardGrouping::Priority => PRIORITY_COLUMNS
+            .iter()
+            .map(|&priority| BoardColumn {
+                kind: ColumnKind::Priority(priority),
+                title: priority_title(priority),
+                color: priority_color(priority),
+                cards: visible()
+                    .filter(|(_, (r, _))| Priority::from_eventkit(r.priority) == priority)
+                    .map(|(i, _)| i)
+                    .collect(),
+            })
+            .collect(),
+        BoardGrouping::Due => DueBucket::ALL
+            .iter()
+            .map(|&bucket| BoardColumn {
+                kind: ColumnKind::Due(bucket),
+                title: bucket.title().to_string(),
+                color: bucket.color(),
+                cards: visible()
+                    .filter(|(_, (r, _))| DueBucket::of(r.due_date.as_deref(), today) == bucket)
+                    .map(|(i, _)| i)
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BoardState {
+    pub grouping: BoardGrouping,
+    /// Focused column and card within it.
+    pub column: usize,
+    pub card: usize,
+    /// First column on screen when there are more than fit.
+    pub scroll: usize,
+}
+
+impl BoardState {
+    pub fn new() -> Self {
+        Self {
+            grouping: BoardGrouping::List,
+            column: 0,
+            card: 0,
+            scroll: 0,
+        }
+    }
+
+    pub fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        self.column = 0;
+        self.card = 0;
+        self.scroll = 0;
+    }
+
+    /// Keep the focus inside `columns` after they changed.
+    pub fn clamp(&mut self, columns: &[BoardColumn]) {
+        self.column = self.column.min(columns.len().saturating_sub(1));
+        let cards = columns.get(self.column).map_or(0, |c| c.cards.len());
+        self.card = self.card.min(cards.saturating_sub(1));
+    }
+
+    pub fn move_column(&mut self, delta: isize, columns: &[BoardColumn]) {
+        if let Some(column) = self
+            .column
+            .checked_add_signed(delta)
+            .filter(|&c| c < columns.len())
+        {
+            self.column = column;
+            self.clamp(columns);
+        }
+    }
+
+    pub fn move_card(&mut self, delta: isize, columns: &[BoardColumn]) {
+        let cards = columns.get(self.column).map_or(0, |c| c.cards.len());
+        if let Some(card) = self.card.checked_add_signed(delta).filter(|&c| c < cards) {
+            self.card = card;
+        }
+    }
+
+    /// Index of the focused card's reminder.
+    pub fn selected(&self, columns: &[BoardColumn]) -> Option<usize> {
+        columns.get(self.column)?.cards.get(self.card).copied()
+    }
+
+    /// Scroll sideways so the focused column is one of the `visible` on screen.
+    pub fn scroll_into_view(&mut self, visible: usize, total: usize) {
+        let visible = visible.max(1);
+        if self.column < self.scroll {
+            self.scroll = self.column;
+        } else if self.column >= self.scroll + visible {
+            self.scroll = self.column + 1 - visible;
+        }
+        self.scroll = self.scroll.min(total.saturating_sub(visible));
+    }
+}
+
+impl Default for BoardState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,227 @@
+//! Reminders as they were last fetched, per list, so a list opens instantly with what
+//! it showed last time while Swift fetches it again. Saved next to the session so a
+//! cold start has lists and reminders before EventKit answers.
+
+use super::session::{data_dir, file_version};
+use crate::{Reminder, ReminderList};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Bump when a field changes meaning. Unlike the session there is nothing to migrate:
+/// an older cache is dropped and refilled by the next fetch.
+pub const CACHE_VERSION: u32 = 1;
+const CACHE_FILE: &str = "tui-cache.json";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReminderCache {
+    pub version: u32,
+    /// The lists as last fetched.
+    pub lists: Vec<ReminderList>,
+    /// Reminders per list id.
+    pub entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// When the reminders were fetched, in seconds since the Unix epoch.
+    pub fetched_at: i64,
+    pub reminders: Vec<Reminder>,
+}
+
+impl Default for ReminderCache {
+    fn default() -> Self {
+        Self {
+            version: CACHE_VERSION,
+            lists: Vec::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl ReminderCache {
+    /// The saved cache, or `None` when there is none or it was written by another
+    /// version.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let cache: Self = serde_json::from_str(&contents).ok()?;
+        (cache.version == CACHE_VERSION).then_some(cache)
+    }
+
+    /// Write atomically, like the session. A newer version's cache is left alone.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if file_version(path).is_some_and(|version| version > CACHE_VERSION) {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string(self).map_err(io::Error::other)?;
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, path)
+    }
+
+    pub fn get(&self, list_id: &str) -> Option<&CacheEntry> {
+        self.entries.get(list_id)
+    }
+
+    pub fn insert(&mut self, list_id: &str, reminders: Vec<Reminder>, fetched_at: i64) {
+        self.entries.insert(
+            list_id.to_string(),
+            CacheEntry {
+                fetched_at,
+                reminders,
+            },
+        );
+    }
+
+    /// Move `reminder` from one list's cached reminders to another's, so reopening
+    /// either shows the move before the refetch. A list that isn't cached stays that way.
+    pub fn move_reminder(
+        &mut self,
+        reminder: &Reminder,
+        from_list_id: Option<&str>,
+        to_list_id: &str,
+    ) {
+        if let Some(entry) = from_list_id.and_then(|id| self.entries.get_mut(id)) {
+            entry.reminders.retain(|r| r.id != reminder.id);
+        }
+        if let Some(entry) = self.entries.get_mut(to_list_id) {
+            entry.reminders.retain(|r| r.id != reminder.id);
+            entry.reminders.push(reminder.clone());
+        }
+    }
+
+    /// Replace the lists, dropping the reminders of lists that no longer exist.
+    pub fn set_lists(&mut self, lists: &[ReminderList]) {
+        self.entries
+            .retain(|id, _| lists.iter().any(|list| &list.id == id));
+        self.lists = lists.to_vec();
+    }
+}
+
+/// Replace `current` with a fresh fetch and count what differs: reminders added,
+/// removed or edited since the cached copy was shown.
+pub fn merge(current: &mut Vec<Reminder>, fresh: Vec<Reminder>) -> usize {
+    let changed = fresh
+        .iter()
+        .filter(|reminder| !current.contains(reminder))
+        .count();
+    let removed = current
+        .iter()
+        .filter(|reminder| !fresh.iter().any(|r| r.id == reminder.id))
+        .count();
+    *current = fresh;
+    changed + removed
+}
+
+/// Where the cache lives, in the data directory next to the session.
+pub fn cache_path() -> PathBuf {
+    data_dir().join(CACHE_FILE)
+}
+
+/// How long ago `fetched_at` was, for the status line: "just now", "5m ago", "3h ago".
+pub fn age_label(fetched_at: i64, now: i64) -> String {
+    let seconds = (now - fetched_at).max(0);
+    match seconds {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", seconds / 60),
+        3600..=86_399 => format!("{}h ago", seconds / 3600),
+        _ => format!("{}d ago", seconds / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(id: &str) -> Reminder {
+        Reminder {
+            id: id.to_string(),
+            title: id.to_string(),
+            notes: None,
+            completed: false,
+            priority: 0,
+            due_date: None,
+        }
+    }
+
+    fn list(id: &str) -> ReminderList {
+        ReminderList {
+            id: id.to_string(),
+            name: id.to_string(),
+            color: "#FF0000".to_string(),
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn test_cache_round_trips_and_drops_other_versions() {
+        let path = std::env::temp_dir()
+            .join(format!("rem-cache-{}", std::process::id()))
+            .join(CACHE_FILE);
+        let mut cache = ReminderCache::default();
+        cache.set_lists(&[list("work"), list("home")]);
+        cache.insert("work", vec![reminder("a")], 100);
+        cache.insert("home", vec![reminder("b")], 200);
+        cache.save(&path).unwrap();
+        assert_eq!(ReminderCache::load(&path), Some(cache.clone()));
+
+        // Reminders of lists that are gone are dropped
+        cache.set_lists(&[list("work")]);
+        assert!(cache.get("home").is_none());
+        assert_eq!(cache.get("work").unwrap().fetched_at, 100);
+
+        fs::write(&path, r#"{"version": 99, "lists": []}"#).unwrap();
+        assert_eq!(ReminderCache::load(&path), None);
+        cache.save(&path).unwrap();
+        assert_eq!(file_version(&path), Some(99), "newer file was overwritten");
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_move_reminder_between_entries() {
+        let mut cache = ReminderCache::default();
+        cache.insert("home", vec![reminder("a"), reminder("b")], 10);
+        cache.insert("work", vec![reminder("c")], 20);
+
+        cache.move_reminder(&reminder("b"), Some("home"), "work");
+        let ids = |cache: &ReminderCache, list: &str| -> Vec<String> {
+            let entry = cache.get(list).unwrap();
+            entry.reminders.iter().map(|r| r.id.clone()).collect()
+        };
+        assert_eq!(ids(&cache, "home"), ["a"]);
+        assert_eq!(ids(&cache, "work"), ["c", "b"]);
+        assert_eq!(cache.get("work").unwrap().fetched_at, 20);
+
+        // Only cached lists are touched
+        cache.move_reminder(&reminder("a"), Some("home"), "errands");
+        assert!(cache.get("home").unwrap().reminders.is_empty());
+        assert!(cache.get("errands").is_none());
+    }
+
+    #[test]
+    fn test_merge_counts_changes() {
+        let mut current = vec![reminder("a"), reminder("b"), reminder("c")];
+        let mut edited = reminder("b");
+        edited.completed = true;
+        let fresh = vec![reminder("a"), edited, reminder("d")];
+        assert_eq!(merge(&mut current, fresh.clone()), 3);
+        assert_eq!(current, fresh);
+        assert_eq!(merge(&mut current, fresh), 0);
+    }
+
+    #[test]
+    fn test_age_label() {
+        assert_eq!(age_label(1000, 1030), "just now");
+        assert_eq!(age_label(1000, 1000 + 5 * 60), "5m ago");
+        assert_eq!(age_label(1000, 1000 + 3 * 3600), "3h ago");
+        assert_eq!(age_label(1000, 1000 + 2 * 86_400), "2d ago");
+        assert_eq!(age_label(1000, 900), "just now");
+    }
+}
@@ -0,0 +1,139 @@
+//! Log pane: the buffered log records in place of the status box, filtered by level and
+//! scrolled from the newest record up.
+
+use crate::logging::LogRecord;
+use ratatui::style::Color;
+use tracing::Level;
+
+/// Rows the pane takes, borders included, when the terminal has room.
+pub const PANE_HEIGHT: u16 = 12;
+/// Rows PageUp/PageDown scroll.
+pub const PAGE: usize = 10;
+
+#[derive(Clone, Debug)]
+pub struct LogPane {
+    pub open: bool,
+    /// The least severe level shown.
+    pub level: Level,
+    /// Records scrolled up from the newest one.
+    pub scroll: usize,
+    /// Rows the records had when last drawn, to keep scrolling within them.
+    pub rows: usize,
+}
+
+impl LogPane {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            level: Level::INFO,
+            scroll: 0,
+            rows: PAGE,
+        }
+    }
+
+    /// Show one level more: error, warn, info, debug, trace, then back to error.
+    pub fn cycle_level(&mut self) {
+        self.level = match self.level {
+            Level::ERROR => Level::WARN,
+            Level::WARN => Level::INFO,
+            Level::INFO => Level::DEBUG,
+            Level::DEBUG => Level::TRACE,
+            _ => Level::ERROR,
+        };
+        self.scroll = 0;
+    }
+
+    /// Scroll towards older records for a positive `delta`, stopping with the oldest
+    /// record on the top row.
+    pub fn scroll_by(&mut self, delta: isize, total: usize) {
+        let max = total.saturating_sub(self.rows);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(max);
+    }
+
+    pub fn scroll_to_oldest(&mut self, total: usize) {
+        self.scroll = total.saturating_sub(self.rows);
+    }
+
+    /// The records that fit in `rows`, oldest first, ending `scroll` records before
+    /// the newest. Fewer records than the scroll assumed (after a level change or a
+    /// resize) show the oldest page.
+    pub fn visible<'a>(&self, records: &'a [LogRecord], rows: usize) -> &'a [LogRecord] {
+        let scroll = self.scroll.min(records.len().saturating_sub(rows));
+        let end = records.len() - scroll;
+        &records[end.saturating_sub(rows)..end]
+    }
+}
+
+impl Default for LogPane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => Color::Red,
+        Level::WARN => Color::Yellow,
+        Level::INFO => Color::Cyan,
+        Level::DEBUG => Color::Gray,
+        _ => Color::DarkGray,
+    }
+}
+
+/// `rem_core::tui::app` shows as `tui::app`; the crate name is the same everywhere.
+pub fn short_target(target: &str) -> &str {
+    target.strip_prefix("rem_core::").unwrap_or(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Local;
+
+    fn records(count: usize) -> Vec<LogRecord> {
+        (0..count)
+            .map(|i| LogRecord {
+                time: Local::now(),
+                level: Level::INFO,
+                target: "rem_core".to_string(),
+                message: i.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_scrolling_stays_within_records() {
+        let many = records(25);
+        let mut pane = LogPane::new();
+        let ids = |visible: &[LogRecord]| {
+            visible
+                .iter()
+                .map(|r| r.message.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(pane.visible(&many, 3)), ["22", "23", "24"]);
+
+        pane.scroll_by(4, many.len());
+        assert_eq!(ids(pane.visible(&many, 3)), ["18", "19", "20"]);
+        pane.scroll_by(100, many.len());
+        assert_eq!(pane.scroll, 15);
+        pane.scroll_by(-100, many.len());
+        assert_eq!(pane.scroll, 0);
+
+        pane.scroll_to_oldest(many.len());
+        assert_eq!(pane.visible(&many, PAGE)[0].message, "0");
+        assert_eq!(ids(pane.visible(&records(2), PAGE)), ["0", "1"]);
+    }
+
+    #[test]
+    fn test_cycle_level_wraps_and_resets_scroll() {
+        let mut pane = LogPane::new();
+        pane.scroll = 4;
+        pane.cycle_level();
+        assert_eq!(pane.level, Level::DEBUG);
+        assert_eq!(pane.scroll, 0);
+        pane.cycle_level();
+        pane.cycle_level();
+        assert_eq!(pane.level, Level::ERROR);
+    }
+}
@@ -1,5 +1,13 @@
+pub mod agenda;
 pub mod app;
+pub mod board;
+pub mod cache;
 pub mod components;
 pub mod events;
+pub mod log_pane;
+pub mod mouse;
+pub mod responsive;
+pub mod session;
+pub mod viewport;
 
 pub use app::TUIApp;
@@ -0,0 +1,158 @@
+//! Mouse hit-testing. Every frame records where rows, checkboxes and footer hints were
+//! drawn, so a click can be mapped back to what was under it.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::Color,
+    text::{Line, Span},
+};
+use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
+
+/// Two clicks on the same target within this window count as a double-click.
+pub const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    /// A row of the lists or reminders list, by index into the shown items.
+    Row(usize),
+    /// The checkbox of a reminder row.
+    Checkbox(usize),
+    /// A footer hint; clicking it presses its key.
+    Hint(KeyEvent),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct HitMap {
+    regions: Vec<(Rect, Target)>,
+}
+
+impl HitMap {
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    pub fn push(&mut self, area: Rect, target: Target) {
+        self.regions.push((area, target));
+    }
+
+    /// The target under a cell. Later regions sit on top, so a checkbox wins over its row.
+    pub fn target_at(&self, column: u16, row: u16) -> Option<Target> {
+        let position = Position::new(column, row);
+        self.regions
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, target)| *target)
+    }
+
+    /// Record the rows of a `List` drawn into `inner`, starting from the item at
+    /// `offset`. `checkbox_column` is the checkbox's offset from the left of a row.
+    pub fn record_rows(
+        &mut self,
+        inner: Rect,
+        offset: usize,
+        heights: &[usize],
+        checkbox_column: Option<u16>,
+    ) {
+        let drawn = heights.get(offset..).unwrap_or_default();
+        self.record_window(inner, offset, drawn, checkbox_column);
+    }
+
+    /// Like `record_rows`, for a list that only built the items it draws: `heights`
+    /// are those of the items from `first` on.
+    pub fn record_window(
+        &mut self,
+        inner: Rect,
+        first: usize,
+        heights: &[usize],
+        checkbox_column: Option<u16>,
+    ) {
+        let mut y = inner.y;
+        for (index, &height) in (first..).zip(heights) {
+            if y >= inner.bottom() {
+                break;
+            }
+            let height = u16::try_from(height)
+                .unwrap_or(u16::MAX)
+                .min(inner.bottom() - y);
+            self.push(
+                Rect::new(inner.x, y, inner.width, height),
+                Target::Row(index),
+            );
+            if let Some(column) = checkbox_column.filter(|&c| c < inner.width) {
+                self.push(
+                    Rect::new(inner.x + column, y, 2.min(inner.width - column), 1),
+                    Target::Checkbox(index),
+                );
+            }
+            y = y.saturating_add(height);
+        }
+    }
+
+    /// Record the key hints of `lines`, drawn centered into `area`, the inside of a
+    /// controls bar. Key labels are the spans with a dark gray background; each hint
+    /// covers its label and the description that follows.
+    pub fn record_hints(&mut self, lines: &[Line], area: Rect) {
+        for (y, line) in (area.top()..area.bottom()).zip(lines) {
+            let width = u16::try_from(line.width()).unwrap_or(u16::MAX);
+            let mut x = area.x + (area.width / 2).saturating_sub(width / 2);
+            let mut spans = line.spans.iter().peekable();
+            while let Some(span) = spans.next() {
+                let start = x;
+                x = x.saturating_add(span_width(span));
+                if !is_key_label(span) {
+                    continue;
+                }
+                let Some(key) = hint_key(span.content.trim()) else {
+                    continue;
+                };
+                // The description runs up to the next label, minus trailing spaces
+                let mut end = x;
+                while let Some(description) = spans.next_if(|next| !is_key_label(next)) {
+                    let text = description.content.trim_end();
+                    if !text.is_empty() {
+                        end = x.saturating_add(u16::try_from(text.width()).unwrap_or(u16::MAX));
+                    }
+                    x = x.saturating_add(span_width(description));
+                }
+                let end = end.min(area.right());
+                if start < end {
+                    self.push(Rect::new(start, y, end - start, 1), Target::Hint(key));
+                }
+            }
+        }
+    }
+}
+
+fn is_key_label(span: &Span) -> bool {
+    span.style.bg == Some(Color::DarkGray)
+}
+
+fn span_width(span: &Span) -> u16 {
+    u16::try_from(span.width()).unwrap_or(u16::MAX)
+}
+
+/// The key a footer label stands for. Navigation hints are left to the scroll wheel.
+fn hint_key(label: &str) -> Option<KeyEvent> {
+    let code = match label {
+        "⏎" | "⏎/space" => KeyCode::Enter,
+        "␣" => KeyCode::Char(' '),
+        "dd/Del" => KeyCode::Delete,
+        // `q` and Esc do the same everywhere, and Esc can't end up typed into a form
+        "q" | "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Ctrl+S" => {
+            return Some(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        }
+        _ => {
+            let mut chars = label.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphabetic() => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyEvent::new(code, KeyModifiers::NONE))
+}
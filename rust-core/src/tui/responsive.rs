@@ -0,0 +1,183 @@
+//! Responsive layout: how much chrome a screen gets for the terminal size, and
+//! truncation for text that doesn't fit.
+
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
+use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Below this height the controls and status bars lose their borders.
+pub const COMPACT_HEIGHT: u16 = 20;
+/// Below this height the controls bar is hidden altogether.
+pub const TINY_HEIGHT: u16 = 12;
+/// Below this width reminders are shown one line each.
+pub const COMPACT_WIDTH: u16 = 60;
+/// From this width on the reminders view keeps the lists visible beside it.
+pub const SPLIT_WIDTH: u16 = 120;
+/// Width of the lists sidebar in the split layout.
+pub const SIDEBAR_WIDTH: u16 = 32;
+/// Rows of content kept when the status area grows into it.
+pub const MIN_CONTENT: u16 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Screen {
+    /// Short terminal: borderless one-line status, collapsed controls, no margin.
+    pub short: bool,
+    /// Narrow terminal: no margin and one line per reminder.
+    pub narrow: bool,
+    /// Wide terminal: lists and reminders side by side.
+    pub wide: bool,
+}
+
+impl Screen {
+    pub fn of(area: Rect) -> Self {
+        Self {
+            short: area.height < COMPACT_HEIGHT,
+            narrow: area.width < COMPACT_WIDTH,
+            wide: area.width >= SPLIT_WIDTH,
+        }
+    }
+
+    /// One line per reminder, without notes or spacing.
+    pub fn compact(self) -> bool {
+        self.short || self.narrow
+    }
+}
+
+/// The regions of a standard screen.
+#[derive(Clone, Copy, Debug)]
+pub struct Areas {
+    pub search: Option<Rect>,
+    pub content: Rect,
+    /// `None` when the terminal is too short for hints.
+    pub controls: Option<Rect>,
+    pub status: Rect,
+    /// The controls and status bars are borderless.
+    pub collapsed: bool,
+}
+
+impl Areas {
+    /// Split `area` into an optional search bar, the content, a controls bar with
+    /// `hint_lines` lines of hints and the status log. `margin` applies to roomy
+    /// terminals only.
+    pub fn new(area: Rect, margin: u16, search: bool, hint_lines: u16) -> Self {
+        let screen = Screen::of(area);
+        let area = area.inner(Margin::new(
+            if screen.narrow { 0 } else { margin },
+            if screen.short { 0 } else { margin },
+        ));
+        let (controls, status) = if area.height < TINY_HEIGHT {
+            (0, 1)
+        } else if screen.short {
+            (hint_lines, 1)
+        } else {
+            (hint_lines + 2, 3)
+        };
+
+        let mut constraints = Vec::with_capacity(4);
+        if search {
+            constraints.push(Constraint::Length(3));
+        }
+        constraints.extend([
+            Constraint::Min(0),
+            Constraint::Length(controls),
+            Constraint::Length(status),
+        ]);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+
+        let offset = usize::from(search);
+        Self {
+            search: search.then(|| chunks[0]),
+            content: chunks[offset],
+            controls: (controls > 0).then(|| chunks[offset + 1]),
+            status: chunks[offset + 2],
+            collapsed: status == 1,
+        }
+    }
+
+    /// Grow the status area to `height` rows, taking them from the content but leaving
+    /// it at least `MIN_CONTENT` rows. The controls move up with it.
+    pub fn expand_status(mut self, height: u16) -> Self {
+        let extra = height
+            .saturating_sub(self.status.height)
+            .min(self.content.height.saturating_sub(MIN_CONTENT));
+        self.content.height -= extra;
+        if let Some(controls) = &mut self.controls {
+            controls.y -= extra;
+        }
+        self.status.y -= extra;
+        self.status.height += extra;
+        self.collapsed = self.status.height < 3;
+        self
+    }
+}
+
+/// Cut `text` to `width` columns, ending in an ellipsis when anything was dropped.
+pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > width - 1 {
+            break;
+        }
+        truncated.push(c);
+        used += char_width;
+    }
+    truncated.push('…');
+    Cow::Owned(truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_adds_ellipsis_only_when_needed() {
+        assert_eq!(truncate("Buy milk", 8), "Buy milk");
+        assert_eq!(truncate("Buy milk", 5), "Buy …");
+        assert_eq!(truncate("Buy milk", 1), "…");
+        assert_eq!(truncate("Buy milk", 0), "");
+        // Wide characters count double
+        assert_eq!(truncate("日本語のメモ", 7), "日本語…");
+    }
+
+    #[test]
+    fn test_areas_collapse_chrome_on_short_terminals() {
+        let roomy = Areas::new(Rect::new(0, 0, 80, 30), 1, false, 2);
+        assert_eq!(roomy.controls.map(|r| r.height), Some(4));
+        assert_eq!(roomy.status.height, 3);
+        assert_eq!(roomy.content, Rect::new(1, 1, 78, 21));
+
+        let short = Areas::new(Rect::new(0, 0, 80, 16), 1, true, 2);
+        assert_eq!(short.controls.map(|r| r.height), Some(2));
+        assert_eq!(short.status.height, 1);
+        assert_eq!(short.search.map(|r| r.height), Some(3));
+        assert_eq!(short.content.height, 10);
+
+        let tiny = Areas::new(Rect::new(0, 0, 40, 8), 1, false, 2);
+        assert!(tiny.controls.is_none());
+        assert_eq!(tiny.content, Rect::new(0, 0, 40, 7));
+    }
+
+    #[test]
+    fn test_expand_status_keeps_some_content() {
+        let roomy = Areas::new(Rect::new(0, 0, 80, 30), 1, false, 2).expand_status(12);
+        assert_eq!(roomy.status, Rect::new(1, 17, 78, 12));
+        assert_eq!(roomy.controls, Some(Rect::new(1, 13, 78, 4)));
+        assert_eq!(roomy.content, Rect::new(1, 1, 78, 12));
+
+        let tiny = Areas::new(Rect::new(0, 0, 40, 8), 1, false, 2).expand_status(12);
+        assert_eq!(tiny.content.height, 3);
+        assert_eq!(tiny.status, Rect::new(0, 3, 40, 5));
+        assert!(!tiny.collapsed);
+    }
+}
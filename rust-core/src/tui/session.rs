@@ -0,0 +1,176 @@
+//! UI state that survives restarts: the last open list, selections, toggles, layout
+//! and recent searches. Stored as versioned JSON in the data directory shared with
+//! the `rem` CLI.
+
+use super::agenda::AgendaMode;
+use super::board::BoardGrouping;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Bump when a field changes meaning, and teach `migrate` the old layout. Adding a
+/// field doesn't need a bump: missing fields take their defaults.
+pub const SESSION_VERSION: u32 = 1;
+const SESSION_FILE: &str = "tui-session.json";
+/// How many recent searches are kept, globally and per list.
+pub const RECENT_SEARCHES: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub version: u32,
+    /// The list open (or previewed) when the app last closed.
+    pub last_list_id: Option<String>,
+    /// Whether that list's reminders were on screen, rather than just highlighted.
+    pub list_open: bool,
+    /// Selected reminder per list id.
+    pub selection: BTreeMap<String, usize>,
+    pub show_completed: bool,
+    pub board_grouping: BoardGrouping,
+    pub agenda_mode: AgendaMode,
+    pub two_pane: bool,
+    /// Global searches, most recent first.
+    pub recent_searches: Vec<String>,
+    /// Searches within each list, most recent first, per list id.
+    pub list_searches: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            version: SESSION_VERSION,
+            last_list_id: None,
+            list_open: false,
+            selection: BTreeMap::new(),
+            show_completed: false,
+            board_grouping: BoardGrouping::List,
+            agenda_mode: AgendaMode::Week,
+            two_pane: false,
+            recent_searches: Vec::new(),
+            list_searches: BTreeMap::new(),
+        }
+    }
+}
+
+impl Session {
+    /// The saved session, or `None` when there is none or it can't be used. A file
+    /// written by a newer version is ignored rather than misread.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let value: serde_json::Value = serde_json::from_str(&contents).ok()?;
+        migrate(value)
+    }
+
+    /// Write atomically, so a crash mid-write never leaves a truncated file. A newer
+    /// version's session is left alone.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if file_version(path).is_some_and(|version| version > SESSION_VERSION) {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, path)
+    }
+}
+
+/// Put `query` at the front of the recent searches.
+pub fn remember_search(recent: &mut Vec<String>, query: &str) {
+    recent.retain(|q| q != query);
+    recent.insert(0, query.to_string());
+    recent.truncate(RECENT_SEARCHES);
+}
+
+pub(super) fn file_version(path: &Path) -> Option<u32> {
+    let contents = fs::read_to_string(path).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    version_of(&value)
+}
+
+fn version_of(value: &serde_json::Value) -> Option<u32> {
+    value
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|v| u32::try_from(v).ok())
+}
+
+/// Bring a session of any known version up to `SESSION_VERSION`.
+fn migrate(value: serde_json::Value) -> Option<Session> {
+    match version_of(&value).unwrap_or(SESSION_VERSION) {
+        version if version > SESSION_VERSION => None,
+        _ => {
+            let mut session: Session = serde_json::from_value(value).ok()?;
+            session.version = SESSION_VERSION;
+            Some(session)
+        }
+    }
+}
+
+/// Where the session lives, in the data directory.
+pub fn session_path() -> PathBuf {
+    data_dir().join(SESSION_FILE)
+}
+
+/// `$REM_DATA`, or the platform data directory, like the `rem` CLI's `get_data_dir`.
+pub(crate) fn data_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("REM_DATA") {
+        PathBuf::from(dir)
+    } else if let Some(dirs) = ProjectDirs::from("com", "kdheepak", "rem") {
+        dirs.data_local_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".data")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("rem-session-{}-{name}", std::process::id()))
+            .join(SESSION_FILE)
+    }
+
+    #[test]
+    fn test_session_round_trips_and_skips_newer_files() {
+        let path = temp_path("round-trip");
+        let mut session = Session {
+            last_list_id: Some("work".to_string()),
+            list_open: true,
+            show_completed: true,
+            board_grouping: BoardGrouping::Due,
+            two_pane: true,
+            ..Session::default()
+        };
+        session.selection.insert("work".to_string(), 3);
+        remember_search(&mut session.recent_searches, "milk");
+        session.save(&path).unwrap();
+        assert_eq!(Session::load(&path), Some(session.clone()));
+
+        fs::write(&path, r#"{"version": 99, "two_pane": false}"#).unwrap();
+        assert_eq!(Session::load(&path), None);
+        session.save(&path).unwrap();
+        assert_eq!(file_version(&path), Some(99), "newer file was overwritten");
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_session_fills_missing_fields_with_defaults() {
+        let session = migrate(serde_json::json!({ "show_completed": true })).unwrap();
+        assert!(session.show_completed);
+        assert_eq!(session.version, SESSION_VERSION);
+        assert_eq!(session.agenda_mode, AgendaMode::Week);
+
+        let mut recent = vec!["a".to_string(), "b".to_string()];
+        remember_search(&mut recent, "b");
+        assert_eq!(recent, ["b", "a"]);
+    }
+}
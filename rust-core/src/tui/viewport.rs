@@ -0,0 +1,122 @@
+//! Virtualized lists: which items of a long list are on screen, worked out the way
+//! ratatui's `List` scrolls, so only those rows have to be built.
+
+use std::ops::Range;
+
+/// The items `List` would draw in `rows` lines for a list of `len` items scrolled to
+/// `offset` with `selected` kept in view. `height` gives an item's height in lines and
+/// is only asked for items around the visible ones.
+pub fn visible_range(
+    len: usize,
+    offset: usize,
+    selected: Option<usize>,
+    rows: usize,
+    mut height: impl FnMut(usize) -> usize,
+) -> Range<usize> {
+    if len == 0 {
+        return 0..0;
+    }
+    let offset = offset.min(len - 1);
+    let mut first = offset;
+    let mut last = offset;
+    let mut used = 0;
+    while last < len {
+        let item = height(last);
+        if used + item > rows {
+            break;
+        }
+        used += item;
+        last += 1;
+    }
+
+    let target = selected.map_or(offset, |selected| selected.min(len - 1));
+    // Scroll down to the selection, dropping items from the top
+    while target >= last {
+        used += height(last);
+        last += 1;
+        while used > rows {
+            used -= height(first);
+            first += 1;
+        }
+    }
+    // Or up to it, dropping items from the bottom
+    while target < first {
+        first -= 1;
+        used += height(first);
+        while used > rows {
+            last -= 1;
+            used -= height(last);
+        }
+    }
+    first..last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        backend::TestBackend,
+        widgets::{List, ListItem, ListState},
+        Terminal,
+    };
+
+    /// The items `List` itself draws, read back from the buffer.
+    fn list_range(
+        heights: &[usize],
+        offset: usize,
+        selected: Option<usize>,
+        rows: u16,
+    ) -> Range<usize> {
+        let items: Vec<ListItem> = heights
+            .iter()
+            .enumerate()
+            .map(|(i, &h)| ListItem::new(vec![i.to_string(); h].join("\n")))
+            .collect();
+        let mut state = ListState::default()
+            .with_offset(offset)
+            .with_selected(selected);
+        let mut terminal = Terminal::new(TestBackend::new(10, rows)).unwrap();
+        terminal
+            .draw(|f| f.render_stateful_widget(List::new(items), f.area(), &mut state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let drawn: Vec<usize> = (0..rows)
+            .filter_map(|y| {
+                let line: String = (0..10).map(|x| buffer[(x, y)].symbol()).collect();
+                line.trim().parse().ok()
+            })
+            .collect();
+        match (drawn.first(), drawn.last()) {
+            (Some(&first), Some(&last)) => first..last + 1,
+            _ => state.offset()..state.offset(),
+        }
+    }
+
+    #[test]
+    fn test_visible_range_matches_list() {
+        let heights = [1, 3, 2, 1, 4, 1, 1, 2, 3, 1, 1, 2];
+        for offset in [0, 3, 7, 11, 20] {
+            for selected in [None, Some(0), Some(4), Some(8), Some(11), Some(30)] {
+                for rows in [1, 4, 7] {
+                    assert_eq!(
+                        visible_range(heights.len(), offset, selected, rows, |i| heights[i]),
+                        list_range(&heights, offset, selected, rows as u16),
+                        "offset {offset}, selected {selected:?}, rows {rows}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_range_only_measures_nearby_items() {
+        let mut measured = 0;
+        let range = visible_range(100_000, 50_000, Some(50_020), 10, |_| {
+            measured += 1;
+            2
+        });
+        assert_eq!(range, 50_016..50_021);
+        assert!(measured < 50, "measured {measured} items");
+        assert_eq!(visible_range(0, 3, Some(1), 10, |_| 1), 0..0);
+    }
+}
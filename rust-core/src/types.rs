@@ -1 +1,298 @@
 // Shared type definitions for the Rust core
+
+use chrono::{
+    DateTime, Datelike, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike,
+};
+
+/// How urgent a reminder is. Backends store priorities on different scales; the TUI
+/// works with these four levels and converts at the edges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Lowest to highest.
+    pub const ALL: [Priority; 4] = [
+        Priority::None,
+        Priority::Low,
+        Priority::Medium,
+        Priority::High,
+    ];
+
+    /// iCalendar `PRIORITY` (RFC 5545): 0 is undefined, 1-4 high, 5 medium, 6-9 low.
+    pub fn from_ical(value: u8) -> Self {
+        match value {
+            0 => Priority::None,
+            1..=4 => Priority::High,
+            5 => Priority::Medium,
+            _ => Priority::Low,
+        }
+    }
+
+    /// The canonical iCalendar value for each level: 0, 9, 5 or 1.
+    pub fn to_ical(self) -> u8 {
+        match self {
+            Priority::None => 0,
+            Priority::Low => 9,
+            Priority::Medium => 5,
+            Priority::High => 1,
+        }
+    }
+
+    /// EventKit uses the iCalendar scale. Reminders.app itself only ever writes
+    /// 0, 1, 5 and 9, but other clients can store anything from 0 to 9.
+    pub fn from_eventkit(value: u8) -> Self {
+        Self::from_ical(value)
+    }
+
+    pub fn to_eventkit(self) -> u8 {
+        self.to_ical()
+    }
+
+    /// todo.txt priority letters: `A` is high, `B` medium and anything from `C` on low.
+    pub fn from_todo_txt(letter: Option<char>) -> Self {
+        match letter.map(|c| c.to_ascii_uppercase()) {
+            Some('A') => Priority::High,
+            Some('B') => Priority::Medium,
+            Some('C'..='Z') => Priority::Low,
+            _ => Priority::None,
+        }
+    }
+
+    pub fn to_todo_txt(self) -> Option<char> {
+        match self {
+            Priority::None => None,
+            Priority::Low => Some('C'),
+            Priority::Medium => Some('B'),
+            Priority::High => Some('A'),
+        }
+    }
+
+    /// Reminders.app style: `!!!` high, `!!` medium, `!` low and nothing for none.
+    pub fn marker(self) -> &'static str {
+        match self {
+            Priority::None => "",
+            Priority::Low => "!",
+            Priority::Medium => "!!",
+            Priority::High => "!!!",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::None => "None",
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        }
+    }
+
+    /// One level up, stopping at high.
+    pub fn raise(self) -> Self {
+        match self {
+            Priority::None => Priority::Low,
+            Priority::Low => Priority::Medium,
+            Priority::Medium | Priority::High => Priority::High,
+        }
+    }
+
+    /// One level down, stopping at none.
+    pub fn lower(self) -> Self {
+        match self {
+            Priority::None | Priority::Low => Priority::None,
+            Priority::Medium => Priority::Low,
+            Priority::High => Priority::Medium,
+        }
+    }
+}
+
+/// When a reminder is due, in local time. All-day reminders have a date but no time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Due {
+    AllDay(NaiveDate),
+    At(DateTime<Local>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DueStatus {
+    Overdue,
+    Today,
+    Upcoming,
+}
+
+impl Due {
+    /// Accepts RFC 3339 timestamps (what Swift sends for timed reminders, usually UTC),
+    /// local date-times without an offset and plain dates for all-day reminders.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(text) {
+            return Some(Due::At(date_time.with_timezone(&Local)));
+        }
+        if let Ok(naive) = NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S") {
+            return Some(Due::At(local_from_naive(naive)));
+        }
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .map(Due::AllDay)
+    }
+
+    pub fn date(self) -> NaiveDate {
+        match self {
+            Due::AllDay(date) => date,
+            Due::At(date_time) => date_time.date_naive(),
+        }
+    }
+
+    pub fn time(self) -> Option<NaiveTime> {
+        match self {
+            Due::AllDay(_) => None,
+            Due::At(date_time) => Some(date_time.time()),
+        }
+    }
+
+    /// An all-day reminder only becomes overdue once its day is over.
+    pub fn status(self, now: DateTime<Local>) -> DueStatus {
+        let overdue = match self {
+            Due::AllDay(date) => date < now.date_naive(),
+            Due::At(date_time) => date_time < now,
+        };
+        if overdue {
+            DueStatus::Overdue
+        } else if self.date() == now.date_naive() {
+            DueStatus::Today
+        } else {
+            DueStatus::Upcoming
+        }
+    }
+
+    /// "today 3pm", "tomorrow", "in 2d", "3d overdue" or a date further out.
+    pub fn relative(self, now: DateTime<Local>) -> String {
+        let days = (self.date() - now.date_naive()).num_days();
+        let at = |day: &str| match self.time() {
+            Some(time) => format!("{day} {}", clock(time)),
+            None => day.to_string(),
+        };
+        match days {
+            ..=-1 => format!("{}d overdue", -days),
+            0 => at("today"),
+            1 => at("tomorrow"),
+            2..=6 => format!("in {days}d"),
+            _ if self.date().year() == now.year() => self.date().format("%-d %b").to_string(),
+            _ => self.date().format("%-d %b %Y").to_string(),
+        }
+    }
+}
+
+/// "3pm" on the hour, "3:30pm" otherwise.
+fn clock(time: NaiveTime) -> String {
+    if time.minute() == 0 {
+        time.format("%-I%P").to_string()
+    } else {
+        time.format("%-I:%M%P").to_string()
+    }
+}
+
+/// A wall-clock time in the local zone. Ambiguous times (clocks going back) take the
+/// first occurrence; times skipped by clocks going forward move past the gap.
+fn local_from_naive(naive: NaiveDateTime) -> DateTime<Local> {
+    match Local.from_local_datetime(&naive) {
+        LocalResult::Single(date_time) | LocalResult::Ambiguous(date_time, _) => date_time,
+        LocalResult::None => local_from_naive(naive + Duration::hours(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_round_trips_through_every_backend() {
+        for priority in Priority::ALL {
+            assert_eq!(Priority::from_eventkit(priority.to_eventkit()), priority);
+            assert_eq!(Priority::from_ical(priority.to_ical()), priority);
+            assert_eq!(Priority::from_todo_txt(priority.to_todo_txt()), priority);
+        }
+    }
+
+    #[test]
+    fn test_priority_reads_the_whole_eventkit_range() {
+        let levels: Vec<Priority> = (0..=9).map(Priority::from_eventkit).collect();
+        use Priority::*;
+        assert_eq!(
+            levels,
+            [None, High, High, High, High, Medium, Low, Low, Low, Low]
+        );
+        assert_eq!(Priority::from_todo_txt(Some('d')), Low);
+        assert_eq!(Priority::from_todo_txt(Some('1')), None);
+    }
+
+    fn at(text: &str) -> DateTime<Local> {
+        match Due::parse(text) {
+            Some(Due::At(date_time)) => date_time,
+            other => panic!("{text} parsed as {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_due_parses_timestamps_into_local_time() {
+        let utc = Due::parse("2025-10-15T12:00:00Z").unwrap();
+        assert_eq!(
+            utc,
+            Due::At(
+                DateTime::parse_from_rfc3339("2025-10-15T12:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Local)
+            )
+        );
+        assert_eq!(
+            Due::parse(" 2025-10-15 "),
+            Some(Due::AllDay(NaiveDate::from_ymd_opt(2025, 10, 15).unwrap()))
+        );
+        assert_eq!(at("2025-10-15T09:30:00").time().hour(), 9);
+        assert_eq!(Due::parse("next tuesday"), None);
+    }
+
+    #[test]
+    fn test_due_reads_swift_payloads() {
+        // What RemindersService sends: a plain date for all-day reminders and an
+        // ISO 8601 UTC timestamp for timed ones
+        let all_day = Due::parse("2025-10-15").unwrap();
+        assert_eq!(
+            all_day,
+            Due::AllDay(NaiveDate::from_ymd_opt(2025, 10, 15).unwrap())
+        );
+        assert_eq!(all_day.time(), None);
+        assert_eq!(all_day.status(at("2025-10-15T23:30:00")), DueStatus::Today);
+
+        let timed = at("2025-10-15T13:30:00Z");
+        assert_eq!(
+            timed,
+            DateTime::parse_from_rfc3339("2025-10-15T13:30:00+00:00").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_due_relative_and_status() {
+        let now = at("2025-10-15T12:00:00");
+        let check = |text: &str, relative: &str, status: DueStatus| {
+            let due = Due::parse(text).unwrap();
+            assert_eq!(due.relative(now), relative, "{text}");
+            assert_eq!(due.status(now), status, "{text}");
+        };
+        check("2025-10-15T15:00:00", "today 3pm", DueStatus::Today);
+        check("2025-10-15T09:30:00", "today 9:30am", DueStatus::Overdue);
+        // All day: due until midnight
+        check("2025-10-15", "today", DueStatus::Today);
+        check("2025-10-16T09:00:00", "tomorrow 9am", DueStatus::Upcoming);
+        check("2025-10-17", "in 2d", DueStatus::Upcoming);
+        check("2025-10-12", "3d overdue", DueStatus::Overdue);
+        check("2025-11-02", "2 Nov", DueStatus::Upcoming);
+        check("2026-01-05", "5 Jan 2026", DueStatus::Upcoming);
+    }
+}
@@ -0,0 +1,1034 @@
+//! Headless TUI tests.
+//!
+//! `Harness` drives a `TUIApp` with scripted key events, renders it into ratatui's
+//! `TestBackend` and snapshot-compares the buffer, so rendering regressions are caught
+//! without a real terminal. Update snapshots with `INSTA_UPDATE=always cargo test`
+//! (or `cargo insta review`).
+
+use chrono::{Local, NaiveDate, TimeZone};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::{backend::TestBackend, style::Color, Terminal};
+use rem_core::logging::LogRecord;
+use rem_core::tui::agenda::AgendaMode;
+use rem_core::tui::app::AppView;
+use rem_core::tui::board::BoardGrouping;
+use rem_core::tui::session::Session;
+use rem_core::tui::TUIApp;
+use rem_core::{Reminder, ReminderList, TuiAction};
+
+const WIDTH: u16 = 80;
+const HEIGHT: u16 = 30;
+
+struct Harness {
+    app: TUIApp,
+    terminal: Terminal<TestBackend>,
+    actions: Vec<TuiAction>,
+}
+
+impl Harness {
+    fn new(lists: Vec<ReminderList>) -> Self {
+        Self::with_size(lists, WIDTH, HEIGHT)
+    }
+
+    fn with_size(lists: Vec<ReminderList>, width: u16, height: u16) -> Self {
+        let app = TUIApp::new(lists).expect("Failed to create TUI app");
+        let terminal =
+            Terminal::new(TestBackend::new(width, height)).expect("Failed to create terminal");
+        Self {
+            app,
+            terminal,
+            actions: Vec::new(),
+        }
+    }
+
+    fn press(&mut self, code: KeyCode) -> &mut Self {
+        self.send(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn ctrl(&mut self, c: char) -> &mut Self {
+        self.send(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+    }
+
+    /// Press each character of `script` as a plain key.
+    fn keys(&mut self, script: &str) -> &mut Self {
+        for c in script.chars() {
+            self.press(KeyCode::Char(c));
+        }
+        self
+    }
+
+    fn send(&mut self, key: KeyEvent) -> &mut Self {
+        self.app.handle_key_event_for_test(key);
+        self.actions.extend(self.app.take_actions());
+        self
+    }
+
+    fn mouse(&mut self, kind: MouseEventKind, column: u16, row: u16) -> &mut Self {
+        self.app.handle_mouse_event_for_test(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        });
+        self.actions.extend(self.app.take_actions());
+        self
+    }
+
+    fn click(&mut self, (column, row): (u16, u16)) -> &mut Self {
+        self.mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    /// Resize the terminal the way crossterm reports it.
+    fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.terminal.backend_mut().resize(width, height);
+        self.app.handle_event_for_test(Event::Resize(width, height));
+        self
+    }
+
+    /// Where `text` starts on screen in the last rendered frame.
+    fn locate(&self, text: &str) -> (u16, u16) {
+        let buffer = self.terminal.backend().buffer();
+        for y in 0..buffer.area.height {
+            let mut line = String::new();
+            let mut columns = Vec::new();
+            for x in 0..buffer.area.width {
+                let symbol = buffer[(x, y)].symbol();
+                columns.extend(std::iter::repeat_n(x, symbol.len()));
+                line.push_str(symbol);
+            }
+            if let Some(offset) = line.find(text) {
+                return (columns[offset], y);
+            }
+        }
+        panic!("{text:?} is not on screen");
+    }
+
+    /// Foreground color of the first cell of `text` in the last rendered frame.
+    fn color_of(&self, text: &str) -> Color {
+        self.terminal.backend().buffer()[self.locate(text)].fg
+    }
+
+    fn take_actions(&mut self) -> Vec<TuiAction> {
+        std::mem::take(&mut self.actions)
+    }
+
+    /// Draw one frame and return the buffer as text, one quoted line per row.
+    fn render(&mut self) -> String {
+        self.app
+            .draw(&mut self.terminal)
+            .expect("Failed to draw frame");
+        self.terminal.backend().to_string()
+    }
+}
+
+fn lists() -> Vec<ReminderList> {
+    vec![
+        ReminderList {
+            id: "groceries".to_string(),
+            name: "Groceries".to_string(),
+            color: "#34C759".to_string(),
+            count: 3,
+        },
+        ReminderList {
+            id: "work".to_string(),
+            name: "Work".to_string(),
+            color: "#007AFF".to_string(),
+            count: 1,
+        },
+        ReminderList {
+            id: "someday".to_string(),
+            name: "Someday".to_string(),
+            color: "#FF9500".to_string(),
+            count: 0,
+        },
+    ]
+}
+
+fn reminder(id: &str, title: &str, notes: Option<&str>, completed: bool) -> Reminder {
+    Reminder {
+        id: id.to_string(),
+        title: title.to_string(),
+        notes: notes.map(str::to_string),
+        completed,
+        priority: 0,
+        due_date: None,
+    }
+}
+
+fn groceries() -> Vec<Reminder> {
+    vec![
+        reminder("g-1", "Buy milk", Some("Oat, not dairy"), false),
+        reminder("g-2", "Bread", None, false),
+        reminder("g-3", "Coffee beans", None, true),
+    ]
+}
+
+/// Open the Groceries list the way Swift would: Enter emits `SelectList`, then the
+/// data arrives through `set_reminders`.
+fn open_groceries(harness: &mut Harness) {
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(groceries());
+}
+
+#[test]
+fn test_snapshot_lists_view() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Down);
+
+    insta::assert_snapshot!("lists_view", harness.render());
+    assert!(
+        harness.take_actions().is_empty(),
+        "Navigation emits nothing"
+    );
+}
+
+#[test]
+fn test_snapshot_lists_empty() {
+    let mut harness = Harness::new(Vec::new());
+    // With no lists the app waits for data on the loading screen
+    assert!(matches!(harness.app.get_current_view(), AppView::Loading));
+    harness.app.set_current_view(AppView::Lists);
+
+    insta::assert_snapshot!("lists_empty", harness.render());
+}
+
+#[test]
+fn test_snapshot_loading_view() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::SelectList { list_id }] if list_id == "groceries"),
+        "Enter should select the highlighted list, got {actions:?}"
+    );
+    insta::assert_snapshot!("loading_view", harness.render());
+}
+
+#[test]
+fn test_snapshot_reminders_view() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.take_actions();
+    harness.press(KeyCode::Char('j'));
+
+    insta::assert_snapshot!("reminders_view", harness.render());
+
+    // Showing completed reminders adds the crossed-out one
+    harness.press(KeyCode::Char('h'));
+    insta::assert_snapshot!("reminders_view_with_completed", harness.render());
+    assert!(
+        harness.take_actions().is_empty(),
+        "Visibility is handled locally"
+    );
+}
+
+#[test]
+fn test_snapshot_reminders_empty() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(Vec::new());
+
+    insta::assert_snapshot!("reminders_empty", harness.render());
+}
+
+#[test]
+fn test_snapshot_reminders_all_completed() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+    harness
+        .app
+        .set_reminders(vec![reminder("g-3", "Coffee beans", None, true)]);
+
+    insta::assert_snapshot!("reminders_all_completed", harness.render());
+}
+
+#[test]
+fn test_snapshot_list_search() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.take_actions();
+    harness.keys("/bre");
+
+    insta::assert_snapshot!("reminders_list_search", harness.render());
+    assert!(harness.take_actions().is_empty(), "List search is local");
+}
+
+#[test]
+fn test_snapshot_global_search() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Char('/'));
+
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::GlobalSearch { query }] if query.is_empty()),
+        "'/' on the lists screen should request global data, got {actions:?}"
+    );
+
+    let all: Vec<(Reminder, String)> = groceries()
+        .into_iter()
+        .map(|r| (r, "Groceries".to_string()))
+        .chain(std::iter::once((
+            reminder("w-1", "Send invoice", None, false),
+            "Work".to_string(),
+        )))
+        .collect();
+    let reminders = all.iter().map(|(r, _)| r.clone()).collect();
+    harness.app.set_reminders_with_global_data(reminders, all);
+    harness.keys("i");
+
+    insta::assert_snapshot!("global_search", harness.render());
+}
+
+#[test]
+fn test_snapshot_create_form() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Char('c'));
+    harness.keys("Buy milk");
+    harness.press(KeyCode::Tab);
+    harness.keys("2 litres");
+
+    insta::assert_snapshot!("create_form", harness.render());
+
+    harness.ctrl('s');
+    let actions = harness.take_actions();
+    match actions.as_slice() {
+        [TuiAction::CreateReminder { new_reminder }] => {
+            assert_eq!(new_reminder.title, "Buy milk");
+            assert_eq!(new_reminder.notes.as_deref(), Some("2 litres"));
+            assert_eq!(new_reminder.list_id, "groceries");
+        }
+        other => panic!("Expected a CreateReminder action, got {other:?}"),
+    }
+}
+
+fn due(id: &str, title: &str, due_date: &str) -> Reminder {
+    Reminder {
+        due_date: Some(due_date.to_string()),
+        ..reminder(id, title, None, false)
+    }
+}
+
+/// Open the agenda on Wednesday 15 October 2025 with a few due reminders loaded.
+fn open_agenda(harness: &mut Harness) {
+    let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+    harness.app.open_agenda(today);
+    let actions = harness.app.take_actions();
+    harness.actions.extend(actions);
+    harness.app.set_all_reminders(vec![
+        (
+            due("g-1", "Buy milk", "2025-10-15"),
+            "Groceries".to_string(),
+        ),
+        (
+            due("w-1", "Send invoice", "2025-10-15T09:30:00"),
+            "Work".to_string(),
+        ),
+        (
+            due("w-2", "Team sync", "2025-10-17T14:00:00"),
+            "Work".to_string(),
+        ),
+        (due("s-1", "Plan trip", "2025-11-02"), "Someday".to_string()),
+        (
+            reminder("g-2", "Bread", None, false),
+            "Groceries".to_string(),
+        ),
+    ]);
+}
+
+#[test]
+fn test_snapshot_agenda() {
+    let mut harness = Harness::new(lists());
+    open_agenda(&mut harness);
+    assert!(matches!(
+        harness.take_actions().as_slice(),
+        [TuiAction::LoadAllReminders]
+    ));
+
+    insta::assert_snapshot!("agenda_week", harness.render());
+
+    // Two days on, in day mode
+    harness.keys("llw");
+    insta::assert_snapshot!("agenda_day", harness.render());
+
+    // Next month, back in week mode; nothing is due on 17 November
+    harness.keys("]w");
+    insta::assert_snapshot!("agenda_next_month", harness.render());
+    assert!(harness.take_actions().is_empty(), "Navigation is local");
+}
+
+#[test]
+fn test_agenda_create_prefills_selected_day() {
+    let mut harness = Harness::new(lists());
+    open_agenda(&mut harness);
+    harness.take_actions();
+
+    harness.press(KeyCode::Down);
+    harness.press(KeyCode::Char('c'));
+    harness.keys("Dentist");
+    harness.ctrl('s');
+
+    match harness.take_actions().as_slice() {
+        [TuiAction::CreateReminder { new_reminder }] => {
+            assert_eq!(new_reminder.title, "Dentist");
+            assert_eq!(new_reminder.due_date.as_deref(), Some("2025-10-22"));
+        }
+        other => panic!("Expected a CreateReminder action, got {other:?}"),
+    }
+    assert!(matches!(harness.app.get_current_view(), AppView::Agenda));
+    assert!(
+        harness.render().contains("Dentist"),
+        "The new reminder should show up in the agenda right away"
+    );
+}
+
+/// Open the board with groceries and work reminders loaded.
+fn open_board(harness: &mut Harness) {
+    harness.app.open_board();
+    let actions = harness.app.take_actions();
+    harness.actions.extend(actions);
+    let mut urgent = reminder("w-1", "Send invoice", None, false);
+    urgent.priority = 1;
+    harness.app.set_all_reminders(
+        groceries()
+            .into_iter()
+            .map(|r| (r, "Groceries".to_string()))
+            .chain([
+                (urgent, "Work".to_string()),
+                (
+                    reminder("w-2", "Book flights", None, false),
+                    "Work".to_string(),
+                ),
+            ])
+            .collect(),
+    );
+}
+
+#[test]
+fn test_snapshot_board() {
+    let mut harness = Harness::new(lists());
+    open_board(&mut harness);
+    assert!(matches!(
+        harness.take_actions().as_slice(),
+        [TuiAction::LoadAllReminders]
+    ));
+
+    insta::assert_snapshot!("board_by_list", harness.render());
+
+    // Focusing the third list scrolls the columns sideways
+    harness.keys("ll");
+    insta::assert_snapshot!("board_scrolled", harness.render());
+
+    harness.keys("g");
+    insta::assert_snapshot!("board_by_priority", harness.render());
+    assert!(harness.take_actions().is_empty(), "Navigation is local");
+}
+
+#[test]
+fn test_board_move_card_between_lists() {
+    let mut harness = Harness::new(lists());
+    open_board(&mut harness);
+    harness.take_actions();
+
+    // Bread moves from Groceries to Work and stays focused
+    harness.keys("jL");
+    assert!(
+        matches!(
+            harness.take_actions().as_slice(),
+            [TuiAction::MoveReminder { reminder_id, list_id }]
+                if reminder_id == "g-2" && list_id == "work"
+        ),
+        "H/L should emit a move"
+    );
+    assert_eq!(harness.app.get_list_name_for_reminder("g-2"), Some("Work"));
+    let counts: Vec<u32> = harness.app.get_lists().iter().map(|l| l.count).collect();
+    assert_eq!(counts, vec![2, 2, 0]);
+
+    // A failed move puts it back
+    harness.app.resolve_pending_change("g-2", false);
+    assert_eq!(
+        harness.app.get_list_name_for_reminder("g-2"),
+        Some("Groceries")
+    );
+    let counts: Vec<u32> = harness.app.get_lists().iter().map(|l| l.count).collect();
+    assert_eq!(counts, vec![3, 1, 0]);
+}
+
+#[test]
+fn test_board_move_updates_cached_lists() {
+    let mut harness = Harness::new(lists());
+    open_board(&mut harness);
+    harness.keys("jL");
+    harness.press(KeyCode::Char('q'));
+
+    // Both lists open from the cache already showing the move
+    let mut open_cached = |list_id: &str| -> Vec<String> {
+        while harness.app.get_lists()[harness.app.get_selected_index()].id != list_id {
+            harness.press(KeyCode::Down);
+        }
+        harness.press(KeyCode::Enter);
+        let ids = harness
+            .app
+            .get_filtered_reminders_for_test()
+            .iter()
+            .map(|r| r.id.clone())
+            .collect();
+        harness.press(KeyCode::Char('q'));
+        ids
+    };
+    assert_eq!(open_cached("groceries"), ["g-1"]);
+    assert_eq!(open_cached("work"), ["w-1", "w-2", "g-2"]);
+}
+
+#[test]
+fn test_board_move_card_between_priorities() {
+    let mut harness = Harness::new(lists());
+    open_board(&mut harness);
+    harness.take_actions();
+
+    // Send invoice is the only high priority card; drop it to medium
+    harness.keys("gL");
+    assert!(
+        matches!(
+            harness.take_actions().as_slice(),
+            [TuiAction::SetPriority { reminder_id, priority: 5 }] if reminder_id == "w-1"
+        ),
+        "Moving between priority columns should emit a priority change"
+    );
+
+    // Due buckets are read-only
+    harness.keys("gLH");
+    assert!(harness.take_actions().is_empty());
+}
+
+#[test]
+fn test_mouse_select_and_open_list() {
+    let mut harness = Harness::new(lists());
+    harness.render();
+
+    let work = harness.locate("Work");
+    harness.click(work);
+    assert_eq!(harness.app.get_selected_index(), 1);
+    assert!(
+        harness.take_actions().is_empty(),
+        "A single click only selects"
+    );
+
+    harness.click(work);
+    assert!(
+        matches!(
+            harness.take_actions().as_slice(),
+            [TuiAction::SelectList { list_id }] if list_id == "work"
+        ),
+        "Double-click should open the list"
+    );
+}
+
+#[test]
+fn test_mouse_checkbox_and_scroll_wheel() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.take_actions();
+    harness.render();
+
+    // Clicking the title only selects; clicking the checkbox toggles
+    let (title_x, title_y) = harness.locate("Bread");
+    harness.click((title_x, title_y));
+    assert_eq!(harness.app.get_selected_index(), 1);
+    assert!(harness.take_actions().is_empty());
+
+    harness.click((title_x - 3, title_y));
+    assert!(matches!(
+        harness.take_actions().as_slice(),
+        [TuiAction::ToggleReminder { reminder_id }] if reminder_id == "g-2"
+    ));
+
+    harness.render();
+    harness.mouse(MouseEventKind::ScrollUp, 40, 10);
+    assert_eq!(harness.app.get_selected_index(), 0);
+}
+
+#[test]
+fn test_mouse_footer_hints() {
+    let mut harness = Harness::new(lists());
+    harness.render();
+
+    let create = harness.locate("c create");
+    // The description is part of the hint, not just the key label
+    harness.click((create.0 + 4, create.1));
+    assert!(matches!(
+        harness.app.get_current_view(),
+        AppView::CreateReminder
+    ));
+
+    harness.render();
+    let cancel = harness.locate("cancel");
+    harness.click(cancel);
+    assert!(matches!(harness.app.get_current_view(), AppView::Lists));
+    harness.take_actions();
+
+    // Clicks outside any target do nothing
+    harness.render();
+    harness.click((0, 0));
+    assert!(matches!(harness.app.get_current_view(), AppView::Lists));
+    assert!(harness.take_actions().is_empty());
+}
+
+fn long_groceries() -> Vec<Reminder> {
+    let mut reminders = groceries();
+    reminders[1].title = "Bread from the bakery on the corner, the sourdough one".to_string();
+    reminders
+}
+
+#[test]
+fn test_snapshot_compact_after_resize() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(long_groceries());
+    harness.render();
+
+    // A small terminal collapses the chrome and shows one line per reminder
+    harness.resize(50, 16);
+    insta::assert_snapshot!("reminders_compact", harness.render());
+
+    // Back to a roomy terminal, the full layout returns
+    harness.resize(WIDTH, HEIGHT);
+    assert!(harness.render().contains("Oat, not dairy"));
+}
+
+#[test]
+fn test_snapshot_tiny_terminal_hides_controls() {
+    let mut harness = Harness::with_size(lists(), 40, 10);
+    insta::assert_snapshot!("lists_tiny", harness.render());
+}
+
+#[test]
+fn test_snapshot_wide_split() {
+    let mut harness = Harness::with_size(lists(), 130, HEIGHT);
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(long_groceries());
+
+    insta::assert_snapshot!("reminders_wide_split", harness.render());
+}
+
+#[test]
+fn test_two_pane_previews_highlighted_list() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Char('v'));
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::PreviewList { list_id }] if list_id == "groceries"),
+        "Turning on two panes previews the highlighted list, got {actions:?}"
+    );
+    harness.app.set_reminders(groceries());
+    assert!(matches!(harness.app.get_current_view(), AppView::Lists));
+    insta::assert_snapshot!("two_pane_lists_focused", harness.render());
+
+    // Moving through the lists previews each one, once
+    harness.press(KeyCode::Down);
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::PreviewList { list_id }] if list_id == "work"),
+        "Unexpected actions: {actions:?}"
+    );
+    harness.press(KeyCode::Char('j'));
+    harness.press(KeyCode::Char('k'));
+    assert_eq!(harness.take_actions().len(), 2);
+}
+
+#[test]
+fn test_two_pane_tab_switches_focus() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Char('v'));
+    harness.app.set_reminders(groceries());
+    harness.take_actions();
+
+    harness.press(KeyCode::Tab);
+    assert!(matches!(
+        harness.app.get_current_view(),
+        AppView::Reminders { list_id } if list_id == "groceries"
+    ));
+    harness.press(KeyCode::Down);
+    insta::assert_snapshot!("two_pane_reminders_focused", harness.render());
+
+    // Toggling works on the focused pane; switching back keeps the list highlighted
+    harness.press(KeyCode::Char(' '));
+    harness.press(KeyCode::Tab);
+    assert!(matches!(harness.app.get_current_view(), AppView::Lists));
+    assert_eq!(harness.app.get_selected_index(), 0);
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::ToggleReminder { reminder_id }] if reminder_id == "g-2"),
+        "Unexpected actions: {actions:?}"
+    );
+}
+
+#[test]
+fn test_session_restores_board_grouping_and_agenda_mode() {
+    let path = std::env::temp_dir()
+        .join(format!("rem-harness-views-{}", std::process::id()))
+        .join("tui-session.json");
+    let saved = Session {
+        board_grouping: BoardGrouping::Priority,
+        agenda_mode: AgendaMode::Day,
+        ..Session::default()
+    };
+    saved.save(&path).expect("Failed to save session");
+
+    let mut harness = Harness::new(lists());
+    harness.app.restore_session(path.clone());
+    harness.press(KeyCode::Char('b'));
+    harness.app.set_all_reminders(
+        groceries()
+            .into_iter()
+            .map(|r| (r, "Groceries".to_string()))
+            .collect(),
+    );
+    assert!(matches!(harness.app.get_current_view(), AppView::Board));
+    harness.render();
+    harness.locate("!!! High (0)");
+
+    harness.press(KeyCode::Char('q')).press(KeyCode::Char('a'));
+    assert!(matches!(harness.app.get_current_view(), AppView::Agenda));
+    harness.render();
+    harness.locate(" Day ─");
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_session_restores_last_list_and_selection() {
+    let path = std::env::temp_dir()
+        .join(format!("rem-harness-{}", std::process::id()))
+        .join("tui-session.json");
+    let mut saved = Session {
+        last_list_id: Some("groceries".to_string()),
+        list_open: true,
+        show_completed: true,
+        ..Session::default()
+    };
+    saved.selection.insert("groceries".to_string(), 2);
+    saved.save(&path).expect("Failed to save session");
+
+    let mut harness = Harness::new(lists());
+    harness.app.restore_session(path.clone());
+    let actions = harness.app.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::SelectList { list_id }] if list_id == "groceries"),
+        "The last open list is reopened, got {actions:?}"
+    );
+    harness.app.set_reminders(groceries());
+    assert!(harness.app.show_completed_todos());
+    assert_eq!(harness.app.get_selected_index(), 2);
+
+    // Searching and moving are remembered for the next launch
+    harness
+        .keys("/milk")
+        .press(KeyCode::Enter)
+        .press(KeyCode::Esc);
+    harness.press(KeyCode::Char('q'));
+    harness.press(KeyCode::Char('v'));
+    harness.app.save_session();
+    let restored = Session::load(&path).expect("Session was not saved");
+    assert_eq!(restored.list_searches["groceries"], ["milk"]);
+    assert!(restored.recent_searches.is_empty());
+    assert!(restored.two_pane);
+    assert!(!restored.list_open);
+    assert_eq!(restored.selection.get("groceries"), Some(&0));
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_cached_list_opens_instantly_and_revalidates() {
+    let path = std::env::temp_dir()
+        .join(format!("rem-harness-cache-{}", std::process::id()))
+        .join("tui-cache.json");
+    let mut harness = Harness::new(lists());
+    harness.app.restore_cache(path.clone());
+    open_groceries(&mut harness);
+    harness.press(KeyCode::Char('q'));
+    harness.take_actions();
+
+    // The second open shows last time's reminders right away and still refetches
+    harness.press(KeyCode::Enter);
+    assert!(matches!(
+        harness.app.get_current_view(),
+        AppView::Reminders { list_id } if list_id == "groceries"
+    ));
+    assert_eq!(harness.app.get_filtered_reminders_for_test().len(), 2);
+    let actions = harness.take_actions();
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::SelectList { list_id }] if list_id == "groceries"),
+        "Unexpected actions: {actions:?}"
+    );
+
+    // Fresh data is merged without moving the cursor off the selected reminder
+    harness.press(KeyCode::Down);
+    let mut fresh = groceries();
+    fresh.insert(0, reminder("g-4", "Eggs", None, false));
+    harness.app.set_reminders(fresh);
+    assert_eq!(harness.app.get_selected_index(), 2);
+    let selected = &harness.app.get_filtered_reminders_for_test()[2];
+    assert_eq!(selected.id, "g-2");
+
+    // A cold start without lists yet shows the cached ones
+    harness.app.save_cache();
+    let mut cold = Harness::new(Vec::new());
+    cold.app.restore_cache(path.clone());
+    assert!(matches!(cold.app.get_current_view(), AppView::Lists));
+    assert_eq!(cold.app.get_lists().len(), 3);
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_cached_rows_are_drawn_while_the_fetch_is_outstanding() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.press(KeyCode::Char('q'));
+    harness.take_actions();
+
+    // Swift gets `SelectList` back with the cached rows already on screen, and only
+    // draws again after the fetch
+    let actions = harness
+        .app
+        .run_persistent_iteration_for_test(
+            &mut harness.terminal,
+            [Event::Key(KeyEvent::new(
+                KeyCode::Enter,
+                KeyModifiers::NONE,
+            ))],
+        )
+        .expect("Failed to run iteration");
+    assert!(
+        matches!(actions.as_slice(), [TuiAction::SelectList { list_id }] if list_id == "groceries"),
+        "Unexpected actions: {actions:?}"
+    );
+    harness.locate("Buy milk");
+    harness.locate("Bread");
+}
+
+#[test]
+fn test_search_history_is_kept_per_scope() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.keys("/milk").press(KeyCode::Enter);
+    harness.keys("/bread").press(KeyCode::Enter);
+
+    // Up walks back through this list's searches, Down returns to what was typed
+    harness.keys("/co");
+    harness.press(KeyCode::Up);
+    assert_eq!(harness.app.get_search_query(), "bread");
+    harness.press(KeyCode::Up).press(KeyCode::Up);
+    assert_eq!(harness.app.get_search_query(), "milk");
+    harness.press(KeyCode::Down).press(KeyCode::Down);
+    assert_eq!(harness.app.get_search_query(), "co");
+    harness.press(KeyCode::Esc).press(KeyCode::Esc);
+
+    // Another list has its own (empty) history
+    harness
+        .press(KeyCode::Char('q'))
+        .press(KeyCode::Down)
+        .press(KeyCode::Enter);
+    harness.app.set_reminders(Vec::new());
+    harness.keys("/");
+    harness.press(KeyCode::Up);
+    assert_eq!(harness.app.get_search_query(), "");
+}
+
+#[test]
+fn test_search_jumps_between_matches_with_counter() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(vec![
+        reminder("g-1", "Buy milk", None, false),
+        reminder("g-2", "Bread", None, false),
+        reminder("g-3", "Milk powder", None, false),
+        reminder("g-4", "Eggs", Some("Next to the milk"), false),
+    ]);
+    harness.keys("/milk").press(KeyCode::Enter);
+    assert_eq!(harness.app.get_filtered_reminders_for_test().len(), 3);
+
+    // `n` shows the whole list again and moves between matches, wrapping around
+    harness.press(KeyCode::Char('n'));
+    assert_eq!(harness.app.get_filtered_reminders_for_test().len(), 4);
+    assert_eq!(harness.app.get_selected_index(), 2);
+    harness.press(KeyCode::Char('n'));
+    insta::assert_snapshot!("search_jump_counter", harness.render());
+    harness.press(KeyCode::Char('n'));
+    assert_eq!(harness.app.get_selected_index(), 0);
+    harness.press(KeyCode::Char('N'));
+    assert_eq!(harness.app.get_selected_index(), 3);
+    assert!(harness.render().contains("3/3"));
+
+    // Off a match, the counter only has the total
+    harness.press(KeyCode::Up).press(KeyCode::Up);
+    assert!(harness.render().contains("-/3"));
+}
+
+#[test]
+fn test_priority_quick_keys() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.take_actions();
+
+    // `3` is high (EventKit 1), shown as `!!!`; pressing it again changes nothing
+    harness.press(KeyCode::Char('3')).press(KeyCode::Char('3'));
+    harness.press(KeyCode::Down).press(KeyCode::Char('1'));
+    let actions = harness.take_actions();
+    assert!(
+        matches!(
+            actions.as_slice(),
+            [
+                TuiAction::SetPriority { reminder_id: high, priority: 1 },
+                TuiAction::SetPriority { reminder_id: low, priority: 9 },
+            ] if high == "g-1" && low == "g-2"
+        ),
+        "Unexpected actions: {actions:?}"
+    );
+    insta::assert_snapshot!("reminders_priorities", harness.render());
+
+    // A failed save puts the old priority back
+    harness.app.resolve_pending_change("g-2", false);
+    harness.press(KeyCode::Char('0'));
+    assert!(harness.take_actions().is_empty());
+}
+
+#[test]
+fn test_overdue_badges_and_relative_due_dates() {
+    let mut harness = Harness::new(lists());
+    harness
+        .app
+        .pin_clock(Local.with_ymd_and_hms(2025, 10, 15, 12, 0, 0).unwrap());
+
+    // Completed reminders and ones due later today don't count as overdue
+    let groceries = vec![
+        due("g-1", "Buy milk", "2025-10-12"),
+        due("g-2", "Bread", "2025-10-15"),
+        due("g-4", "Eggs", "2025-10-15T15:00:00"),
+        due("g-5", "Flour", "2025-10-16T09:00:00"),
+        due("g-6", "Tea", "2025-11-02"),
+        Reminder {
+            completed: true,
+            ..due("g-3", "Coffee beans", "2025-10-01")
+        },
+    ];
+    let mut all: Vec<(Reminder, String)> = groceries
+        .iter()
+        .cloned()
+        .map(|r| (r, "Groceries".to_string()))
+        .collect();
+    all.push((
+        due("w-1", "Send invoice", "2025-10-15T09:30:00"),
+        "Work".to_string(),
+    ));
+    harness.app.set_all_reminders(all);
+    insta::assert_snapshot!("lists_overdue_badges", harness.render());
+    assert_eq!(harness.color_of("⚠ 1 overdue"), Color::Red);
+
+    harness.press(KeyCode::Enter);
+    harness.app.set_reminders(groceries);
+    insta::assert_snapshot!("reminders_relative_due_dates", harness.render());
+    assert_eq!(harness.color_of("Buy milk"), Color::Red);
+    assert_eq!(harness.color_of("3d overdue"), Color::Red);
+    assert_eq!(harness.color_of("Bread"), Color::Yellow);
+    assert_eq!(harness.color_of("today 3pm"), Color::Yellow);
+    assert_eq!(harness.color_of("Flour"), Color::White);
+}
+
+#[test]
+fn test_scripted_toggle_and_delete_actions() {
+    let mut harness = Harness::new(lists());
+    open_groceries(&mut harness);
+    harness.take_actions();
+
+    harness.press(KeyCode::Char(' '));
+    harness.keys("dd");
+    let actions = harness.take_actions();
+    assert!(
+        matches!(
+            actions.as_slice(),
+            [
+                TuiAction::ToggleReminder { reminder_id: toggled },
+                TuiAction::DeleteReminder { reminder_id: deleted },
+            ] if toggled == "g-1" && deleted == "g-2"
+        ),
+        "Unexpected actions: {actions:?}"
+    );
+
+    harness.press(KeyCode::Char('q'));
+    assert!(matches!(
+        harness.take_actions().as_slice(),
+        [TuiAction::Back]
+    ));
+    assert!(matches!(harness.app.get_current_view(), AppView::Lists));
+}
+
+#[test]
+fn test_log_pane_filters_and_scrolls() {
+    let mut harness = Harness::new(lists());
+    let time = Local.with_ymd_and_hms(2025, 10, 15, 9, 30, 0).unwrap();
+    let record = |level, target: &str, message: &str| LogRecord {
+        time,
+        level,
+        target: target.to_string(),
+        message: message.to_string(),
+    };
+    let log = harness.app.log_buffer().clone();
+    log.push(record(
+        tracing::Level::DEBUG,
+        "rem_core",
+        "set_reminders elapsed_ms=2",
+    ));
+    log.push(record(
+        tracing::Level::INFO,
+        "rem_core::status",
+        "📋 Loading Groceries reminders...",
+    ));
+    log.push(record(
+        tracing::Level::WARN,
+        "rem_core::tui::app",
+        r#"Swift rejected the change, rolling back reminder_id="g-1""#,
+    ));
+
+    // The pane replaces the status box and takes the keys until it is closed
+    harness.press(KeyCode::Char('`'));
+    assert!(harness.app.is_log_pane_open());
+    harness.press(KeyCode::Down);
+    assert_eq!(harness.app.get_selected_index(), 0);
+    insta::assert_snapshot!("log_pane", harness.render());
+
+    // `l` shows one level more
+    harness.press(KeyCode::Char('l'));
+    assert!(harness.render().contains("set_reminders elapsed_ms=2"));
+
+    harness.press(KeyCode::Char('`'));
+    assert!(!harness.app.is_log_pane_open());
+    assert!(harness.render().contains(" Status "));
+}
+
+#[test]
+fn test_huge_list_renders_the_visible_rows() {
+    let mut harness = Harness::new(lists());
+    harness.press(KeyCode::Enter);
+    let reminders: Vec<Reminder> = (0..10_000)
+        .map(|i| reminder(&format!("r-{i}"), &format!("Reminder {i}"), None, false))
+        .collect();
+    harness.app.set_reminders(reminders);
+    assert!(harness.render().contains("Reminder 0"));
+
+    // Wrapping to the end scrolls the last rows into view
+    harness.press(KeyCode::Up);
+    assert_eq!(harness.app.get_selected_index(), 9_999);
+    let screen = harness.render();
+    assert!(screen.contains("▶ ☐  Reminder 9999"), "{screen}");
+    assert!(!screen.contains("Reminder 0 "));
+
+    // Clicks map to the rows as drawn
+    let (column, row) = harness.locate("Reminder 9998");
+    harness.click((column, row));
+    assert_eq!(harness.app.get_selected_index(), 9_998);
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
-use strum::Display;
+use strum::{Display, VariantNames};
 
-#[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
+use crate::source::{Change, NewReminder, Reminder, ReminderList};
+
+#[derive(Debug, Clone, PartialEq, Eq, Display, VariantNames, Serialize, Deserialize)]
 pub enum Action {
     Tick,
     Render,
@@ -19,10 +21,33 @@ pub enum Action {
     Right,
     Enter,
     Back,
+    First,
+    Last,
+    // Reminder actions
+    Refresh,
+    ToggleCompleted,
+    Create,
+    Delete,
+    Search,
+    GlobalSearch,
+    // Changes the current screen asks `App` to make in the reminder source
+    CreateReminder(NewReminder),
+    DeleteReminder(String),
+    SetCompleted(String, bool),
     // EventKit actions
     CheckPermissions,
     RequestPermissions,
     LoadLists,
     LoadReminders(String),
     SelectList(String),
+    // Results of loading in the background; errors are their messages
+    ListsLoaded(Result<Vec<ReminderList>, String>),
+    ListCounted(String, Result<usize, String>),
+    /// For the list with this id, or every list when `None`, each reminder with its
+    /// list's title.
+    RemindersLoaded(Option<String>, Result<Vec<(Reminder, String)>, String>),
+    /// A change to a reminder made in the background, or why it failed.
+    Saved(Result<Change, String>),
+    /// Another `rem serve` client changed the reminders.
+    ChangedElsewhere,
 }
@@ -1,23 +1,40 @@
+use std::sync::Arc;
+#[cfg(unix)]
+use std::{path::PathBuf, time::Duration};
+
 use color_eyre::Result;
 use crossterm::event::KeyEvent;
-use ratatui::prelude::Rect;
+use ratatui::{
+    prelude::Rect,
+    widgets::{Clear, Paragraph},
+};
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
-use tracing::{debug, info};
+use strum::VariantNames;
+use tokio::{
+    sync::{Semaphore, mpsc},
+    task::JoinSet,
+};
+use tracing::{debug, error, info};
 
+#[cfg(unix)]
+use crate::rpc::{self, Client};
 use crate::{
     action::Action,
     components::{
-        Component, fps::FpsCounter, home::Home, lists::ListsComponent,
-        permission::PermissionComponent, reminders::RemindersComponent,
+        Component, create::CreateForm, fps::FpsCounter, home::Home, lists::ListsComponent,
+        permission::PermissionComponent,
+        reminders::{RemindersComponent, fetch_reminders},
     },
-    config::Config,
-    eventkit::EventKitManager,
+    config::{Config, ConfigWatcher, get_config_dir},
+    source::{Change, ChangeKind, ReminderSource},
     tui::{Event, Tui},
 };
 
 pub struct App {
     config: Config,
+    config_watcher: ConfigWatcher,
+    /// Why the config files last failed to load; the previous config stays in use.
+    config_error: Option<String>,
     tick_rate: f64,
     frame_rate: f64,
     components: Vec<Box<dyn Component>>,
@@ -27,14 +44,29 @@ pub struct App {
     last_tick_key_events: Vec<KeyEvent>,
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
-    eventkit: Option<EventKitManager>,
+    source: Option<Arc<dyn ReminderSource>>,
     permission_component: PermissionComponent,
     lists_component: Option<ListsComponent>,
     reminders_component: Option<RemindersComponent>,
+    /// The new reminder form, open over the current screen.
+    create_form: Option<CreateForm>,
+    /// Why the last change to a reminder failed, shown until the next key.
+    error: Option<String>,
+    /// Loads for the lists screen and for the open list, running in the background.
+    /// Each set is aborted when its screen goes away or a newer load replaces it.
+    /// Aborting can't stop a source call already running on a blocking thread: it
+    /// runs to the end, but its result is dropped with the task instead of sent.
+    lists_tasks: JoinSet<()>,
+    reminders_tasks: JoinSet<()>,
+    /// The TUI's own changes, for `sync` to publish to a running `rem serve`.
+    #[cfg(unix)]
+    sync_tx: Option<mpsc::UnboundedSender<Change>>,
     last_ctrl_c_time: Option<std::time::Instant>,
 }
 
-#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Copy, Clone, PartialEq, Eq, Hash, VariantNames, Serialize, Deserialize,
+)]
 pub enum Mode {
     #[default]
     Home,
@@ -43,6 +75,76 @@ pub enum Mode {
     Reminders,
 }
 
+/// How many lists are counted at once.
+const PARALLEL_COUNTS: usize = 4;
+
+/// How long the TUI waits before looking for a `rem serve` to sync with again.
+#[cfg(unix)]
+const SYNC_RETRY: Duration = Duration::from_secs(5);
+
+/// Call the reminder source on tokio's blocking threads, since its calls can take
+/// seconds, with any error as its message.
+async fn blocking<T: Send + 'static>(
+    source: &Arc<dyn ReminderSource>,
+    call: impl FnOnce(&dyn ReminderSource) -> Result<T> + Send + 'static,
+) -> Result<T, String> {
+    let source = source.clone();
+    match tokio::task::spawn_blocking(move || call(source.as_ref())).await {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Keep in sync with the `rem serve` on `socket_path` while one is running: publish
+/// the TUI's changes from `changes`, and ask for a reload whenever another client
+/// changes something. Without a server the TUI's changes have nobody to go to, and the
+/// socket is tried again every `SYNC_RETRY`.
+#[cfg(unix)]
+async fn sync(
+    socket_path: PathBuf,
+    mut changes: mpsc::UnboundedReceiver<Change>,
+    action_tx: mpsc::UnboundedSender<Action>,
+) {
+    loop {
+        let Ok(mut client) = Client::subscribe(&socket_path).await else {
+            let retry = tokio::time::sleep(SYNC_RETRY);
+            tokio::pin!(retry);
+            loop {
+                tokio::select! {
+                    _ = &mut retry => break,
+                    change = changes.recv() => if change.is_none() {
+                        return;
+                    },
+                }
+            }
+            continue;
+        };
+        debug!("Syncing with {}", socket_path.display());
+        loop {
+            tokio::select! {
+                change = client.next_change() => match change {
+                    Ok(Some(change)) => {
+                        debug!("Changed elsewhere: {change:?}");
+                        if action_tx.send(Action::ChangedElsewhere).is_err() {
+                            return;
+                        }
+                    }
+                    // The server went away
+                    _ => break,
+                },
+                change = changes.recv() => match change {
+                    Some(change) => {
+                        if client.publish(&change).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => return,
+                },
+            }
+        }
+    }
+}
+
 impl App {
     pub fn new(tick_rate: f64, frame_rate: f64) -> Result<Self> {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
@@ -53,14 +155,22 @@ impl App {
             should_quit: false,
             should_suspend: false,
             config: Config::new()?,
+            config_watcher: ConfigWatcher::new(get_config_dir()),
+            config_error: None,
             mode: Mode::Permission,
             last_tick_key_events: Vec::new(),
             action_tx: action_tx.clone(),
             action_rx,
-            eventkit: None,
+            source: None,
             permission_component: PermissionComponent::new(),
             lists_component: None,
             reminders_component: None,
+            create_form: None,
+            error: None,
+            lists_tasks: JoinSet::new(),
+            reminders_tasks: JoinSet::new(),
+            #[cfg(unix)]
+            sync_tx: None,
             last_ctrl_c_time: None,
         };
 
@@ -131,6 +241,14 @@ impl App {
             }
         }
 
+        // The form takes every key while it's open
+        if let Some(create_form) = &mut self.create_form {
+            if let Some(action) = create_form.handle_events(Some(event.clone()))? {
+                action_tx.send(action)?;
+            }
+            return Ok(());
+        }
+
         // Handle events for current mode-specific components
         match self.mode {
             Mode::Permission => {
@@ -175,13 +293,21 @@ impl App {
             }
             self.last_ctrl_c_time = Some(now);
         }
-        
+        self.error = None;
+
+        // Text input goes to the component, see `handle_events`
+        if self.is_editing() {
+            self.last_tick_key_events.clear();
+            return Ok(());
+        }
+
         let Some(keymap) = self.config.keybindings.get(&self.mode) else {
             return Ok(());
         };
         match keymap.get(&vec![key]) {
             Some(action) => {
                 info!("Got action: {action:?}");
+                self.last_tick_key_events.clear();
                 action_tx.send(action.clone())?;
             }
             _ => {
@@ -192,6 +318,7 @@ impl App {
                 // Check for multi-key combinations
                 if let Some(action) = keymap.get(&self.last_tick_key_events) {
                     info!("Got action: {action:?}");
+                    self.last_tick_key_events.clear();
                     action_tx.send(action.clone())?;
                 }
             }
@@ -205,91 +332,406 @@ impl App {
                 debug!("{action:?}");
             }
             match action {
-                Action::Tick => {
-                    self.last_tick_key_events.drain(..);
-                }
-                Action::Quit => self.should_quit = true,
-                Action::Suspend => self.should_suspend = true,
-                Action::Resume => self.should_suspend = false,
                 Action::ClearScreen => tui.terminal.clear()?,
                 Action::Resize(w, h) => self.handle_resize(tui, w, h)?,
                 Action::Render => self.render(tui)?,
-                Action::CheckPermissions => {
-                    if let Some(action) = self.permission_component.update(action.clone())? {
-                        self.action_tx.send(action)?;
-                    }
+                _ => self.update(action.clone())?,
+            }
+            for component in self.components.iter_mut() {
+                if let Some(action) = component.update(action.clone())? {
+                    self.action_tx.send(action)?
+                };
+            }
+        }
+        Ok(())
+    }
+
+    /// Act on an action that doesn't need the terminal.
+    fn update(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Tick => {
+                self.last_tick_key_events.drain(..);
+                // Reap finished loads
+                while self.lists_tasks.try_join_next().is_some() {}
+                while self.reminders_tasks.try_join_next().is_some() {}
+                if self.config_watcher.changed() {
+                    self.reload_config()?;
                 }
-                Action::RequestPermissions => {
-                    if let Some(action) = self.permission_component.update(action.clone())? {
-                        self.action_tx.send(action)?;
-                    }
+            }
+            Action::Quit => self.should_quit = true,
+            Action::Suspend => self.should_suspend = true,
+            Action::Resume => self.should_suspend = false,
+            Action::CheckPermissions => {
+                if let Some(action) = self.permission_component.update(action.clone())? {
+                    self.action_tx.send(action)?;
                 }
-                Action::LoadLists => {
-                    if let Some(eventkit) = self.permission_component.get_eventkit() {
-                        self.eventkit = Some(EventKitManager::new()?);
-                        let mut lists_component = ListsComponent::new();
-                        lists_component.register_action_handler(self.action_tx.clone())?;
-                        lists_component.register_config_handler(self.config.clone())?;
-
-                        // Load lists directly into the component
-                        if let Err(e) = lists_component.load_lists(eventkit) {
-                            eprintln!("Failed to load lists: {}", e);
-                        }
+            }
+            Action::RequestPermissions => {
+                if let Some(action) = self.permission_component.update(action.clone())? {
+                    self.action_tx.send(action)?;
+                }
+            }
+            Action::LoadLists => {
+                if let Some(source) = self.permission_component.get_source() {
+                    let mut lists_component = ListsComponent::new();
+                    lists_component.register_action_handler(self.action_tx.clone())?;
+                    lists_component.register_config_handler(self.config.clone())?;
+                    lists_component.start_loading();
 
-                        self.lists_component = Some(lists_component);
-                        self.mode = Mode::Lists;
-                    }
+                    self.source = Some(source);
+
+                    self.lists_component = Some(lists_component);
+                    self.mode = Mode::Lists;
+                    self.load_lists();
+                    self.start_sync();
                 }
-                Action::SelectList(ref list_id) => {
-                    if let Some(_lists_component) = &self.lists_component {
-                        // Find the selected list to get its title
-                        let list_title = format!("List {list_id}"); // Simplified
-
-                        let mut reminders_component =
-                            RemindersComponent::new(list_id.clone(), list_title);
-                        reminders_component.register_action_handler(self.action_tx.clone())?;
-                        reminders_component.register_config_handler(self.config.clone())?;
-
-                        self.reminders_component = Some(reminders_component);
-                        self.mode = Mode::Reminders;
-
-                        // Load reminders
-                        self.action_tx
-                            .send(Action::LoadReminders(list_id.clone()))?;
-                    }
+            }
+            Action::ListsLoaded(ref lists) => {
+                if let Some(lists_component) = &mut self.lists_component {
+                    lists_component.set_lists(lists.clone());
                 }
-                Action::LoadReminders(ref list_id) => {
-                    if let Some(reminders_component) = &mut self.reminders_component {
-                        if let Some(eventkit) = &self.eventkit {
-                            // Load reminders directly into the component
-                            if let Err(e) = reminders_component.load_reminders(eventkit) {
-                                eprintln!("Failed to load reminders: {}", e);
-                            }
-                        }
-                    }
+            }
+            Action::ListCounted(ref list_id, ref count) => {
+                if let Some(lists_component) = &mut self.lists_component {
+                    lists_component.set_count(list_id, count.clone());
                 }
-                Action::Back => match self.mode {
-                    Mode::Reminders => {
+            }
+            Action::SelectList(ref list_id) => {
+                if let Some(lists_component) = &self.lists_component {
+                    let list_title = lists_component
+                        .lists()
+                        .iter()
+                        .find(|list| &list.id == list_id)
+                        .map_or_else(|| format!("List {list_id}"), |list| list.title.clone());
+
+                    let mut reminders_component =
+                        RemindersComponent::new(list_id.clone(), list_title);
+                    reminders_component.register_action_handler(self.action_tx.clone())?;
+                    reminders_component.register_config_handler(self.config.clone())?;
+
+                    self.reminders_component = Some(reminders_component);
+                    self.mode = Mode::Reminders;
+
+                    // Load reminders
+                    self.action_tx
+                        .send(Action::LoadReminders(list_id.clone()))?;
+                }
+            }
+            Action::LoadReminders(_) => self.load_reminders(true),
+            Action::ChangedElsewhere => self.reload(),
+            Action::RemindersLoaded(ref list_id, ref reminders) => {
+                // A load that finished as its list was left may still be queued
+                if let Some(reminders_component) = &mut self.reminders_component
+                    && reminders_component.list_id() == list_id.as_deref()
+                {
+                    reminders_component.set_reminders(reminders.clone());
+                }
+            }
+            // Key-bound actions the current screen acts on
+            Action::Up
+            | Action::Down
+            | Action::First
+            | Action::Last
+            | Action::Enter
+            | Action::Refresh
+            | Action::ToggleCompleted
+            | Action::Delete
+            | Action::Search => {
+                if let Some(action) = self.update_current_component(action.clone())? {
+                    self.action_tx.send(action)?;
+                }
+            }
+            Action::Create => self.open_create_form()?,
+            Action::GlobalSearch => self.search_all_lists()?,
+            Action::CreateReminder(ref new_reminder) => {
+                self.create_form = None;
+                let list = self
+                    .lists_component
+                    .as_ref()
+                    .and_then(|lists| lists.lists().iter().find(|l| l.id == new_reminder.list_id));
+                let list = list.map(|list| list.title.clone()).unwrap_or_default();
+                let failed = format!("Couldn't create \"{}\"", new_reminder.title);
+                let new_reminder = new_reminder.clone();
+                self.save(ChangeKind::Created, list, failed, move |source| {
+                    source.create_reminder(&new_reminder).map(|r| Some(r.id))
+                });
+            }
+            Action::DeleteReminder(ref reminder_id) => {
+                let list = self.list_title_of(reminder_id);
+                let id = reminder_id.clone();
+                let failed = "Couldn't delete the reminder".to_string();
+                self.save(ChangeKind::Deleted, list, failed, move |source| {
+                    Ok(source.delete_reminder(&id)?.then_some(id))
+                });
+            }
+            Action::SetCompleted(ref reminder_id, completed) => {
+                let list = self.list_title_of(reminder_id);
+                let id = reminder_id.clone();
+                let failed = "Couldn't update the reminder".to_string();
+                self.save(ChangeKind::Updated, list, failed, move |source| {
+                    Ok(source.set_reminder_completed(&id, completed)?.then_some(id))
+                });
+            }
+            Action::Saved(ref saved) => {
+                match saved {
+                    Ok(change) => self.publish(change.clone()),
+                    Err(message) => self.error = Some(message.clone()),
+                }
+                // The list counts change either way, and a failure undoes the toggle
+                self.reload();
+            }
+            Action::Error(ref message) => self.error = Some(message.clone()),
+            Action::Back if self.create_form.is_some() => self.create_form = None,
+            Action::Back => match self.mode {
+                Mode::Reminders => {
+                    // The first Back leaves a list's search, the next leaves the list
+                    let searching = self.reminders_component.as_mut().is_some_and(|reminders| {
+                        reminders.list_id().is_some() && reminders.clear_search()
+                    });
+                    if !searching {
+                        self.reminders_tasks.abort_all();
                         self.reminders_component = None;
                         self.mode = Mode::Lists;
                     }
-                    Mode::Lists => {
-                        self.lists_component = None;
-                        self.mode = Mode::Permission;
-                    }
-                    _ => {}
-                },
+                }
+                Mode::Lists => {
+                    self.lists_tasks.abort_all();
+                    self.reminders_tasks.abort_all();
+                    self.lists_component = None;
+                    self.mode = Mode::Permission;
+                }
                 _ => {}
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rebuild the config after its files change and hand it to every component. A
+    /// config that doesn't load is reported on screen and the last good one kept.
+    fn reload_config(&mut self) -> Result<()> {
+        let config = match Config::new() {
+            Ok(config) => config,
+            Err(e) => {
+                error!("Config not reloaded: {e}");
+                self.config_error = Some(format!("Config not reloaded: {e}"));
+                return Ok(());
             }
-            for component in self.components.iter_mut() {
-                if let Some(action) = component.update(action.clone())? {
-                    self.action_tx.send(action)?
-                };
-            }
+        };
+        info!("Config reloaded");
+        self.config = config;
+        self.config_error = None;
+
+        for component in self.components.iter_mut() {
+            component.register_config_handler(self.config.clone())?;
+        }
+        self.permission_component
+            .register_config_handler(self.config.clone())?;
+        if let Some(lists_component) = &mut self.lists_component {
+            lists_component.register_config_handler(self.config.clone())?;
+        }
+        if let Some(reminders_component) = &mut self.reminders_component {
+            reminders_component.register_config_handler(self.config.clone())?;
+        }
+        if let Some(create_form) = &mut self.create_form {
+            create_form.register_config_handler(self.config.clone())?;
         }
         Ok(())
     }
 
+    /// Whether keys go straight to a form, search box or prompt instead of the keymap.
+    fn is_editing(&self) -> bool {
+        if self.create_form.is_some() {
+            return true;
+        }
+        match self.mode {
+            Mode::Lists => self.lists_component.as_ref().is_some_and(|c| c.is_editing()),
+            Mode::Reminders => self.reminders_component.as_ref().is_some_and(|c| c.is_editing()),
+            _ => false,
+        }
+    }
+
+    /// Open the new reminder form over the lists or a list, filing into the list
+    /// under the cursor or the one open.
+    fn open_create_form(&mut self) -> Result<()> {
+        let Some(lists_component) = &self.lists_component else {
+            return Ok(());
+        };
+        let list_id = match self.mode {
+            Mode::Lists => lists_component.selected_list_id(),
+            Mode::Reminders => self.reminders_component.as_ref().and_then(|c| c.list_id()),
+            _ => return Ok(()),
+        };
+        let mut create_form = CreateForm::new(self.mode, lists_component.lists().to_vec(), list_id);
+        create_form.register_config_handler(self.config.clone())?;
+        self.create_form = Some(create_form);
+        Ok(())
+    }
+
+    /// Show every list's reminders with the search open, or reload them if they're
+    /// already showing.
+    fn search_all_lists(&mut self) -> Result<()> {
+        if self.source.is_none() {
+            return Ok(());
+        }
+        let showing_all = self
+            .reminders_component
+            .as_ref()
+            .is_some_and(|c| c.list_id().is_none());
+        if !showing_all {
+            let mut reminders_component = RemindersComponent::all_lists();
+            reminders_component.register_action_handler(self.action_tx.clone())?;
+            reminders_component.register_config_handler(self.config.clone())?;
+            self.reminders_component = Some(reminders_component);
+            self.mode = Mode::Reminders;
+        }
+        self.load_reminders(true);
+        Ok(())
+    }
+
+    /// Load the lists in the background, then count their reminders a few lists at a
+    /// time, so the lists show straight away and each count as it arrives.
+    fn load_lists(&mut self) {
+        let Some(source) = self.source.clone() else {
+            return;
+        };
+        let action_tx = self.action_tx.clone();
+        self.lists_tasks.abort_all();
+        self.lists_tasks.spawn(async move {
+            let lists =
+                blocking(&source, |source| source.get_reminder_lists_without_counts()).await;
+            let list_ids: Vec<String> =
+                lists.iter().flatten().map(|list| list.id.clone()).collect();
+            let _ = action_tx.send(Action::ListsLoaded(lists));
+
+            // Dropping the set, when this task is aborted, aborts the counts too
+            let permits = Arc::new(Semaphore::new(PARALLEL_COUNTS));
+            let mut counts = JoinSet::new();
+            for list_id in list_ids {
+                let (source, action_tx, permits) =
+                    (source.clone(), action_tx.clone(), permits.clone());
+                counts.spawn(async move {
+                    let Ok(_permit) = permits.acquire().await else {
+                        return;
+                    };
+                    let id = list_id.clone();
+                    let count =
+                        blocking(&source, move |source| source.get_reminder_count_for_list(&id))
+                            .await;
+                    let _ = action_tx.send(Action::ListCounted(list_id, count));
+                });
+            }
+            while counts.join_next().await.is_some() {}
+        });
+    }
+
+    /// Load the open list's reminders in the background, replacing any load already
+    /// running. `show_loading` swaps the list for the loading screen meanwhile.
+    fn load_reminders(&mut self, show_loading: bool) {
+        let (Some(source), Some(reminders_component)) =
+            (self.source.clone(), &mut self.reminders_component)
+        else {
+            return;
+        };
+        if show_loading {
+            reminders_component.start_loading();
+        }
+        let list_id = reminders_component.list_id().map(str::to_string);
+        let list_title = reminders_component.list_title().to_string();
+        let action_tx = self.action_tx.clone();
+        self.reminders_tasks.abort_all();
+        self.reminders_tasks.spawn(async move {
+            let id = list_id.clone();
+            let reminders = blocking(&source, move |source| {
+                fetch_reminders(source, id.as_deref(), &list_title)
+            })
+            .await;
+            let _ = action_tx.send(Action::RemindersLoaded(list_id, reminders));
+        });
+    }
+
+    /// Start keeping in sync with `rem serve`, unless already started.
+    #[cfg(unix)]
+    fn start_sync(&mut self) {
+        if self.sync_tx.is_none() {
+            let (sync_tx, changes) = mpsc::unbounded_channel();
+            let socket_path = rpc::default_socket_path();
+            tokio::spawn(sync(socket_path, changes, self.action_tx.clone()));
+            self.sync_tx = Some(sync_tx);
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn start_sync(&mut self) {}
+
+    /// Make a change in the reminder source on the blocking threads, and report it
+    /// back as `Saved`. `call` returns the id of the reminder it changed, or `None`
+    /// when there was no such reminder; `failed` starts the message for an error.
+    /// Unlike loads, changes aren't aborted when their screen goes away.
+    fn save(
+        &self,
+        kind: ChangeKind,
+        list: String,
+        failed: String,
+        call: impl FnOnce(&dyn ReminderSource) -> Result<Option<String>> + Send + 'static,
+    ) {
+        let Some(source) = self.source.clone() else {
+            return;
+        };
+        let action_tx = self.action_tx.clone();
+        tokio::spawn(async move {
+            let saved = match blocking(&source, call).await {
+                Ok(Some(id)) => Ok(Change { kind, id, list }),
+                Ok(None) => Err("That reminder no longer exists".to_string()),
+                Err(e) => Err(format!("{failed}: {e}")),
+            };
+            let _ = action_tx.send(Action::Saved(saved));
+        });
+    }
+
+    /// The title of the list a reminder on the screen is in.
+    fn list_title_of(&self, reminder_id: &str) -> String {
+        self.reminders_component
+            .as_ref()
+            .map(|reminders| reminders.list_title_of(reminder_id).to_string())
+            .unwrap_or_default()
+    }
+
+    /// Tell other `rem serve` clients about a change the TUI made.
+    #[cfg(unix)]
+    fn publish(&self, change: Change) {
+        if let Some(sync_tx) = &self.sync_tx {
+            let _ = sync_tx.send(change);
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn publish(&self, _change: Change) {}
+
+    /// Reload the lists and the open list after a change, for their counts and so a
+    /// change that failed shows as it really is.
+    fn reload(&mut self) {
+        if self.lists_component.is_some() {
+            self.load_lists();
+        }
+        self.load_reminders(false);
+    }
+
+    fn update_current_component(&mut self, action: Action) -> Result<Option<Action>> {
+        match self.mode {
+            Mode::Permission => self.permission_component.update(action),
+            Mode::Lists => match &mut self.lists_component {
+                Some(lists_component) => lists_component.update(action),
+                None => Ok(None),
+            },
+            Mode::Reminders => match &mut self.reminders_component {
+                Some(reminders_component) => reminders_component.update(action),
+                None => Ok(None),
+            },
+            Mode::Home => Ok(None),
+        }
+    }
+
     fn handle_resize(&mut self, tui: &mut Tui, w: u16, h: u16) -> Result<()> {
         tui.resize(Rect::new(0, 0, w, h))?;
         self.render(tui)?;
@@ -335,7 +777,249 @@ impl App {
                     }
                 }
             }
+
+            let form = self.create_form.as_mut();
+            if let Some(Err(err)) = form.map(|form| form.draw(frame, frame.area())) {
+                let _ = self
+                    .action_tx
+                    .send(Action::Error(format!("Failed to draw the form: {err:?}")));
+            }
+
+            // A change that failed, or a broken config until it's fixed
+            if let Some(config_error) = self.error.as_ref().or(self.config_error.as_ref()) {
+                let area = frame.area();
+                let banner = Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+                let style = self.config.styles.style(self.mode, "error");
+                frame.render_widget(Clear, banner);
+                frame.render_widget(Paragraph::new(config_error.as_str()).style(style), banner);
+            }
         })?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc as std_mpsc,
+    };
+
+    use futures::future::BoxFuture;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::source::{
+        NewReminder, PermissionStatus, Reminder, ReminderList, ReminderUpdate, local::LocalSource,
+    };
+
+    /// A local source whose reminders of "list-slow" wait for the test to let each
+    /// call through, like a slow EventKit or AppleScript fetch.
+    struct SlowSource {
+        local: LocalSource,
+        gate: Mutex<std_mpsc::Receiver<()>>,
+        /// How many slow calls have started and returned.
+        started: AtomicUsize,
+        finished: AtomicUsize,
+    }
+
+    impl SlowSource {
+        /// "Fast" with two reminders and "Slow" with one.
+        fn new(name: &str) -> (Arc<Self>, std_mpsc::Sender<()>) {
+            let dir = std::env::temp_dir().join(format!("rem-app-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("reminders.json");
+            let lists = r##"{"next_id": 1, "lists": [
+                {"id": "list-fast", "title": "Fast", "color": "#007AFF", "reminders": []},
+                {"id": "list-slow", "title": "Slow", "color": "#007AFF", "reminders": []}
+            ]}"##;
+            std::fs::write(&path, lists).unwrap();
+
+            let local = LocalSource::new(path);
+            for (list_id, title) in [
+                ("list-fast", "Buy milk"),
+                ("list-fast", "Call mom"),
+                ("list-slow", "Report"),
+            ] {
+                local
+                    .create_reminder(&NewReminder {
+                        title: title.to_string(),
+                        notes: None,
+                        due_date: None,
+                        list_id: list_id.to_string(),
+                        priority: 0,
+                    })
+                    .unwrap();
+            }
+            let (release, gate) = std_mpsc::channel();
+            let source = Self {
+                local,
+                gate: Mutex::new(gate),
+                started: AtomicUsize::new(0),
+                finished: AtomicUsize::new(0),
+            };
+            (Arc::new(source), release)
+        }
+    }
+
+    impl ReminderSource for SlowSource {
+        fn check_permission_status(&self) -> PermissionStatus {
+            PermissionStatus::Authorized
+        }
+
+        fn request_permission(&self) -> BoxFuture<'_, Result<bool>> {
+            self.local.request_permission()
+        }
+
+        fn get_reminder_lists(&self) -> Result<Vec<ReminderList>> {
+            self.local.get_reminder_lists()
+        }
+
+        fn get_reminders_for_list(&self, list_id: &str) -> Result<Vec<Reminder>> {
+            if list_id != "list-slow" {
+                return self.local.get_reminders_for_list(list_id);
+            }
+            self.started.fetch_add(1, Ordering::SeqCst);
+            self.gate.lock().unwrap().recv().unwrap();
+            let reminders = self.local.get_reminders_for_list(list_id);
+            self.finished.fetch_add(1, Ordering::SeqCst);
+            reminders
+        }
+
+        fn default_list_id(&self) -> Option<String> {
+            self.local.default_list_id()
+        }
+
+        fn create_reminder(&self, new_reminder: &NewReminder) -> Result<Reminder> {
+            self.local.create_reminder(new_reminder)
+        }
+
+        fn set_reminder_completed(&self, reminder_id: &str, completed: bool) -> Result<bool> {
+            self.local.set_reminder_completed(reminder_id, completed)
+        }
+
+        fn delete_reminder(&self, reminder_id: &str) -> Result<bool> {
+            self.local.delete_reminder(reminder_id)
+        }
+
+        fn update_reminder(
+            &self,
+            reminder_id: &str,
+            update: &ReminderUpdate,
+        ) -> Result<Option<Reminder>> {
+            self.local.update_reminder(reminder_id, update)
+        }
+    }
+
+    /// An app on the lists screen of `source`, past the permission check.
+    fn app_with(source: Arc<dyn ReminderSource>) -> App {
+        let mut app = App::new(4.0, 60.0).unwrap();
+        while app.action_rx.try_recv().is_ok() {}
+        app.source = Some(source);
+        app.lists_component = Some(ListsComponent::new());
+        app.mode = Mode::Lists;
+        app
+    }
+
+    /// Wait for a slow call to get to `counter`.
+    async fn reached(counter: &AtomicUsize) {
+        let wait = async {
+            while counter.load(Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), wait)
+            .await
+            .expect("the slow call didn't get there within 5s");
+    }
+
+    async fn next_action(app: &mut App) -> Action {
+        tokio::time::timeout(Duration::from_secs(5), app.action_rx.recv())
+            .await
+            .expect("no action within 5s")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_reload_sends_each_count_as_it_arrives() {
+        let (source, release) = SlowSource::new("counts");
+        let mut app = app_with(source);
+        app.update(Action::ChangedElsewhere).unwrap();
+
+        let Action::ListsLoaded(Ok(lists)) = next_action(&mut app).await else {
+            panic!("expected the lists first");
+        };
+        let titles: Vec<_> = lists.iter().map(|list| list.title.as_str()).collect();
+        assert_eq!(titles, ["Fast", "Slow"]);
+
+        // The fast list's count doesn't wait for the slow one's
+        assert_eq!(
+            next_action(&mut app).await,
+            Action::ListCounted("list-fast".to_string(), Ok(2))
+        );
+        release.send(()).unwrap();
+        assert_eq!(
+            next_action(&mut app).await,
+            Action::ListCounted("list-slow".to_string(), Ok(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changes_are_saved_in_the_background() {
+        let (source, _release) = SlowSource::new("save");
+        let id = source.get_reminders_for_list("list-fast").unwrap()[0]
+            .id
+            .clone();
+        let mut app = app_with(source.clone());
+        // Nothing on screen to reload
+        app.lists_component = None;
+
+        app.update(Action::SetCompleted(id.clone(), true)).unwrap();
+        let change = Change {
+            kind: ChangeKind::Updated,
+            id: id.clone(),
+            list: String::new(),
+        };
+        assert_eq!(next_action(&mut app).await, Action::Saved(Ok(change)));
+        assert!(source.get_reminders_for_list("list-fast").unwrap()[0].completed);
+
+        app.update(Action::DeleteReminder("nope".to_string()))
+            .unwrap();
+        let saved = next_action(&mut app).await;
+        app.update(saved).unwrap();
+        assert_eq!(app.error.as_deref(), Some("That reminder no longer exists"));
+    }
+
+    #[tokio::test]
+    async fn test_load_for_a_left_list_is_dropped() {
+        let (source, release) = SlowSource::new("left");
+        let mut app = app_with(source.clone());
+        app.update(Action::SelectList("list-slow".to_string()))
+            .unwrap();
+        let load = next_action(&mut app).await;
+        assert_eq!(load, Action::LoadReminders("list-slow".to_string()));
+        app.update(load).unwrap();
+
+        // Leaving aborts the load, but not the source call already running: it
+        // finishes, and its reminders go nowhere
+        reached(&source.started).await;
+        app.update(Action::Back).unwrap();
+        release.send(()).unwrap();
+        reached(&source.finished).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(app.action_rx.try_recv().is_err());
+
+        // The next list opened loads as usual
+        app.update(Action::SelectList("list-fast".to_string()))
+            .unwrap();
+        let load = next_action(&mut app).await;
+        app.update(load).unwrap();
+        let Action::RemindersLoaded(list_id, Ok(reminders)) = next_action(&mut app).await else {
+            panic!("expected the reminders");
+        };
+        assert_eq!(list_id.as_deref(), Some("list-fast"));
+        assert_eq!(reminders.len(), 2);
+    }
+}
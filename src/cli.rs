@@ -1,6 +1,13 @@
-use clap::Parser;
+use std::path::PathBuf;
 
-use crate::config::{get_config_dir, get_data_dir};
+use chrono::Duration;
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    config::{get_config_dir, get_data_dir},
+    notify::{self, NotifierKind},
+    output::{Field, OutputFormat},
+};
 
 #[derive(Parser, Debug)]
 #[command(author, version = version(), about)]
@@ -12,6 +19,144 @@ pub struct Cli {
     /// Frame rate, i.e. number of frames per second
     #[arg(short, long, value_name = "FLOAT", default_value_t = 60.0)]
     pub frame_rate: f64,
+
+    /// Run a single command instead of launching the TUI
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Create a reminder
+    Add {
+        /// Title of the reminder
+        title: String,
+
+        /// List name or id (defaults to the Reminders default list)
+        #[arg(short, long)]
+        list: Option<String>,
+
+        /// Due date: today, tomorrow, a weekday, "in 3d", 2025-01-31 or "2025-01-31 09:00"
+        #[arg(short, long)]
+        due: Option<String>,
+
+        /// Priority, 0 (none) to 9
+        #[arg(short, long, default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=9))]
+        priority: u8,
+
+        /// Notes for the reminder
+        #[arg(short, long)]
+        notes: Option<String>,
+    },
+
+    /// Show all lists, or the reminders in one list
+    #[command(visible_alias = "ls")]
+    List {
+        /// List name or id
+        list: Option<String>,
+
+        /// Include completed reminders
+        #[arg(short, long)]
+        all: bool,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Mark a reminder as completed
+    Done {
+        /// Reminder id, as printed by `rem ls <list>`
+        id: String,
+
+        /// Mark the reminder as not completed instead
+        #[arg(long)]
+        undo: bool,
+    },
+
+    /// Delete a reminder
+    #[command(visible_alias = "rm")]
+    Remove {
+        /// Reminder id, as printed by `rem ls <list>`
+        id: String,
+    },
+
+    /// Search reminder titles and notes across all lists
+    Search {
+        query: String,
+
+        /// Include completed reminders
+        #[arg(short, long)]
+        all: bool,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Watch due dates and send notifications until interrupted
+    Watch {
+        /// Also notify this long before the due time; repeatable, e.g. --lead 15m --lead 1d
+        #[arg(long, value_name = "DURATION", value_parser = notify::parse_duration)]
+        lead: Vec<Duration>,
+
+        /// How to deliver notifications
+        #[arg(long, value_enum, default_value_t)]
+        notifier: NotifierKind,
+
+        /// Shell command run by the command notifier
+        #[arg(long, required_if_eq("notifier", "command"))]
+        command: Option<String>,
+
+        /// Seconds between checks
+        #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+        interval: u64,
+    },
+
+    /// Postpone notifications for a reminder
+    Snooze {
+        /// Reminder id, as printed by `rem ls <list>`
+        id: String,
+
+        /// How long to snooze, e.g. 10m, 2h or 1d
+        #[arg(long = "for", value_name = "DURATION", default_value = "10m", value_parser = notify::parse_duration)]
+        duration: Duration,
+    },
+
+    /// Inspect the TUI configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
+    /// Serve JSON-RPC 2.0 on a Unix socket for editors and scripts
+    #[cfg(unix)]
+    Serve {
+        /// Socket path (defaults to rem.sock in the data directory)
+        #[arg(long, value_name = "PATH")]
+        socket: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum ConfigCommand {
+    /// Print the effective config (the defaults merged with your config file) as JSON
+    Dump,
+
+    /// Check the config files for mistakes; exits with status 5 if there are errors
+    Check,
+
+    /// Print a JSON Schema for config files, for editor completion
+    Schema,
+}
+
+#[derive(Args, Debug, Clone, Default, PartialEq)]
+pub struct OutputArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Comma-separated fields to print, e.g. title,due,list
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub fields: Vec<Field>,
 }
 
 const VERSION_MESSAGE: &str = concat!(
@@ -0,0 +1,502 @@
+use std::{fmt, sync::Arc};
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::{
+    cli::{Command, ConfigCommand, OutputArgs},
+    config::{Config, check, get_config_dir},
+    notify,
+    output::{self, Record, RecordKind},
+    source::{NewReminder, PermissionStatus, Reminder, ReminderList, ReminderSource},
+};
+#[cfg(unix)]
+use crate::rpc;
+
+/// Exit statuses for the non-interactive commands.
+pub mod exit_code {
+    pub const SUCCESS: i32 = 0;
+    /// Reminders returned an error or could not be reached.
+    pub const FAILURE: i32 = 1;
+    /// Invalid arguments (clap uses the same code for parse errors).
+    pub const USAGE: i32 = 2;
+    /// The list or reminder doesn't exist, or a search matched nothing.
+    pub const NOT_FOUND: i32 = 3;
+    /// Access to Reminders was denied.
+    pub const PERMISSION_DENIED: i32 = 4;
+    /// `rem config check` found errors in the config files.
+    pub const INVALID_CONFIG: i32 = 5;
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    Usage(String),
+    NotFound(String),
+    PermissionDenied,
+    /// The number of errors `rem config check` found.
+    InvalidConfig(usize),
+    Failed(color_eyre::Report),
+}
+
+impl CommandError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandError::Usage(_) => exit_code::USAGE,
+            CommandError::NotFound(_) => exit_code::NOT_FOUND,
+            CommandError::PermissionDenied => exit_code::PERMISSION_DENIED,
+            CommandError::InvalidConfig(_) => exit_code::INVALID_CONFIG,
+            CommandError::Failed(_) => exit_code::FAILURE,
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Usage(message) | CommandError::NotFound(message) => {
+                write!(f, "{message}")
+            }
+            CommandError::PermissionDenied => write!(
+                f,
+                "Reminders access is denied. Enable it in System Settings > Privacy & Security > Reminders."
+            ),
+            CommandError::InvalidConfig(1) => write!(f, "1 error in the config"),
+            CommandError::InvalidConfig(count) => write!(f, "{count} errors in the config"),
+            CommandError::Failed(report) => write!(f, "{report}"),
+        }
+    }
+}
+
+impl From<color_eyre::Report> for CommandError {
+    fn from(report: color_eyre::Report) -> Self {
+        CommandError::Failed(report)
+    }
+}
+
+/// Run a single command without the TUI and return the process exit status.
+pub async fn run(command: Command) -> i32 {
+    match execute(command).await {
+        Ok(()) => exit_code::SUCCESS,
+        Err(error) => {
+            eprintln!("rem: {error}");
+            error.exit_code()
+        }
+    }
+}
+
+async fn execute(command: Command) -> Result<(), CommandError> {
+    // Config commands don't need access to Reminders
+    if let Command::Config { command } = command {
+        return config(command);
+    }
+
+    let source = connect().await?;
+
+    match command {
+        Command::Add {
+            title,
+            list,
+            due,
+            priority,
+            notes,
+        } => {
+            let list_id = match list {
+                Some(list) => find_list(source.as_ref(), &list)?.id,
+                None => source.default_list_id().ok_or_else(|| {
+                    CommandError::Usage("No default list; pass one with --list".to_string())
+                })?,
+            };
+            let due_date = due
+                .map(|due| parse_due_date(&due, Local::now().naive_local()))
+                .transpose()?;
+
+            let reminder = source.create_reminder(&NewReminder {
+                title,
+                notes,
+                due_date,
+                list_id,
+                priority,
+            })?;
+            println!("{}", reminder.id);
+        }
+        Command::List {
+            list: None, output, ..
+        } => {
+            let records: Vec<_> = source
+                .get_reminder_lists()?
+                .iter()
+                .map(Record::from_list)
+                .collect();
+            print_records(RecordKind::List, &output, &records)?;
+        }
+        Command::List {
+            list: Some(list),
+            all,
+            output,
+        } => {
+            let list = find_list(source.as_ref(), &list)?;
+            let records: Vec<_> = source
+                .get_reminders_for_list(&list.id)?
+                .iter()
+                .filter(|r| all || !r.completed)
+                .map(|r| Record::from_reminder(r, &list.title))
+                .collect();
+            print_records(RecordKind::Reminder, &output, &records)?;
+        }
+        Command::Done { id, undo } => {
+            if !source.set_reminder_completed(&id, !undo)? {
+                return Err(CommandError::NotFound(format!(
+                    "No reminder with id '{id}'"
+                )));
+            }
+        }
+        Command::Remove { id } => {
+            if !source.delete_reminder(&id)? {
+                return Err(CommandError::NotFound(format!(
+                    "No reminder with id '{id}'"
+                )));
+            }
+        }
+        Command::Search { query, all, output } => {
+            let matches = search(source.as_ref(), &query, all)?;
+            if matches.is_empty() {
+                return Err(CommandError::NotFound(format!(
+                    "No reminders match '{query}'"
+                )));
+            }
+            let records: Vec<_> = matches
+                .iter()
+                .map(|(reminder, list)| Record::from_reminder(reminder, &list.title))
+                .collect();
+            print_records(RecordKind::Reminder, &output, &records)?;
+        }
+        Command::Watch {
+            lead,
+            notifier,
+            command,
+            interval,
+        } => {
+            let notifier = notify::notifier(notifier, command)?;
+            let interval = std::time::Duration::from_secs(interval.max(1));
+            notify::watch(source, notifier, &lead, interval).await?;
+        }
+        Command::Snooze { id, duration } => {
+            if source.find_reminder(&id)?.is_none() {
+                return Err(CommandError::NotFound(format!(
+                    "No reminder with id '{id}'"
+                )));
+            }
+            let until = Local::now().naive_local() + duration;
+            let path = notify::state_path();
+            let mut state = notify::WatchState::load(&path);
+            state.snooze(&id, until);
+            state.save(&path)?;
+            println!("Snoozed until {}", until.format("%Y-%m-%d %H:%M"));
+        }
+        Command::Config { .. } => unreachable!("config commands return before connecting"),
+        #[cfg(unix)]
+        Command::Serve { socket } => {
+            rpc::serve(source, socket.unwrap_or_else(rpc::default_socket_path)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn config(command: ConfigCommand) -> Result<(), CommandError> {
+    match command {
+        ConfigCommand::Dump => {
+            let config = Config::new().map_err(color_eyre::Report::from)?;
+            let dump = serde_json::to_string_pretty(&config).map_err(color_eyre::Report::from)?;
+            println!("{dump}");
+        }
+        ConfigCommand::Check => {
+            let dir = get_config_dir();
+            let files = check::present_files(&dir);
+            if files.is_empty() {
+                println!("No config file in {}; using the defaults", dir.display());
+                return Ok(());
+            }
+            let diagnostics = check::check_dir(&dir);
+            for diagnostic in &diagnostics {
+                println!("{diagnostic}");
+            }
+            let errors = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == check::Severity::Error)
+                .count();
+            if errors > 0 {
+                return Err(CommandError::InvalidConfig(errors));
+            }
+            for file in files {
+                println!("{} is valid", file.display());
+            }
+        }
+        ConfigCommand::Schema => {
+            let schema = serde_json::to_string_pretty(&check::schema())
+                .map_err(color_eyre::Report::from)?;
+            println!("{schema}");
+        }
+    }
+    Ok(())
+}
+
+/// Open the reminder store, asking for access if the user hasn't decided yet.
+pub(crate) async fn connect() -> Result<Arc<dyn ReminderSource>, CommandError> {
+    let source = crate::source::open()?;
+    match source.check_permission_status() {
+        PermissionStatus::Authorized => Ok(source),
+        PermissionStatus::NotDetermined => {
+            if source.request_permission().await? {
+                Ok(source)
+            } else {
+                Err(CommandError::PermissionDenied)
+            }
+        }
+        PermissionStatus::Denied | PermissionStatus::Restricted => {
+            Err(CommandError::PermissionDenied)
+        }
+    }
+}
+
+/// Find a list by id, or by name ignoring case.
+pub(crate) fn find_list(
+    source: &dyn ReminderSource,
+    name_or_id: &str,
+) -> Result<ReminderList, CommandError> {
+    let lists = source.get_reminder_lists()?;
+    lists
+        .iter()
+        .find(|list| list.id == name_or_id)
+        .or_else(|| {
+            lists
+                .iter()
+                .find(|list| list.title.eq_ignore_ascii_case(name_or_id))
+        })
+        .cloned()
+        .ok_or_else(|| CommandError::NotFound(format!("No list named '{name_or_id}'")))
+}
+
+pub(crate) fn search(
+    source: &dyn ReminderSource,
+    query: &str,
+    include_completed: bool,
+) -> Result<Vec<(Reminder, ReminderList)>, CommandError> {
+    let query = query.to_lowercase();
+    let mut matches = Vec::new();
+    for list in source.get_reminder_lists()? {
+        for reminder in source.get_reminders_for_list(&list.id)? {
+            let is_match = reminder.title.to_lowercase().contains(&query)
+                || reminder
+                    .notes
+                    .as_ref()
+                    .is_some_and(|notes| notes.to_lowercase().contains(&query));
+            if is_match && (include_completed || !reminder.completed) {
+                matches.push((reminder, list.clone()));
+            }
+        }
+    }
+    Ok(matches)
+}
+
+fn print_records(
+    kind: RecordKind,
+    output: &OutputArgs,
+    records: &[Record],
+) -> Result<(), CommandError> {
+    let fields = kind
+        .select_fields(&output.fields)
+        .map_err(CommandError::Usage)?;
+    print!("{}", output::render(output.format, &fields, records));
+    Ok(())
+}
+
+/// Parse a human due date relative to `now` into an ISO 8601 date or local date-time.
+///
+/// Accepts `today`, `tomorrow`, weekday names (the next such day), `in 3d` / `in 2w`,
+/// `2025-01-31`, and any of those followed by a `HH:MM` time.
+pub fn parse_due_date(input: &str, now: NaiveDateTime) -> Result<String, CommandError> {
+    let invalid = || CommandError::Usage(format!("Invalid due date '{input}'"));
+    let input = input.trim().to_lowercase();
+
+    let (day, time) = match input.rsplit_once(' ') {
+        Some((day, time)) if time.contains(':') => (
+            day,
+            Some(NaiveTime::parse_from_str(time, "%H:%M").map_err(|_| invalid())?),
+        ),
+        _ => (input.as_str(), None),
+    };
+
+    let today = now.date();
+    let date = match day {
+        "today" => today,
+        "tomorrow" => today + Duration::days(1),
+        relative if relative.starts_with("in ") => {
+            let amount = relative.trim_start_matches("in ").replace(' ', "");
+            let (number, unit) = amount.split_at(amount.len().saturating_sub(1));
+            let number: i64 = number.parse().map_err(|_| invalid())?;
+            match unit {
+                "d" => today + Duration::days(number),
+                "w" => today + Duration::weeks(number),
+                _ => return Err(invalid()),
+            }
+        }
+        other => match other.parse::<Weekday>() {
+            Ok(weekday) => {
+                let ahead = (7 + weekday.num_days_from_monday()
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                today + Duration::days(if ahead == 0 { 7 } else { ahead.into() })
+            }
+            Err(_) => NaiveDate::parse_from_str(other, "%Y-%m-%d").map_err(|_| invalid())?,
+        },
+    };
+
+    Ok(match time {
+        Some(time) => date.and_time(time).format("%Y-%m-%dT%H:%M:%S").to_string(),
+        None => date.format("%Y-%m-%d").to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        cli::Cli,
+        output::{Field, OutputFormat},
+    };
+
+    fn now() -> NaiveDateTime {
+        // A Wednesday
+        NaiveDate::from_ymd_opt(2025, 1, 15)
+            .unwrap()
+            .and_hms_opt(10, 30, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_due_date_keywords() {
+        assert_eq!(parse_due_date("today", now()).unwrap(), "2025-01-15");
+        assert_eq!(parse_due_date("Tomorrow", now()).unwrap(), "2025-01-16");
+        assert_eq!(parse_due_date("in 3d", now()).unwrap(), "2025-01-18");
+        assert_eq!(parse_due_date("in 2w", now()).unwrap(), "2025-01-29");
+    }
+
+    #[test]
+    fn test_parse_due_date_weekdays() {
+        assert_eq!(parse_due_date("friday", now()).unwrap(), "2025-01-17");
+        assert_eq!(parse_due_date("mon", now()).unwrap(), "2025-01-20");
+        // The same weekday means next week, not today
+        assert_eq!(parse_due_date("wednesday", now()).unwrap(), "2025-01-22");
+    }
+
+    #[test]
+    fn test_parse_due_date_with_time() {
+        assert_eq!(
+            parse_due_date("tomorrow 09:00", now()).unwrap(),
+            "2025-01-16T09:00:00"
+        );
+        assert_eq!(
+            parse_due_date("2025-02-01 17:45", now()).unwrap(),
+            "2025-02-01T17:45:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_due_date_invalid() {
+        for input in ["someday", "in 3 months", "2025-13-01", "today 25:00"] {
+            let error = parse_due_date(input, now()).unwrap_err();
+            assert_eq!(error.exit_code(), exit_code::USAGE, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_subcommands() {
+        let cli = Cli::try_parse_from([
+            "rem",
+            "add",
+            "Buy milk",
+            "--list",
+            "Groceries",
+            "--due",
+            "tomorrow",
+            "--priority",
+            "5",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Add {
+                title: "Buy milk".to_string(),
+                list: Some("Groceries".to_string()),
+                due: Some("tomorrow".to_string()),
+                priority: 5,
+                notes: None,
+            })
+        );
+
+        let cli = Cli::try_parse_from(["rem", "ls", "Groceries"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::List {
+                list: Some("Groceries".to_string()),
+                all: false,
+                output: OutputArgs::default(),
+            })
+        );
+
+        let cli = Cli::try_parse_from([
+            "rem",
+            "search",
+            "milk",
+            "--format",
+            "ndjson",
+            "--fields",
+            "title,due,list",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Search {
+                query: "milk".to_string(),
+                all: false,
+                output: OutputArgs {
+                    format: OutputFormat::Ndjson,
+                    fields: vec![Field::Title, Field::Due, Field::List],
+                },
+            })
+        );
+
+        let cli = Cli::try_parse_from(["rem", "rm", "reminder-groceries-0"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Remove {
+                id: "reminder-groceries-0".to_string(),
+            })
+        );
+
+        let cli = Cli::try_parse_from(["rem", "config", "dump"]).unwrap();
+        assert_eq!(
+            cli.command,
+            Some(Command::Config {
+                command: ConfigCommand::Dump,
+            })
+        );
+
+        // No subcommand launches the TUI
+        assert_eq!(Cli::try_parse_from(["rem"]).unwrap().command, None);
+    }
+
+    #[test]
+    fn test_cli_rejects_out_of_range_priority() {
+        assert!(Cli::try_parse_from(["rem", "add", "x", "--priority", "10"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_rejects_unknown_format_and_field() {
+        assert!(Cli::try_parse_from(["rem", "ls", "--format", "yaml"]).is_err());
+        assert!(Cli::try_parse_from(["rem", "ls", "--fields", "title,size"]).is_err());
+    }
+}
@@ -8,6 +8,7 @@ use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{action::Action, config::Config, tui::Event};
 
+pub mod create;
 pub mod fps;
 pub mod home;
 pub mod lists;
@@ -101,6 +102,15 @@ pub trait Component {
         let _ = mouse; // to appease clippy
         Ok(None)
     }
+    /// Whether the component is taking text input, such as a search query. `App` then
+    /// hands it every key instead of looking keys up in the keymap.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether keys should skip the keymap.
+    fn is_editing(&self) -> bool {
+        false
+    }
     /// Update the state of the component based on a received action. (REQUIRED)
     ///
     /// # Arguments
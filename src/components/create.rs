@@ -0,0 +1,258 @@
+use chrono::Local;
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use crate::{
+    action::Action,
+    app::Mode,
+    commands::parse_due_date,
+    components::Component,
+    config::Config,
+    source::{NewReminder, ReminderList},
+};
+
+const FIELDS: [&str; 5] = ["Title", "Notes", "Due", "List", "Priority"];
+
+/// EventKit's priorities, from none to high, with their labels.
+const PRIORITIES: [(u8, &str); 4] = [(0, "None"), (9, "Low"), (5, "Medium"), (1, "High")];
+
+/// A form for a new reminder, drawn over the screen it was opened from. It takes every
+/// key while open; `App` closes it on `CreateReminder` or `Back`.
+pub struct CreateForm {
+    config: Config,
+    /// The screen underneath, whose styles the form uses.
+    mode: Mode,
+    lists: Vec<ReminderList>,
+    title: String,
+    notes: String,
+    due: String,
+    list_index: usize,
+    priority_index: usize,
+    field: usize,
+    error: Option<String>,
+}
+
+impl CreateForm {
+    /// A blank form that files the reminder in `list_id`, or the first list.
+    pub fn new(mode: Mode, lists: Vec<ReminderList>, list_id: Option<&str>) -> Self {
+        let list_index = list_id
+            .and_then(|id| lists.iter().position(|list| list.id == id))
+            .unwrap_or(0);
+        Self {
+            config: Config::default(),
+            mode,
+            lists,
+            title: String::new(),
+            notes: String::new(),
+            due: String::new(),
+            list_index,
+            priority_index: 0,
+            field: 0,
+            error: None,
+        }
+    }
+
+    fn text_field(&mut self) -> Option<&mut String> {
+        match self.field {
+            0 => Some(&mut self.title),
+            1 => Some(&mut self.notes),
+            2 => Some(&mut self.due),
+            _ => None,
+        }
+    }
+
+    /// Step the list or priority picker when one has focus.
+    fn cycle(&mut self, forward: bool) {
+        let (index, len) = match self.field {
+            3 => (&mut self.list_index, self.lists.len()),
+            4 => (&mut self.priority_index, PRIORITIES.len()),
+            _ => return,
+        };
+        if len > 0 {
+            *index = if forward {
+                (*index + 1) % len
+            } else {
+                (*index + len - 1) % len
+            };
+        }
+    }
+
+    /// The reminder to create, or why the form can't be submitted yet.
+    fn submit(&self) -> Result<NewReminder, String> {
+        let title = self.title.trim();
+        if title.is_empty() {
+            return Err("A reminder needs a title".to_string());
+        }
+        let list = self
+            .lists
+            .get(self.list_index)
+            .ok_or_else(|| "There's no list to add the reminder to".to_string())?;
+        let due_date = match self.due.trim() {
+            "" => None,
+            due => {
+                Some(parse_due_date(due, Local::now().naive_local()).map_err(|e| e.to_string())?)
+            }
+        };
+        let notes = self.notes.trim();
+        Ok(NewReminder {
+            title: title.to_string(),
+            notes: (!notes.is_empty()).then(|| notes.to_string()),
+            due_date,
+            list_id: list.id.clone(),
+            priority: PRIORITIES[self.priority_index].0,
+        })
+    }
+
+    fn save(&mut self) -> Option<Action> {
+        match self.submit() {
+            Ok(new_reminder) => Some(Action::CreateReminder(new_reminder)),
+            Err(error) => {
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+
+    fn style(&self, key: &str) -> Style {
+        self.config.styles.style(self.mode, key)
+    }
+
+    fn field_line(&self, index: usize) -> Line<'_> {
+        let focused = index == self.field;
+        let value = match index {
+            0 => self.title.as_str(),
+            1 => self.notes.as_str(),
+            2 => self.due.as_str(),
+            3 => self
+                .lists
+                .get(self.list_index)
+                .map_or("-", |list| list.title.as_str()),
+            _ => PRIORITIES[self.priority_index].1,
+        };
+        let mut spans = vec![
+            Span::styled(if focused { "▶ " } else { "  " }, self.style("cursor")),
+            Span::styled(format!("{:<10}", FIELDS[index]), self.style("hint")),
+            Span::styled(
+                value.to_string(),
+                self.style(if focused { "item_selected" } else { "item" }),
+            ),
+        ];
+        if focused {
+            spans.push(Span::styled(
+                if index < 3 { "▏" } else { "  ↑/↓" },
+                self.style("hint"),
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Component for CreateForm {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_editing(&self) -> bool {
+        true
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        let action = match key.code {
+            KeyCode::Esc => Some(Action::Back),
+            KeyCode::Enter => self.save(),
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => self.save(),
+            KeyCode::Tab => {
+                self.field = (self.field + 1) % FIELDS.len();
+                None
+            }
+            KeyCode::BackTab => {
+                self.field = (self.field + FIELDS.len() - 1) % FIELDS.len();
+                None
+            }
+            // Arrows move between text fields and step through the pickers
+            KeyCode::Down if self.field < 3 => {
+                self.field += 1;
+                None
+            }
+            KeyCode::Up if self.field < 3 => {
+                self.field = self.field.saturating_sub(1);
+                None
+            }
+            KeyCode::Down | KeyCode::Right => {
+                self.cycle(true);
+                None
+            }
+            KeyCode::Up | KeyCode::Left => {
+                self.cycle(false);
+                None
+            }
+            KeyCode::Backspace => {
+                if let Some(text) = self.text_field() {
+                    text.pop();
+                }
+                None
+            }
+            KeyCode::Char(c) => {
+                if let Some(text) = self.text_field() {
+                    text.push(c);
+                }
+                None
+            }
+            _ => None,
+        };
+        Ok(action)
+    }
+
+    fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()> {
+        let [popup] = Layout::horizontal([Constraint::Max(64)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup] = Layout::vertical([Constraint::Length(13)])
+            .flex(Flex::Center)
+            .areas(popup);
+
+        let mut lines: Vec<Line> = (0..FIELDS.len()).map(|i| self.field_line(i)).collect();
+        lines.push(Line::from(""));
+        lines.push(match &self.error {
+            Some(error) => Line::from(Span::styled(error.as_str(), self.style("error"))),
+            None => Line::from(Span::styled(
+                "Due takes today, tomorrow, a weekday, in 3d or 2025-01-31, then HH:MM",
+                self.style("hint"),
+            )),
+        });
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(vec![
+                Span::styled("tab", self.style("key_navigate")),
+                Span::styled(" next field  ", self.style("hint")),
+                Span::styled("enter", self.style("key_confirm")),
+                Span::styled(" save  ", self.style("hint")),
+                Span::styled("esc", self.style("key_quit")),
+                Span::styled(" cancel", self.style("hint")),
+            ])
+            .alignment(Alignment::Center),
+        );
+
+        let form = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(" ✏️  New Reminder ", self.style("title")))
+                .title_alignment(Alignment::Center)
+                .style(self.style("border"))
+                .padding(Padding::uniform(1)),
+        );
+
+        f.render_widget(Clear, popup);
+        f.render_widget(form, popup);
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
+use std::collections::HashMap;
+
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Modifier},
+    style::{Color, Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap, BorderType, Padding},
 };
@@ -20,15 +21,18 @@ macro_rules! debug_log {
 
 use crate::{
     action::Action,
+    app::Mode,
     components::Component,
     config::Config,
-    eventkit::{EventKitManager, ReminderList},
+    source::ReminderList,
 };
 
 pub struct ListsComponent {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
     lists: Vec<ReminderList>,
+    /// Counts by list id as they arrive; `None` when counting failed.
+    counts: HashMap<String, Option<usize>>,
     selected_index: usize,
     list_state: ListState,
     loading: bool,
@@ -44,6 +48,7 @@ impl ListsComponent {
             command_tx: None,
             config: Config::default(),
             lists: Vec::new(),
+            counts: HashMap::new(),
             selected_index: 0,
             list_state,
             loading: false,
@@ -51,34 +56,50 @@ impl ListsComponent {
         }
     }
 
-    pub fn load_lists(&mut self, eventkit: &EventKitManager) -> Result<()> {
+    /// Show the loading screen until [`ListsComponent::set_lists`].
+    pub fn start_loading(&mut self) {
         self.loading = true;
         self.error = None;
         debug_log!("Debug: Loading lists...");
+    }
 
-        match eventkit.get_reminder_lists() {
+    /// Show lists loaded by `App`. Counts already in arrive with them, the rest
+    /// through [`ListsComponent::set_count`].
+    pub fn set_lists(&mut self, lists: Result<Vec<ReminderList>, String>) {
+        match lists {
             Ok(lists) => {
                 debug_log!("Debug: Loaded {} lists", lists.len());
                 if std::env::var("DEBUG").unwrap_or_default() == "true" {
                     for (i, list) in lists.iter().enumerate() {
-                        eprintln!("Debug: List {}: {} ({} reminders)", i, list.title, list.reminder_count);
+                        eprintln!("Debug: List {}: {}", i, list.title);
                     }
                 }
                 self.lists = lists;
-                self.loading = false;
-                if !self.lists.is_empty() {
-                    self.selected_index = 0;
-                    self.list_state.select(Some(0));
-                }
+                self.error = None;
+                // Reloads after a change keep the cursor where it was
+                self.move_to(self.selected_index);
             }
             Err(e) => {
                 debug_log!("Debug: Failed to load lists: {}", e);
                 self.error = Some(format!("Failed to load lists: {e}"));
-                self.loading = false;
             }
         }
+        self.loading = false;
+    }
 
-        Ok(())
+    pub fn set_count(&mut self, list_id: &str, count: Result<usize, String>) {
+        if let Err(e) = &count {
+            debug_log!("Debug: Failed to count list {}: {}", list_id, e);
+        }
+        self.counts.insert(list_id.to_string(), count.ok());
+    }
+
+    pub fn lists(&self) -> &[ReminderList] {
+        &self.lists
+    }
+
+    pub fn selected_list_id(&self) -> Option<&str> {
+        self.lists.get(self.selected_index).map(|list| list.id.as_str())
     }
 
     fn move_up(&mut self) {
@@ -107,6 +128,15 @@ impl ListsComponent {
         self.list_state.select(Some(self.selected_index));
     }
 
+    fn move_to(&mut self, index: usize) {
+        if self.lists.is_empty() {
+            return;
+        }
+
+        self.selected_index = index.min(self.lists.len() - 1);
+        self.list_state.select(Some(self.selected_index));
+    }
+
     fn select_current(&self) -> Option<Action> {
         if let Some(list) = self.lists.get(self.selected_index) {
             Some(Action::SelectList(list.id.clone()))
@@ -115,20 +145,30 @@ impl ListsComponent {
         }
     }
 
+    fn style(&self, key: &str) -> Style {
+        self.config.styles.style(Mode::Lists, key)
+    }
+
+    /// The key bound to `action`, for the hints on screen.
+    fn key(&self, action: Action) -> String {
+        self.config
+            .keybindings
+            .hint(Mode::Lists, &action)
+            .unwrap_or_else(|| "-".to_string())
+    }
+
     fn render_loading(&self, f: &mut Frame, area: Rect) {
         let loading_text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "✨ Loading your reminders...",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                self.style("progress")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "⏳ Please wait",
-                Style::default().fg(Color::Gray)
+                self.style("hint")
             )),
         ];
 
@@ -139,12 +179,10 @@ impl ListsComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " 📝 Rem - Apple Reminders ",
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("title")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Blue))
+                    .style(self.style("border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -157,38 +195,24 @@ impl ListsComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "⚠️  Error",
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD),
+                self.style("error"),
             )),
             Line::from(""),
             Line::from(Span::styled(
                 error,
-                Style::default().fg(Color::White)
+                self.style("text")
             )),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "r",
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to retry", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Refresh), self.style("key_confirm")),
+                Span::styled(" to retry", self.style("hint")),
             ]),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "q",
-                    Style::default()
-                        .fg(Color::Red)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to quit", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Quit), self.style("key_quit")),
+                Span::styled(" to quit", self.style("hint")),
             ]),
         ];
 
@@ -199,12 +223,10 @@ impl ListsComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " ❌ Error ",
-                        Style::default()
-                            .fg(Color::Red)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("error")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Red))
+                    .style(self.style("error_border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -219,14 +241,12 @@ impl ListsComponent {
                 Line::from(""),
                 Line::from(Span::styled(
                     "📭 No reminder lists found",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
+                    self.style("empty")
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Check your Apple Reminders app",
-                    Style::default().fg(Color::Gray)
+                    self.style("hint")
                 )),
             ];
 
@@ -237,12 +257,10 @@ impl ListsComponent {
                         .border_type(BorderType::Rounded)
                         .title(Span::styled(
                             " 📝 Rem - Apple Reminders ",
-                            Style::default()
-                                .fg(Color::Blue)
-                                .add_modifier(Modifier::BOLD)
+                            self.style("title")
                         ))
                         .title_alignment(Alignment::Center)
-                        .style(Style::default().fg(Color::Blue))
+                        .style(self.style("border"))
                 )
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true });
@@ -267,48 +285,36 @@ impl ListsComponent {
                 let color = parse_color(&list.color);
                 
                 // Format reminder count with better styling
-                let count_text = if list.reminder_count == 0 {
-                    "Empty".to_string()
-                } else if list.reminder_count == 1 {
-                    "1 reminder".to_string()
-                } else {
-                    format!("{} reminders", list.reminder_count)
+                let count = self.counts.get(&list.id);
+                let count_text = match count {
+                    None => "Counting…".to_string(),
+                    Some(None) => "Couldn't count reminders".to_string(),
+                    Some(Some(0)) => "Empty".to_string(),
+                    Some(Some(1)) => "1 reminder".to_string(),
+                    Some(Some(count)) => format!("{count} reminders"),
                 };
 
                 let mut lines = vec![
                     Line::from(vec![
                         Span::styled(
                             if is_selected { "▶ " } else { "  " },
-                            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
-                        ),
-                        Span::styled(
-                            "●",
-                            Style::default()
-                                .fg(color)
-                                .add_modifier(Modifier::BOLD)
+                            self.style("cursor")
                         ),
+                        // The list's own color from Reminders, not a theme color
+                        Span::styled("●", Style::default().fg(color).bold()),
                         Span::raw("  "),
                         Span::styled(
                             &list.title,
-                            Style::default()
-                                .fg(if is_selected { Color::White } else { Color::LightBlue })
-                                .add_modifier(if is_selected { 
-                                    Modifier::BOLD | Modifier::UNDERLINED 
-                                } else { 
-                                    Modifier::BOLD 
-                                })
+                            self.style(if is_selected { "item_selected" } else { "item" })
                         ),
                     ]),
                     Line::from(vec![
                         Span::raw("    "),
                         Span::styled(
                             count_text,
-                            Style::default().fg(if list.reminder_count == 0 {
-                                Color::DarkGray
-                            } else if is_selected {
-                                Color::Gray
-                            } else {
-                                Color::Gray
+                            self.style(match count {
+                                Some(Some(count)) if *count > 0 => "count",
+                                _ => "count_empty",
                             })
                         ),
                     ]),
@@ -320,9 +326,7 @@ impl ListsComponent {
                 }
 
                 let style = if is_selected {
-                    Style::default()
-                        .bg(Color::DarkGray)
-                        .fg(Color::White)
+                    self.style("selected")
                 } else {
                     Style::default()
                 };
@@ -338,32 +342,29 @@ impl ListsComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " 📝 Your Reminder Lists ",
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("title")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Blue))
+                    .style(self.style("border"))
                     .padding(Padding::horizontal(1))
             )
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD)
-            );
+            .highlight_style(self.style("highlight"));
 
         f.render_stateful_widget(list_widget, main_layout[0], &mut self.list_state);
 
         // Enhanced instructions at the bottom
+        let navigate = format!("{}/{}", self.key(Action::Up), self.key(Action::Down));
         let instructions = Paragraph::new(vec![Line::from(vec![
-            Span::styled("↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" or ", Style::default().fg(Color::Gray)),
-            Span::styled("j/k", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" navigate  ", Style::default().fg(Color::Gray)),
-            Span::styled("⏎", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" select  ", Style::default().fg(Color::Gray)),
-            Span::styled("q", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" quit", Style::default().fg(Color::Gray)),
+            Span::styled(navigate, self.style("key_navigate")),
+            Span::styled(" navigate  ", self.style("hint")),
+            Span::styled(self.key(Action::Enter), self.style("key_confirm")),
+            Span::styled(" select  ", self.style("hint")),
+            Span::styled(self.key(Action::Create), self.style("key_confirm")),
+            Span::styled(" new  ", self.style("hint")),
+            Span::styled(self.key(Action::GlobalSearch), self.style("key_navigate")),
+            Span::styled(" search all  ", self.style("hint")),
+            Span::styled(self.key(Action::Quit), self.style("key_quit")),
+            Span::styled(" quit", self.style("hint")),
         ])])
         .block(
             Block::default()
@@ -371,10 +372,10 @@ impl ListsComponent {
                 .border_type(BorderType::Rounded)
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    self.style("controls_title")
                 ))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow))
+                .style(self.style("controls"))
         )
         .alignment(Alignment::Center);
 
@@ -394,44 +395,31 @@ impl Component for ListsComponent {
     }
 
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        match action {
-            Action::LoadLists => {
-                // This will be handled by the parent component that has access to EventKit
-                Ok(None)
-            }
-            _ => Ok(None),
-        }
-    }
-
-    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         if self.loading {
             return Ok(None);
         }
 
-        if self.error.is_some() {
-            match key.code {
-                KeyCode::Char('r') => {
-                    return Ok(Some(Action::LoadLists));
-                }
-                KeyCode::Char('q') => {
-                    return Ok(Some(Action::Quit));
-                }
-                _ => {}
-            }
-            return Ok(None);
-        }
-
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+        match action {
+            // Reloading replaces this component, see `App`
+            Action::Refresh => Ok(Some(Action::LoadLists)),
+            _ if self.error.is_some() => Ok(None),
+            Action::Up => {
                 self.move_up();
                 Ok(None)
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            Action::Down => {
                 self.move_down();
                 Ok(None)
             }
-            KeyCode::Enter => Ok(self.select_current()),
-            KeyCode::Char('q') => Ok(Some(Action::Quit)),
+            Action::First => {
+                self.move_to(0);
+                Ok(None)
+            }
+            Action::Last => {
+                self.move_to(usize::MAX);
+                Ok(None)
+            }
+            Action::Enter => Ok(self.select_current()),
             _ => Ok(None),
         }
     }
@@ -1,12 +1,12 @@
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Stylize, Modifier},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap, BorderType},
 };
+use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
 
 // Macro for conditional debug logging based on DEBUG environment variable
@@ -20,9 +20,10 @@ macro_rules! debug_log {
 
 use crate::{
     action::Action,
+    app::Mode,
     components::Component,
     config::Config,
-    eventkit::{EventKitManager, PermissionStatus},
+    source::{self, PermissionStatus, ReminderSource},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,7 +40,7 @@ pub struct PermissionComponent {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
     state: PermissionState,
-    eventkit: Option<EventKitManager>,
+    source: Option<Arc<dyn ReminderSource>>,
 }
 
 impl PermissionComponent {
@@ -48,7 +49,7 @@ impl PermissionComponent {
             command_tx: None,
             config: Config::default(),
             state: PermissionState::Checking,
-            eventkit: None,
+            source: None,
         }
     }
 
@@ -57,8 +58,8 @@ impl PermissionComponent {
     }
 
     fn check_permissions(&mut self) -> Result<()> {
-        // Try to initialize EventKit with better error handling
-        match EventKitManager::new() {
+        // Try to open the reminder store with better error handling
+        match source::open() {
             Ok(manager) => {
                 let status = manager.check_permission_status();
                 debug_log!("Debug: Permission status: {:?}", status);
@@ -67,7 +68,7 @@ impl PermissionComponent {
                     PermissionStatus::Authorized => {
                         debug_log!("Debug: Permission already authorized, loading lists");
                         self.state = PermissionState::Granted;
-                        self.eventkit = Some(manager);
+                        self.source = Some(manager);
                         if let Some(tx) = &self.command_tx {
                             let _ = tx.send(Action::LoadLists);
                         }
@@ -75,7 +76,7 @@ impl PermissionComponent {
                     PermissionStatus::NotDetermined => {
                         debug_log!("Debug: Permission not determined, needs permission");
                         self.state = PermissionState::NeedsPermission;
-                        self.eventkit = Some(manager);
+                        self.source = Some(manager);
                     }
                     PermissionStatus::Denied => {
                         debug_log!("Debug: Permission denied");
@@ -89,7 +90,7 @@ impl PermissionComponent {
             }
             Err(e) => {
                 // More specific error handling
-                let error_msg = format!("EventKit initialization failed: {}", e);
+                let error_msg = format!("Reminders initialization failed: {}", e);
                 debug_log!("Debug: {}", error_msg);
                 self.state = PermissionState::Error(error_msg);
             }
@@ -99,7 +100,7 @@ impl PermissionComponent {
     }
 
     async fn request_permissions(&mut self) -> Result<()> {
-        if let Some(manager) = &self.eventkit {
+        if let Some(manager) = &self.source {
             self.state = PermissionState::Requesting;
 
             match manager.request_permission().await {
@@ -123,8 +124,20 @@ impl PermissionComponent {
         Ok(())
     }
 
-    pub fn get_eventkit(&self) -> Option<&EventKitManager> {
-        self.eventkit.as_ref()
+    pub fn get_source(&self) -> Option<Arc<dyn ReminderSource>> {
+        self.source.clone()
+    }
+
+    fn style(&self, key: &str) -> Style {
+        self.config.styles.style(Mode::Permission, key)
+    }
+
+    /// The key bound to `action`, for the hints on screen.
+    fn key(&self, action: Action) -> String {
+        self.config
+            .keybindings
+            .hint(Mode::Permission, &action)
+            .unwrap_or_else(|| "-".to_string())
     }
 
     fn render_checking(&self, f: &mut Frame, area: Rect) {
@@ -133,14 +146,12 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "🔍 Checking permissions...",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                self.style("progress")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Please wait",
-                Style::default().fg(Color::Gray)
+                self.style("hint")
             )),
         ];
 
@@ -151,12 +162,10 @@ impl PermissionComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " 📝 Rem - Apple Reminders ",
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("title")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Blue))
+                    .style(self.style("border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -169,55 +178,41 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "🔐 Permission Required",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                self.style("warning")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Rem needs access to your Apple Reminders",
-                Style::default().fg(Color::White)
+                self.style("text")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "This allows the app to:",
-                Style::default().fg(Color::LightBlue).add_modifier(Modifier::BOLD)
+                self.style("heading")
             )),
             Line::from(vec![
-                Span::styled("  📋 ", Style::default().fg(Color::Cyan)),
-                Span::styled("View your reminder lists", Style::default().fg(Color::Gray)),
+                Span::styled("  📋 ", self.style("bullet")),
+                Span::styled("View your reminder lists", self.style("hint")),
             ]),
             Line::from(vec![
-                Span::styled("  👀 ", Style::default().fg(Color::Cyan)),
-                Span::styled("Read and display your reminders", Style::default().fg(Color::Gray)),
+                Span::styled("  👀 ", self.style("bullet")),
+                Span::styled("Read and display your reminders", self.style("hint")),
             ]),
             Line::from(vec![
-                Span::styled("  ✅ ", Style::default().fg(Color::Cyan)),
-                Span::styled("Mark reminders as complete", Style::default().fg(Color::Gray)),
+                Span::styled("  ✅ ", self.style("bullet")),
+                Span::styled("Mark reminders as complete", self.style("hint")),
             ]),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "Enter",
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to grant access", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Enter), self.style("key_confirm")),
+                Span::styled(" to grant access", self.style("hint")),
             ]),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "q",
-                    Style::default()
-                        .fg(Color::Red)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to quit", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Quit), self.style("key_quit")),
+                Span::styled(" to quit", self.style("hint")),
             ]),
         ];
 
@@ -228,12 +223,10 @@ impl PermissionComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " 🔐 Permissions Required ",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("warning")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Yellow))
+                    .style(self.style("warning_border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -247,23 +240,21 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "⏳ Requesting permissions...",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
+                self.style("warning")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Please check the system dialog",
-                Style::default().fg(Color::White)
+                self.style("text")
             )),
             Line::from(Span::styled(
                 "and grant access to continue",
-                Style::default().fg(Color::White)
+                self.style("text")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "💡 Look for the macOS permission dialog",
-                Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC)
+                self.style("tip")
             )),
         ];
 
@@ -274,12 +265,10 @@ impl PermissionComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         " 📝 Rem - Requesting Access ",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("warning")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Yellow))
+                    .style(self.style("warning_border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -292,7 +281,7 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "Access Denied",
-                Style::default().fg(Color::Red).bold(),
+                self.style("error"),
             )),
             Line::from(""),
             Line::from("Rem cannot access your Reminders."),
@@ -306,7 +295,7 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(vec![
                 Span::styled("Press ", Style::default()),
-                Span::styled("q", Style::default().fg(Color::Red).bold()),
+                Span::styled(self.key(Action::Quit), self.style("error")),
                 Span::styled(" to quit", Style::default()),
             ]),
         ];
@@ -328,14 +317,14 @@ impl PermissionComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "Error",
-                Style::default().fg(Color::Red).bold(),
+                self.style("error"),
             )),
             Line::from(""),
             Line::from(error),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Press ", Style::default()),
-                Span::styled("q", Style::default().fg(Color::Red).bold()),
+                Span::styled(self.key(Action::Quit), self.style("error")),
                 Span::styled(" to quit", Style::default()),
             ]),
         ];
@@ -369,6 +358,9 @@ impl Component for PermissionComponent {
                 }
                 Ok(None)
             }
+            Action::Enter if self.state == PermissionState::NeedsPermission => {
+                Ok(Some(Action::RequestPermissions))
+            }
             Action::RequestPermissions => {
                 let tx = self.command_tx.clone();
                 let mut component = self.clone();
@@ -386,28 +378,6 @@ impl Component for PermissionComponent {
         }
     }
 
-    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
-        match self.state {
-            PermissionState::NeedsPermission => match key.code {
-                KeyCode::Enter => {
-                    return Ok(Some(Action::RequestPermissions));
-                }
-                KeyCode::Char('q') => {
-                    return Ok(Some(Action::Quit));
-                }
-                _ => {}
-            },
-            PermissionState::Denied | PermissionState::Error(_) => match key.code {
-                KeyCode::Char('q') => {
-                    return Ok(Some(Action::Quit));
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-        Ok(None)
-    }
-
     fn draw(&mut self, f: &mut Frame, area: Rect) -> Result<()> {
         // Center the dialog
         let popup_area = centered_rect(60, 50, area);
@@ -436,7 +406,7 @@ impl Clone for PermissionComponent {
             command_tx: self.command_tx.clone(),
             config: self.config.clone(),
             state: self.state.clone(),
-            eventkit: None, // Don't clone EventKit manager
+            source: self.source.clone(),
         }
     }
 }
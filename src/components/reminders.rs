@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Modifier},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap, BorderType, Padding},
 };
@@ -20,21 +22,70 @@ macro_rules! debug_log {
 
 use crate::{
     action::Action,
+    app::Mode,
     components::Component,
     config::Config,
-    eventkit::{EventKitManager, Reminder},
+    source::{Reminder, ReminderSource},
 };
 
+/// The reminders in `list_id`, titled `list_title`, or in every list when it's `None`,
+/// each with its list's title. Blocks on the source, so `App` runs it off the UI thread.
+pub fn fetch_reminders(
+    source: &dyn ReminderSource,
+    list_id: Option<&str>,
+    list_title: &str,
+) -> Result<Vec<(Reminder, String)>> {
+    if let Some(list_id) = list_id {
+        let reminders = source.get_reminders_for_list(list_id)?;
+        return Ok(reminders
+            .into_iter()
+            .map(|reminder| (reminder, list_title.to_string()))
+            .collect());
+    }
+    let mut reminders = Vec::new();
+    for list in source.get_reminder_lists_without_counts()? {
+        for reminder in source.get_reminders_for_list(&list.id)? {
+            reminders.push((reminder, list.title.clone()));
+        }
+    }
+    Ok(reminders)
+}
+
+/// The marker for an EventKit priority, read as iCalendar ranges: 1-4 is high, 5 medium
+/// and 6-9 low. 0 means none.
+fn priority_marker(priority: u8) -> &'static str {
+    match priority {
+        0 => "",
+        1..=4 => "🔴",
+        5 => "🟡",
+        _ => "🟢",
+    }
+}
+
 pub struct RemindersComponent {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
     reminders: Vec<Reminder>,
+    /// Index into the reminders the search lets through, not into `reminders`.
     selected_index: usize,
     list_state: ListState,
     loading: bool,
     error: Option<String>,
-    list_id: String,
+    /// `None` shows every list's reminders, for searching them all.
+    list_id: Option<String>,
     list_title: String,
+    /// Each reminder's list title, when showing every list.
+    list_names: HashMap<String, String>,
+    search: Option<Search>,
+    /// Whether the selected reminder is waiting on a yes to be deleted.
+    confirm_delete: bool,
+}
+
+/// A filter on the reminders shown, matching titles, notes and list names.
+struct Search {
+    query: String,
+    /// Whether keys still go to the query.
+    editing: bool,
 }
 
 impl RemindersComponent {
@@ -50,60 +101,140 @@ impl RemindersComponent {
             list_state,
             loading: false,
             error: None,
-            list_id,
+            list_id: Some(list_id),
             list_title,
+            list_names: HashMap::new(),
+            search: None,
+            confirm_delete: false,
         }
     }
 
-    pub fn load_reminders(&mut self, eventkit: &EventKitManager) -> Result<()> {
+    /// Every list's reminders, with the search open.
+    pub fn all_lists() -> Self {
+        let mut component = Self::new(String::new(), "All Lists".to_string());
+        component.list_id = None;
+        component.start_search();
+        component
+    }
+
+    /// The list shown, or `None` when showing every list.
+    pub fn list_id(&self) -> Option<&str> {
+        self.list_id.as_deref()
+    }
+
+    pub fn list_title(&self) -> &str {
+        &self.list_title
+    }
+
+    /// The title of the list `reminder_id` is in, including one just deleted.
+    pub fn list_title_of(&self, reminder_id: &str) -> &str {
+        self.list_names
+            .get(reminder_id)
+            .map_or(&self.list_title, String::as_str)
+    }
+
+    /// Show the loading screen until [`RemindersComponent::set_reminders`].
+    pub fn start_loading(&mut self) {
         self.loading = true;
         self.error = None;
-        debug_log!("Debug: Loading reminders for list: {}", self.list_id);
+        debug_log!("Debug: Loading reminders for list: {:?}", self.list_id);
+    }
 
-        match eventkit.get_reminders_for_list(&self.list_id) {
+    /// Show reminders loaded by `App` with [`fetch_reminders`].
+    pub fn set_reminders(&mut self, reminders: Result<Vec<(Reminder, String)>, String>) {
+        match reminders {
             Ok(reminders) => {
                 debug_log!("Debug: Loaded {} reminders for list '{}'", reminders.len(), self.list_title);
                 if std::env::var("DEBUG").unwrap_or_default() == "true" {
-                    for (i, reminder) in reminders.iter().enumerate() {
+                    for (i, (reminder, _)) in reminders.iter().enumerate() {
                         eprintln!("Debug: Reminder {}: {} (completed: {})", i, reminder.title, reminder.completed);
                     }
                 }
-                self.reminders = reminders;
-                self.loading = false;
-                if !self.reminders.is_empty() {
-                    self.selected_index = 0;
-                    self.list_state.select(Some(0));
+                // Reloads after a change keep the cursor on the same reminder
+                let selected = self.selected().map(|reminder| reminder.id.clone());
+                self.list_names.clear();
+                self.reminders = Vec::with_capacity(reminders.len());
+                for (reminder, list_title) in reminders {
+                    // Only worth showing, and searching, across lists
+                    if self.list_id.is_none() {
+                        self.list_names.insert(reminder.id.clone(), list_title);
+                    }
+                    self.reminders.push(reminder);
                 }
+                self.error = None;
+                self.select(selected.as_deref());
             }
             Err(e) => {
                 debug_log!("Debug: Failed to load reminders: {}", e);
                 self.error = Some(format!("Failed to load reminders: {e}"));
-                self.loading = false;
             }
         }
+        self.loading = false;
+    }
 
-        Ok(())
+    /// Indexes into `reminders` of the ones the search lets through, in order.
+    fn visible(&self) -> Vec<usize> {
+        let query = match &self.search {
+            Some(search) if !search.query.is_empty() => search.query.to_lowercase(),
+            _ => return (0..self.reminders.len()).collect(),
+        };
+        self.reminders
+            .iter()
+            .enumerate()
+            .filter(|(_, reminder)| {
+                reminder.title.to_lowercase().contains(&query)
+                    || reminder
+                        .notes
+                        .as_ref()
+                        .is_some_and(|notes| notes.to_lowercase().contains(&query))
+                    || self
+                        .list_names
+                        .get(&reminder.id)
+                        .is_some_and(|list| list.to_lowercase().contains(&query))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn selected(&self) -> Option<&Reminder> {
+        let index = *self.visible().get(self.selected_index)?;
+        self.reminders.get(index)
+    }
+
+    /// Put the cursor on the reminder with `id` if it's shown, or keep it in bounds.
+    fn select(&mut self, id: Option<&str>) {
+        let visible = self.visible();
+        if let Some(position) = id.and_then(|id| {
+            visible
+                .iter()
+                .position(|&i| self.reminders[i].id == id)
+        }) {
+            self.selected_index = position;
+        }
+        self.move_to(self.selected_index);
     }
 
     fn move_up(&mut self) {
-        if self.reminders.is_empty() {
+        let count = self.visible().len();
+        if count == 0 {
             return;
         }
 
         if self.selected_index > 0 {
             self.selected_index -= 1;
         } else {
-            self.selected_index = self.reminders.len() - 1;
+            self.selected_index = count - 1;
         }
         self.list_state.select(Some(self.selected_index));
     }
 
     fn move_down(&mut self) {
-        if self.reminders.is_empty() {
+        let count = self.visible().len();
+        if count == 0 {
             return;
         }
 
-        if self.selected_index < self.reminders.len() - 1 {
+        if self.selected_index < count - 1 {
             self.selected_index += 1;
         } else {
             self.selected_index = 0;
@@ -111,27 +242,98 @@ impl RemindersComponent {
         self.list_state.select(Some(self.selected_index));
     }
 
-    fn toggle_completed(&mut self) {
-        if let Some(reminder) = self.reminders.get_mut(self.selected_index) {
-            reminder.completed = !reminder.completed;
-            // In a real app, you'd also update this in EventKit
+    fn move_to(&mut self, index: usize) {
+        let count = self.visible().len();
+        self.selected_index = index.min(count.saturating_sub(1));
+        self.list_state.select(Some(self.selected_index));
+    }
+
+    /// Flip the selected reminder straight away and ask `App` to save it; a failed
+    /// save reloads the list.
+    fn toggle_completed(&mut self) -> Option<Action> {
+        let index = *self.visible().get(self.selected_index)?;
+        let reminder = &mut self.reminders[index];
+        reminder.completed = !reminder.completed;
+        Some(Action::SetCompleted(reminder.id.clone(), reminder.completed))
+    }
+
+    /// Take the selected reminder out of the list and ask `App` to delete it.
+    fn delete_selected(&mut self) -> Option<Action> {
+        let index = *self.visible().get(self.selected_index)?;
+        // Its list name stays until the reload, for `App` to say where it was deleted from
+        let reminder = self.reminders.remove(index);
+        self.move_to(self.selected_index);
+        Some(Action::DeleteReminder(reminder.id))
+    }
+
+    fn start_search(&mut self) {
+        self.search = Some(Search {
+            query: String::new(),
+            editing: true,
+        });
+        self.move_to(0);
+    }
+
+    /// Drop the search, if any. Returns whether there was one.
+    pub fn clear_search(&mut self) -> bool {
+        let selected = self.selected().map(|reminder| reminder.id.clone());
+        let had_search = self.search.take().is_some();
+        self.select(selected.as_deref());
+        had_search
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.clear_search();
+            }
+            KeyCode::Enter => {
+                search.editing = false;
+                if search.query.is_empty() {
+                    self.clear_search();
+                }
+            }
+            KeyCode::Backspace => {
+                search.query.pop();
+                self.move_to(0);
+            }
+            KeyCode::Char(c) => {
+                search.query.push(c);
+                self.move_to(0);
+            }
+            KeyCode::Up => self.move_up(),
+            KeyCode::Down => self.move_down(),
+            _ => {}
         }
     }
 
+    fn style(&self, key: &str) -> Style {
+        self.config.styles.style(Mode::Reminders, key)
+    }
+
+    /// The key bound to `action`, for the hints on screen.
+    fn key(&self, action: Action) -> String {
+        self.config
+            .keybindings
+            .hint(Mode::Reminders, &action)
+            .unwrap_or_else(|| "-".to_string())
+    }
+
     fn render_loading(&self, f: &mut Frame, area: Rect) {
         let loading_text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
                 "⏳ Loading reminders...",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                self.style("progress")
             )),
             Line::from(""),
             Line::from(Span::styled(
                 "Please wait",
-                Style::default().fg(Color::Gray)
+                self.style("hint")
             )),
         ];
 
@@ -142,12 +344,10 @@ impl RemindersComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         format!(" 📋 {} ", self.list_title),
-                        Style::default()
-                            .fg(Color::Blue)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("title")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Blue))
+                    .style(self.style("border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -160,38 +360,24 @@ impl RemindersComponent {
             Line::from(""),
             Line::from(Span::styled(
                 "⚠️  Error Loading Reminders",
-                Style::default()
-                    .fg(Color::Red)
-                    .add_modifier(Modifier::BOLD),
+                self.style("error"),
             )),
             Line::from(""),
             Line::from(Span::styled(
                 error,
-                Style::default().fg(Color::White)
+                self.style("text")
             )),
             Line::from(""),
             Line::from(""),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "r",
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to retry", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Refresh), self.style("key_confirm")),
+                Span::styled(" to retry", self.style("hint")),
             ]),
             Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    "Esc",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                        .bg(Color::DarkGray)
-                ),
-                Span::styled(" to go back", Style::default().fg(Color::Gray)),
+                Span::styled("Press ", self.style("hint")),
+                Span::styled(self.key(Action::Back), self.style("key_back")),
+                Span::styled(" to go back", self.style("hint")),
             ]),
         ];
 
@@ -202,12 +388,10 @@ impl RemindersComponent {
                     .border_type(BorderType::Rounded)
                     .title(Span::styled(
                         format!(" ❌ {} ", self.list_title),
-                        Style::default()
-                            .fg(Color::Red)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("error")
                     ))
                     .title_alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::Red))
+                    .style(self.style("error_border"))
             )
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
@@ -215,27 +399,71 @@ impl RemindersComponent {
         f.render_widget(paragraph, area);
     }
 
+    fn render_search(&self, f: &mut Frame, area: Rect, search: &Search) {
+        let title = if self.list_id.is_some() {
+            " 🔍 Search "
+        } else {
+            " 🔍 Search All Lists "
+        };
+        let mut spans = vec![Span::styled(search.query.as_str(), self.style("search"))];
+        if search.editing {
+            spans.push(Span::styled("▏", self.style("cursor")));
+        } else {
+            spans.push(Span::styled(
+                format!("  {} to edit", self.key(Action::Search)),
+                self.style("hint"),
+            ));
+        }
+
+        let paragraph = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(Span::styled(title, self.style("title")))
+                .style(self.style("border"))
+                .padding(Padding::horizontal(1)),
+        );
+
+        f.render_widget(paragraph, area);
+    }
+
     fn render_reminders(&mut self, f: &mut Frame, area: Rect) {
+        let search_height = if self.search.is_some() { 3 } else { 0 };
         let main_layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(0), Constraint::Length(4)])
+            .constraints([
+                Constraint::Length(search_height),
+                Constraint::Min(0),
+                Constraint::Length(4),
+            ])
             .margin(1)
             .split(area);
+        let (search_area, main_layout) = (main_layout[0], &main_layout[1..]);
+        if let Some(search) = &self.search {
+            self.render_search(f, search_area, search);
+        }
+
+        let visible = self.visible();
+        if visible.is_empty() {
+            let (heading, hint) = match &self.search {
+                Some(search) if !search.query.is_empty() => (
+                    "🔍 No matches".to_string(),
+                    format!("Nothing matches \"{}\"", search.query),
+                ),
+                _ => ("✅ All done!".to_string(), "No reminders in this list".to_string()),
+            };
 
-        if self.reminders.is_empty() {
             let empty_text = vec![
                 Line::from(""),
                 Line::from(""),
                 Line::from(Span::styled(
-                    "✅ All done!",
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD)
+                    heading,
+                    self.style("empty")
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
-                    "No reminders in this list",
-                    Style::default().fg(Color::Gray)
+                    hint,
+                    self.style("hint")
                 )),
             ];
 
@@ -246,12 +474,10 @@ impl RemindersComponent {
                         .border_type(BorderType::Rounded)
                         .title(Span::styled(
                             format!(" 📋 {} ", self.list_title),
-                            Style::default()
-                                .fg(Color::Blue)
-                                .add_modifier(Modifier::BOLD)
+                            self.style("title")
                         ))
                         .title_alignment(Alignment::Center)
-                        .style(Style::default().fg(Color::Blue))
+                        .style(self.style("border"))
                 )
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true });
@@ -259,45 +485,31 @@ impl RemindersComponent {
             f.render_widget(paragraph, main_layout[0]);
         } else {
             // Create beautiful reminder items with enhanced styling
-            let items: Vec<ListItem> = self
-                .reminders
+            let items: Vec<ListItem> = visible
                 .iter()
+                .map(|&index| &self.reminders[index])
                 .enumerate()
                 .map(|(i, reminder)| {
                     let is_selected = i == self.selected_index;
                     
                     let completion_symbol = if reminder.completed { "✅" } else { "⭕" };
-                    let completion_style = if reminder.completed {
-                        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
-                    } else {
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                    };
+                    let completion_style =
+                        self.style(if reminder.completed { "done" } else { "pending" });
 
                     let title_style = if reminder.completed {
-                        Style::default()
-                            .fg(Color::DarkGray)
-                            .add_modifier(Modifier::CROSSED_OUT | Modifier::ITALIC)
+                        self.style("item_completed")
                     } else if is_selected {
-                        Style::default()
-                            .fg(Color::White)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("item_selected")
                     } else {
-                        Style::default()
-                            .fg(Color::LightBlue)
-                            .add_modifier(Modifier::BOLD)
+                        self.style("item")
                     };
 
-                    let priority_symbol = match reminder.priority {
-                        1 => "🔴",
-                        2 => "🟡",
-                        3 => "🟢",
-                        _ => "",
-                    };
+                    let priority_symbol = priority_marker(reminder.priority);
 
                     let mut lines = vec![Line::from(vec![
                         Span::styled(
                             if is_selected { "▶ " } else { "  " },
-                            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                            self.style("cursor")
                         ),
                         Span::styled(completion_symbol, completion_style),
                         Span::raw("  "),
@@ -310,6 +522,12 @@ impl RemindersComponent {
                         } else {
                             Span::raw("")
                         },
+                        match self.list_names.get(&reminder.id) {
+                            Some(list) => {
+                                Span::styled(format!("  {list}"), self.style("list_name"))
+                            }
+                            None => Span::raw(""),
+                        },
                     ])];
 
                     if let Some(notes) = &reminder.notes {
@@ -318,11 +536,7 @@ impl RemindersComponent {
                                 Span::raw("    "),
                                 Span::styled(
                                     format!("💭 {notes}"),
-                                    Style::default().fg(if is_selected {
-                                        Color::Gray
-                                    } else {
-                                        Color::DarkGray
-                                    }).add_modifier(Modifier::ITALIC)
+                                    self.style(if is_selected { "notes_selected" } else { "notes" })
                                 )
                             ]));
                         }
@@ -331,25 +545,18 @@ impl RemindersComponent {
                     if let Some(due_date) = &reminder.due_date {
                         lines.push(Line::from(vec![
                             Span::raw("    "),
-                            Span::styled("📅 Due: ", Style::default().fg(Color::Yellow)),
-                            Span::styled(
-                                due_date,
-                                Style::default()
-                                    .fg(Color::Yellow)
-                                    .add_modifier(Modifier::BOLD)
-                            ),
+                            Span::styled("📅 Due: ", self.style("due_label")),
+                            Span::styled(due_date, self.style("due")),
                         ]));
                     }
 
                     // Add spacing between items
-                    if i < self.reminders.len() - 1 {
+                    if i < visible.len() - 1 {
                         lines.push(Line::from(""));
                     }
 
                     let style = if is_selected {
-                        Style::default()
-                            .bg(Color::DarkGray)
-                            .fg(Color::White)
+                        self.style("selected")
                     } else {
                         Style::default()
                     };
@@ -365,46 +572,61 @@ impl RemindersComponent {
                         .border_type(BorderType::Rounded)
                         .title(Span::styled(
                             format!(" 📋 {} ", self.list_title),
-                            Style::default()
-                                .fg(Color::Blue)
-                                .add_modifier(Modifier::BOLD)
+                            self.style("title")
                         ))
                         .title_alignment(Alignment::Center)
-                        .style(Style::default().fg(Color::Blue))
+                        .style(self.style("border"))
                         .padding(Padding::horizontal(1))
                 )
-                .highlight_style(
-                    Style::default()
-                        .bg(Color::DarkGray)
-                        .add_modifier(Modifier::BOLD)
-                );
+                .highlight_style(self.style("highlight"));
 
             f.render_stateful_widget(list_widget, main_layout[0], &mut self.list_state);
         }
 
         // Enhanced instructions at the bottom
-        let instructions = Paragraph::new(vec![Line::from(vec![
-            Span::styled("↑↓", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" or ", Style::default().fg(Color::Gray)),
-            Span::styled("j/k", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" navigate  ", Style::default().fg(Color::Gray)),
-            Span::styled("Space", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" toggle  ", Style::default().fg(Color::Gray)),
-            Span::styled("Esc", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" back  ", Style::default().fg(Color::Gray)),
-            Span::styled("q", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD).bg(Color::DarkGray)),
-            Span::styled(" quit", Style::default().fg(Color::Gray)),
-        ])])
+        let navigate = format!("{}/{}", self.key(Action::Up), self.key(Action::Down));
+        let controls = match (self.confirm_delete, self.selected()) {
+            (true, Some(reminder)) => vec![
+                Span::styled(format!("Delete \"{}\"? ", reminder.title), self.style("warning")),
+                Span::styled("y", self.style("key_quit")),
+                Span::styled(" delete  ", self.style("hint")),
+                Span::styled("any other key", self.style("key_back")),
+                Span::styled(" keep it", self.style("hint")),
+            ],
+            _ if self.search.as_ref().is_some_and(|search| search.editing) => vec![
+                Span::styled("enter", self.style("key_confirm")),
+                Span::styled(" done  ", self.style("hint")),
+                Span::styled("esc", self.style("key_back")),
+                Span::styled(" clear search", self.style("hint")),
+            ],
+            _ => vec![
+                Span::styled(navigate, self.style("key_navigate")),
+                Span::styled(" navigate  ", self.style("hint")),
+                Span::styled(self.key(Action::ToggleCompleted), self.style("key_confirm")),
+                Span::styled(" toggle  ", self.style("hint")),
+                Span::styled(self.key(Action::Create), self.style("key_confirm")),
+                Span::styled(" new  ", self.style("hint")),
+                Span::styled(self.key(Action::Delete), self.style("key_quit")),
+                Span::styled(" delete  ", self.style("hint")),
+                Span::styled(self.key(Action::Search), self.style("key_navigate")),
+                Span::styled(" search  ", self.style("hint")),
+                Span::styled(self.key(Action::Back), self.style("key_back")),
+                Span::styled(" back  ", self.style("hint")),
+                Span::styled(self.key(Action::Quit), self.style("key_quit")),
+                Span::styled(" quit", self.style("hint")),
+            ],
+        };
+        let instructions = Paragraph::new(vec![Line::from(controls)])
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .title(Span::styled(
                     " Controls ",
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    self.style("controls_title")
                 ))
                 .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Yellow))
+                .style(self.style("controls"))
         )
         .alignment(Alignment::Center);
 
@@ -423,51 +645,62 @@ impl Component for RemindersComponent {
         Ok(())
     }
 
-    fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        match action {
-            Action::LoadReminders(list_id) => {
-                if list_id == self.list_id {
-                    // This will be handled by the parent component that has access to EventKit
-                }
-                Ok(None)
-            }
-            _ => Ok(None),
-        }
+    fn is_editing(&self) -> bool {
+        self.confirm_delete || self.search.as_ref().is_some_and(|search| search.editing)
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
-        if self.loading {
-            return Ok(None);
+        if self.confirm_delete {
+            self.confirm_delete = false;
+            if key.code == KeyCode::Char('y') {
+                return Ok(self.delete_selected());
+            }
+        } else if self.is_editing() {
+            self.handle_search_key(key);
         }
+        Ok(None)
+    }
 
-        if self.error.is_some() {
-            match key.code {
-                KeyCode::Char('r') => {
-                    return Ok(Some(Action::LoadReminders(self.list_id.clone())));
-                }
-                KeyCode::Esc => {
-                    return Ok(Some(Action::Back));
-                }
-                _ => {}
-            }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if self.loading {
             return Ok(None);
         }
 
-        match key.code {
-            KeyCode::Up | KeyCode::Char('k') => {
+        match action {
+            // Loading is handled by `App`, which has the reminder source
+            Action::Refresh => Ok(Some(match &self.list_id {
+                Some(list_id) => Action::LoadReminders(list_id.clone()),
+                None => Action::GlobalSearch,
+            })),
+            _ if self.error.is_some() => Ok(None),
+            Action::Up => {
                 self.move_up();
                 Ok(None)
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            Action::Down => {
                 self.move_down();
                 Ok(None)
             }
-            KeyCode::Char(' ') => {
-                self.toggle_completed();
+            Action::First => {
+                self.move_to(0);
+                Ok(None)
+            }
+            Action::Last => {
+                self.move_to(usize::MAX);
+                Ok(None)
+            }
+            Action::ToggleCompleted => Ok(self.toggle_completed()),
+            Action::Delete => {
+                self.confirm_delete = self.selected().is_some();
+                Ok(None)
+            }
+            Action::Search => {
+                match &mut self.search {
+                    Some(search) => search.editing = true,
+                    None => self.start_search(),
+                }
                 Ok(None)
             }
-            KeyCode::Esc => Ok(Some(Action::Back)),
-            KeyCode::Char('q') => Ok(Some(Action::Quit)),
             _ => Ok(None),
         }
     }
@@ -484,3 +717,98 @@ impl Component for RemindersComponent {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::source::{NewReminder, local::LocalSource};
+
+    /// A local source with "Buy milk" and "Call mom" in the first list.
+    fn source(name: &str) -> (LocalSource, String) {
+        let dir = std::env::temp_dir().join(format!("rem-reminders-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let source = LocalSource::new(dir.join("reminders.json"));
+        let list_id = source.default_list_id().unwrap();
+        for (title, notes) in [("Buy milk", Some("oat")), ("Call mom", None)] {
+            source
+                .create_reminder(&NewReminder {
+                    title: title.to_string(),
+                    notes: notes.map(str::to_string),
+                    due_date: None,
+                    list_id: list_id.clone(),
+                    priority: 0,
+                })
+                .unwrap();
+        }
+        (source, list_id)
+    }
+
+    fn titles(component: &RemindersComponent) -> Vec<&str> {
+        component
+            .visible()
+            .into_iter()
+            .map(|i| component.reminders[i].title.as_str())
+            .collect()
+    }
+
+    fn press(component: &mut RemindersComponent, code: KeyCode) -> Option<Action> {
+        component.handle_key_event(code.into()).unwrap()
+    }
+
+    #[test]
+    fn test_priority_marker_reads_ical_ranges() {
+        let markers: Vec<_> = (0..=9).map(priority_marker).collect();
+        assert_eq!(
+            markers,
+            ["", "🔴", "🔴", "🔴", "🔴", "🟡", "🟢", "🟢", "🟢", "🟢"]
+        );
+    }
+
+    #[test]
+    fn test_search_filters_and_toggles_write_through() {
+        let (source, _) = source("search");
+        let mut component = RemindersComponent::all_lists();
+        component.set_reminders(Ok(fetch_reminders(&source, None, "").unwrap()));
+        assert!(component.is_editing());
+
+        for c in "OAT".chars() {
+            press(&mut component, KeyCode::Char(c));
+        }
+        assert_eq!(titles(&component), ["Buy milk"]);
+        press(&mut component, KeyCode::Enter);
+        assert!(!component.is_editing());
+
+        let milk = component.reminders[component.visible()[0]].id.clone();
+        assert_eq!(
+            component.update(Action::ToggleCompleted).unwrap(),
+            Some(Action::SetCompleted(milk, true))
+        );
+        assert!(component.clear_search());
+        assert_eq!(titles(&component), ["Buy milk", "Call mom"]);
+    }
+
+    #[test]
+    fn test_delete_waits_for_a_yes() {
+        let (source, list_id) = source("delete");
+        let reminders = fetch_reminders(&source, Some(&list_id), "Inbox").unwrap();
+        let mut component = RemindersComponent::new(list_id, "Inbox".to_string());
+        component.set_reminders(Ok(reminders));
+        component.update(Action::Down).unwrap();
+
+        component.update(Action::Delete).unwrap();
+        assert!(component.is_editing());
+        assert_eq!(press(&mut component, KeyCode::Char('q')), None);
+        assert_eq!(titles(&component), ["Buy milk", "Call mom"]);
+
+        component.update(Action::Delete).unwrap();
+        let mom = component.selected().unwrap().id.clone();
+        assert_eq!(
+            press(&mut component, KeyCode::Char('y')),
+            Some(Action::DeleteReminder(mom))
+        );
+        assert_eq!(titles(&component), ["Buy milk"]);
+        assert!(!component.is_editing());
+    }
+}
@@ -1,6 +1,11 @@
 #![allow(dead_code)] // Remove this once you start using the code
 
-use std::{collections::HashMap, env, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    path::PathBuf,
+    time::SystemTime,
+};
 
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -8,14 +13,28 @@ use derive_deref::{Deref, DerefMut};
 use directories::ProjectDirs;
 use lazy_static::lazy_static;
 use ratatui::style::{Color, Modifier, Style};
-use serde::{Deserialize, de::Deserializer};
-use tracing::error;
+use serde::{
+    Deserialize, Serialize, Serializer,
+    de::{self, Deserializer},
+};
+use tracing::{error, warn};
 
 use crate::{action::Action, app::Mode};
 
+pub mod check;
+
 const CONFIG: &str = include_str!("../.config/config.json5");
 
-#[derive(Clone, Debug, Deserialize, Default)]
+/// The files in the config directory that are read, in order.
+const CONFIG_FILES: [(&str, config::FileFormat); 5] = [
+    ("config.json5", config::FileFormat::Json5),
+    ("config.json", config::FileFormat::Json),
+    ("config.yaml", config::FileFormat::Yaml),
+    ("config.toml", config::FileFormat::Toml),
+    ("config.ini", config::FileFormat::Ini),
+];
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct AppConfig {
     #[serde(default)]
     pub data_dir: PathBuf,
@@ -23,7 +42,7 @@ pub struct AppConfig {
     pub config_dir: PathBuf,
 }
 
-#[derive(Clone, Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Config {
     #[serde(default, flatten)]
     pub config: AppConfig,
@@ -54,15 +73,8 @@ impl Config {
             .set_default("data_dir", data_dir.to_str().unwrap())?
             .set_default("config_dir", config_dir.to_str().unwrap())?;
 
-        let config_files = [
-            ("config.json5", config::FileFormat::Json5),
-            ("config.json", config::FileFormat::Json),
-            ("config.yaml", config::FileFormat::Yaml),
-            ("config.toml", config::FileFormat::Toml),
-            ("config.ini", config::FileFormat::Ini),
-        ];
         let mut found_config = false;
-        for (file, format) in &config_files {
+        for (file, format) in &CONFIG_FILES {
             let source = config::File::from(config_dir.join(file))
                 .format(*format)
                 .required(false);
@@ -75,6 +87,18 @@ impl Config {
             error!("No configuration file found. Application may not behave as expected");
         }
 
+        // Refuse a config with mistakes rather than quietly ignoring parts of it
+        let (errors, warnings): (Vec<_>, Vec<_>) = check::check_dir(&config_dir)
+            .into_iter()
+            .partition(|diagnostic| diagnostic.severity == check::Severity::Error);
+        for warning in &warnings {
+            warn!("{warning}");
+        }
+        if !errors.is_empty() {
+            let errors: Vec<_> = errors.iter().map(ToString::to_string).collect();
+            return Err(config::ConfigError::Message(errors.join("\n")));
+        }
+
         let mut cfg: Self = builder.build()?.try_deserialize()?;
 
         for (mode, default_bindings) in default_config.keybindings.iter() {
@@ -96,6 +120,40 @@ impl Config {
     }
 }
 
+/// Notices when the config files change, by comparing their modification times and
+/// sizes each time it's asked.
+pub struct ConfigWatcher {
+    dir: PathBuf,
+    stamps: Vec<Option<(SystemTime, u64)>>,
+}
+
+impl ConfigWatcher {
+    pub fn new(dir: PathBuf) -> Self {
+        let stamps = Self::stamps(&dir);
+        Self { dir, stamps }
+    }
+
+    /// Whether a config file was created, changed or removed since the last call.
+    pub fn changed(&mut self) -> bool {
+        let stamps = Self::stamps(&self.dir);
+        if stamps == self.stamps {
+            return false;
+        }
+        self.stamps = stamps;
+        true
+    }
+
+    fn stamps(dir: &std::path::Path) -> Vec<Option<(SystemTime, u64)>> {
+        CONFIG_FILES
+            .iter()
+            .map(|(file, _)| {
+                let metadata = fs::metadata(dir.join(file)).ok()?;
+                Some((metadata.modified().ok()?, metadata.len()))
+            })
+            .collect()
+    }
+}
+
 pub fn get_data_dir() -> PathBuf {
     if let Some(s) = DATA_FOLDER.clone() {
         s
@@ -135,13 +193,50 @@ impl<'de> Deserialize<'de> for KeyBindings {
             .map(|(mode, inner_map)| {
                 let converted_inner_map = inner_map
                     .into_iter()
-                    .map(|(key_str, cmd)| (parse_key_sequence(&key_str).unwrap(), cmd))
+                    .map(|(key_str, cmd)| Ok((parse_key_sequence(&key_str)?, cmd)))
+                    .collect::<Result<_, String>>()
+                    .map_err(|e| de::Error::custom(format!("{e} in {mode:?} keybindings")))?;
+                Ok((mode, converted_inner_map))
+            })
+            .collect::<Result<_, D::Error>>()?;
+
+        Ok(KeyBindings(keybindings))
+    }
+}
+
+/// Written in the same form the config file uses, sorted so dumps are stable.
+impl Serialize for KeyBindings {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sorted: BTreeMap<String, BTreeMap<String, &Action>> = self
+            .iter()
+            .map(|(mode, bindings)| {
+                let bindings = bindings
+                    .iter()
+                    .map(|(keys, action)| (key_sequence_to_string(keys), action))
                     .collect();
-                (mode, converted_inner_map)
+                (format!("{mode:?}"), bindings)
             })
             .collect();
+        sorted.serialize(serializer)
+    }
+}
 
-        Ok(KeyBindings(keybindings))
+impl KeyBindings {
+    /// The shortest key sequence bound to `action` in `mode`, for showing in hints.
+    pub fn hint(&self, mode: Mode, action: &Action) -> Option<String> {
+        self.get(&mode)?
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(keys, _)| {
+                keys.iter()
+                    .map(key_event_to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
     }
 }
 
@@ -289,6 +384,13 @@ pub fn key_event_to_string(key_event: &KeyEvent) -> String {
     key
 }
 
+/// The inverse of [`parse_key_sequence`], e.g. `<g><g>` or `<ctrl-d>`.
+pub fn key_sequence_to_string(keys: &[KeyEvent]) -> String {
+    keys.iter()
+        .map(|key| format!("<{}>", key_event_to_string(key)))
+        .collect()
+}
+
 pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
     if raw.chars().filter(|c| *c == '>').count() != raw.chars().filter(|c| *c == '<').count() {
         return Err(format!("Unable to parse `{raw}`"));
@@ -340,6 +442,35 @@ impl<'de> Deserialize<'de> for Styles {
     }
 }
 
+impl Serialize for Styles {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sorted: BTreeMap<String, BTreeMap<&String, String>> = self
+            .iter()
+            .map(|(mode, styles)| {
+                let styles = styles
+                    .iter()
+                    .map(|(key, style)| (key, style_to_string(style)))
+                    .collect();
+                (format!("{mode:?}"), styles)
+            })
+            .collect();
+        sorted.serialize(serializer)
+    }
+}
+
+impl Styles {
+    /// The style named `key` in `mode`, or the terminal's default if it isn't configured.
+    pub fn style(&self, mode: Mode, key: &str) -> Style {
+        self.get(&mode)
+            .and_then(|styles| styles.get(key))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
 pub fn parse_style(line: &str) -> Style {
     let (foreground, background) =
         line.split_at(line.to_lowercase().find("on ").unwrap_or(line.len()));
@@ -357,13 +488,45 @@ pub fn parse_style(line: &str) -> Style {
     style
 }
 
+/// The inverse of [`parse_style`] for the styles it produces.
+pub fn style_to_string(style: &Style) -> String {
+    let mut words = Vec::new();
+    for (modifier, name) in [
+        (Modifier::BOLD, "bold"),
+        (Modifier::ITALIC, "italic"),
+        (Modifier::UNDERLINED, "underline"),
+        (Modifier::REVERSED, "inverse"),
+        (Modifier::CROSSED_OUT, "crossed_out"),
+    ] {
+        if style.add_modifier.contains(modifier) {
+            words.push(name.to_string());
+        }
+    }
+    if let Some(fg) = style.fg {
+        words.push(color_to_string(fg));
+    }
+    if let Some(bg) = style.bg {
+        words.push(format!("on {}", color_to_string(bg)));
+    }
+    words.join(" ")
+}
+
+fn color_to_string(color: Color) -> String {
+    match color {
+        Color::Indexed(c) => format!("color{c}"),
+        color => color.to_string().to_lowercase(),
+    }
+}
+
 fn process_color_string(color_str: &str) -> (String, Modifier) {
     let color = color_str
         .replace("grey", "gray")
         .replace("bright ", "")
         .replace("bold ", "")
+        .replace("italic ", "")
         .replace("underline ", "")
-        .replace("inverse ", "");
+        .replace("inverse ", "")
+        .replace("crossed_out ", "");
 
     let mut modifiers = Modifier::empty();
     if color_str.contains("underline") {
@@ -372,9 +535,15 @@ fn process_color_string(color_str: &str) -> (String, Modifier) {
     if color_str.contains("bold") {
         modifiers |= Modifier::BOLD;
     }
+    if color_str.contains("italic") {
+        modifiers |= Modifier::ITALIC;
+    }
     if color_str.contains("inverse") {
         modifiers |= Modifier::REVERSED;
     }
+    if color_str.contains("crossed_out") {
+        modifiers |= Modifier::CROSSED_OUT;
+    }
 
     (color, modifiers)
 }
@@ -511,6 +680,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_default_keybindings_cover_every_screen() -> Result<()> {
+        let c = Config::new()?;
+        let action = |mode, keys: &str| {
+            c.keybindings
+                .get(&mode)
+                .and_then(|bindings| bindings.get(&parse_key_sequence(keys).unwrap()))
+                .cloned()
+        };
+        assert_eq!(action(Mode::Permission, "<enter>"), Some(Action::Enter));
+        assert_eq!(action(Mode::Lists, "<g><g>"), Some(Action::First));
+        assert_eq!(action(Mode::Lists, "<shift-g>"), Some(Action::Last));
+        assert_eq!(
+            action(Mode::Reminders, "<space>"),
+            Some(Action::ToggleCompleted)
+        );
+        assert_eq!(action(Mode::Reminders, "<esc>"), Some(Action::Back));
+        assert_eq!(
+            c.keybindings.hint(Mode::Reminders, &Action::Up),
+            Some("k".to_string())
+        );
+        assert_eq!(c.keybindings.hint(Mode::Lists, &Action::Back), None);
+        assert_ne!(c.styles.style(Mode::Lists, "cursor"), Style::default());
+        assert_eq!(
+            c.styles.style(Mode::Lists, "no such style"),
+            Style::default()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dumped_config_reads_back_the_same() -> Result<()> {
+        let c = Config::new()?;
+        let dumped: Config = json5::from_str(&serde_json::to_string_pretty(&c)?)?;
+        assert_eq!(dumped.keybindings.0, c.keybindings.0);
+        assert_eq!(dumped.styles.0, c.styles.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_style_to_string_round_trips() {
+        for raw in [
+            "bold color12",
+            "italic crossed_out color8",
+            "bold on color8",
+            "color15 on color8",
+            "",
+        ] {
+            let style = parse_style(raw);
+            assert_eq!(style_to_string(&style), raw);
+            assert_eq!(parse_style(&style_to_string(&style)), style);
+        }
+    }
+
+    #[test]
+    fn test_invalid_keybinding_is_an_error() {
+        let result = json5::from_str::<KeyBindings>(r#"{"Lists": {"<ctrl-nope>": "Up"}}"#);
+        assert!(result.unwrap_err().to_string().contains("Lists"));
+    }
+
+    #[test]
+    fn test_config_watcher_sees_files_come_and_go() {
+        let dir = env::temp_dir().join(format!("rem-config-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let mut watcher = ConfigWatcher::new(dir.clone());
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("config.json5"), "{}").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("config.json5"), r#"{"keybindings": {}}"#).unwrap();
+        assert!(watcher.changed());
+
+        fs::remove_file(dir.join("config.json5")).unwrap();
+        assert!(watcher.changed());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_simple_keys() {
         assert_eq!(
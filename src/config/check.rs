@@ -0,0 +1,720 @@
+//! Checks config files against what `rem` understands, for `rem config check`, hot
+//! reloading and startup.
+//!
+//! Files are read into a plain value tree, so every format gets the same checks, and
+//! each problem is located by finding its key path in the file's text.
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Map, Value, json};
+use strum::VariantNames;
+
+use super::{CONFIG, CONFIG_FILES, parse_key_sequence};
+use crate::{action::Action, app::Mode};
+
+/// Top-level keys `Config` reads. `$schema` points editors at [`schema`].
+const TOP_LEVEL_KEYS: [&str; 5] = ["keybindings", "styles", "data_dir", "config_dir", "$schema"];
+
+/// Key names `parse_key_event` accepts besides single characters.
+const KEY_NAMES: [&str; 30] = [
+    "esc",
+    "enter",
+    "left",
+    "right",
+    "up",
+    "down",
+    "home",
+    "end",
+    "pageup",
+    "pagedown",
+    "backtab",
+    "backspace",
+    "delete",
+    "insert",
+    "f1",
+    "f2",
+    "f3",
+    "f4",
+    "f5",
+    "f6",
+    "f7",
+    "f8",
+    "f9",
+    "f10",
+    "f11",
+    "f12",
+    "space",
+    "hyphen",
+    "minus",
+    "tab",
+];
+
+const MODIFIER_WORDS: [&str; 6] = [
+    "bold",
+    "italic",
+    "underline",
+    "inverse",
+    "crossed_out",
+    "bright",
+];
+
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The config can't be used as written.
+    Error,
+    /// The config loads, but part of it has no effect.
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: PathBuf,
+    /// 1-based line and column of the offending key or value, when it could be found.
+    pub position: Option<(usize, usize)>,
+    /// Where in the config the problem is, e.g. `keybindings.Lists.<x>`.
+    pub path: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some((line, column)) = self.position {
+            write!(f, ":{line}:{column}")?;
+        }
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, ": {severity}: {}", self.message)?;
+        if !self.path.is_empty() {
+            write!(f, "\n  at {}", self.path)?;
+        }
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n  help: {suggestion}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check every config file present in `dir`.
+pub fn check_dir(dir: &Path) -> Vec<Diagnostic> {
+    CONFIG_FILES
+        .iter()
+        .map(|(file, format)| (dir.join(file), *format))
+        .filter(|(path, _)| path.exists())
+        .flat_map(|(path, format)| check_file(&path, format))
+        .collect()
+}
+
+/// The config files in `dir` that would be read.
+pub fn present_files(dir: &Path) -> Vec<PathBuf> {
+    CONFIG_FILES
+        .iter()
+        .map(|(file, _)| dir.join(file))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+pub fn check_file(path: &Path, format: config::FileFormat) -> Vec<Diagnostic> {
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => {
+            return vec![Checker::new(path, "").error(
+                &[],
+                format!("Can't read the file: {e}"),
+                None,
+            )];
+        }
+    };
+    let value = config::Config::builder()
+        .add_source(config::File::from(path).format(format))
+        .build()
+        .and_then(|config| config.try_deserialize::<Value>());
+    let mut checker = Checker::new(path, &source);
+    match value {
+        Ok(value) => checker.check(&value),
+        Err(e) => {
+            let diagnostic = checker.error(&[], format!("Can't parse the file: {e}"), None);
+            checker.diagnostics.push(diagnostic);
+        }
+    }
+    checker.diagnostics
+}
+
+struct Checker<'a> {
+    file: &'a Path,
+    source: &'a str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Checker<'a> {
+    fn new(file: &'a Path, source: &'a str) -> Self {
+        Self {
+            file,
+            source,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn diagnostic(
+        &self,
+        severity: Severity,
+        path: &[&str],
+        message: String,
+        suggestion: Option<String>,
+    ) -> Diagnostic {
+        Diagnostic {
+            severity,
+            file: self.file.to_path_buf(),
+            position: locate(self.source, path),
+            path: path.join("."),
+            message,
+            suggestion,
+        }
+    }
+
+    fn error(&self, path: &[&str], message: String, suggestion: Option<String>) -> Diagnostic {
+        self.diagnostic(Severity::Error, path, message, suggestion)
+    }
+
+    fn report(
+        &mut self,
+        severity: Severity,
+        path: &[&str],
+        message: String,
+        suggestion: Option<String>,
+    ) {
+        let diagnostic = self.diagnostic(severity, path, message, suggestion);
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn check(&mut self, value: &Value) {
+        let Some(root) = value.as_object() else {
+            self.report(
+                Severity::Error,
+                &[],
+                "Expected a table at the top level".to_string(),
+                None,
+            );
+            return;
+        };
+        // The config crate lowercases keys, so they match in any case
+        for (key, value) in root {
+            match key.to_ascii_lowercase().as_str() {
+                "keybindings" => self.check_modes("keybindings", value, Self::check_bindings),
+                "styles" => self.check_modes("styles", value, Self::check_styles),
+                lower if TOP_LEVEL_KEYS.contains(&lower) => {}
+                _ => self.report(
+                    Severity::Error,
+                    &[key],
+                    format!("Unknown setting \"{key}\""),
+                    did_you_mean(key, TOP_LEVEL_KEYS.iter().copied()),
+                ),
+            }
+        }
+    }
+
+    fn check_modes(
+        &mut self,
+        section: &str,
+        value: &Value,
+        check: fn(&mut Self, &str, &str, &Map<String, Value>),
+    ) {
+        let Some(modes) = value.as_object() else {
+            self.report(
+                Severity::Error,
+                &[section],
+                format!("Expected a table of screens in \"{section}\""),
+                None,
+            );
+            return;
+        };
+        for (mode, entries) in modes {
+            let Some(mode) = Mode::VARIANTS
+                .iter()
+                .copied()
+                .find(|variant| variant.eq_ignore_ascii_case(mode))
+            else {
+                self.report(
+                    Severity::Error,
+                    &[section, mode],
+                    format!("Unknown screen \"{mode}\""),
+                    did_you_mean(mode, Mode::VARIANTS.iter().copied())
+                        .or_else(|| Some(format!("screens are {}", Mode::VARIANTS.join(", ")))),
+                );
+                continue;
+            };
+            match entries.as_object() {
+                Some(entries) => check(self, section, mode, entries),
+                None => self.report(
+                    Severity::Error,
+                    &[section, mode],
+                    format!("Expected a table in \"{section}.{mode}\""),
+                    None,
+                ),
+            }
+        }
+    }
+
+    fn check_bindings(&mut self, section: &str, mode: &str, bindings: &Map<String, Value>) {
+        let mut seen: Vec<(Vec<crossterm::event::KeyEvent>, &str)> = Vec::new();
+        for (keys, action) in bindings {
+            let path = [section, mode, keys.as_str()];
+            let name = action.as_str().unwrap_or_default();
+            if !bindable_actions().any(|known| known.eq_ignore_ascii_case(name)) {
+                let mut value_path = path.to_vec();
+                value_path.push(name);
+                self.report(
+                    Severity::Error,
+                    &value_path,
+                    format!("Unknown action {action}"),
+                    did_you_mean(name, bindable_actions()),
+                );
+            }
+            match parse_key_sequence(keys) {
+                Ok(sequence) => {
+                    if let Some((_, first)) = seen.iter().find(|(other, _)| *other == sequence) {
+                        self.report(
+                            Severity::Error,
+                            &path,
+                            format!("\"{keys}\" is bound twice; \"{first}\" is the same keys"),
+                            Some("keep one of the two bindings".to_string()),
+                        );
+                    } else {
+                        seen.push((sequence, keys));
+                    }
+                }
+                Err(e) => self.report(
+                    Severity::Error,
+                    &path,
+                    format!("{e}: \"{keys}\""),
+                    key_suggestion(keys),
+                ),
+            }
+        }
+
+        // `App` tries a single key before sequences, so "<g>" hides "<g><g>"
+        for (sequence, keys) in seen.iter().filter(|(sequence, _)| sequence.len() > 1) {
+            if let Some((_, single)) = seen
+                .iter()
+                .find(|(other, _)| other.len() == 1 && other[0] == sequence[0])
+            {
+                self.report(
+                    Severity::Warning,
+                    &[section, mode, keys],
+                    format!(
+                        "\"{keys}\" can never be typed because \"{single}\" is bound on its own"
+                    ),
+                    Some(format!(
+                        "bind \"{single}\" to something else or start the sequence with another key"
+                    )),
+                );
+            }
+        }
+    }
+
+    fn check_styles(&mut self, section: &str, mode: &str, styles: &Map<String, Value>) {
+        let known = default_style_names(mode);
+        for (name, style) in styles {
+            let path = [section, mode, name.as_str()];
+            if !known.iter().any(|known| known == name) {
+                self.report(
+                    Severity::Warning,
+                    &path,
+                    format!("\"{name}\" isn't a style the {mode} screen uses"),
+                    did_you_mean(name, known.iter().map(String::as_str)),
+                );
+            }
+            let Some(style) = style.as_str() else {
+                self.report(
+                    Severity::Error,
+                    &path,
+                    format!("Expected a style string, found {style}"),
+                    None,
+                );
+                continue;
+            };
+            if let Err((word, suggestion)) = check_style(style) {
+                let mut value_path = path.to_vec();
+                value_path.push(style);
+                self.report(
+                    Severity::Error,
+                    &value_path,
+                    format!("Invalid color or modifier \"{word}\" in \"{style}\""),
+                    suggestion,
+                );
+            }
+        }
+    }
+}
+
+/// Check a style the way `parse_style` reads it, returning the first word it would
+/// ignore and a suggestion.
+fn check_style(style: &str) -> Result<(), (String, Option<String>)> {
+    let lower = style.to_lowercase();
+    let (foreground, background) = match lower.split_once("on ") {
+        Some((foreground, background)) => (foreground, Some(background)),
+        None => (lower.as_str(), None),
+    };
+    for (part, needs_color) in [
+        (foreground, false),
+        (background.unwrap_or_default(), background.is_some()),
+    ] {
+        let mut colors = 0;
+        for word in part.split_whitespace() {
+            if MODIFIER_WORDS.contains(&word) {
+                continue;
+            }
+            if !is_color(word) {
+                let suggestion = did_you_mean(word, COLOR_NAMES.iter().chain(&MODIFIER_WORDS).copied())
+                    .or_else(|| Some("colors are black, red, green, yellow, blue, magenta, cyan, white, grayN (0-23), colorN (0-255) or rgbRGB (0-5 each)".to_string()));
+                return Err((word.to_string(), suggestion));
+            }
+            colors += 1;
+        }
+        if colors > 1 || (needs_color && colors == 0) {
+            return Err((
+                part.trim().to_string(),
+                Some("give one color, and one after \"on\" for the background".to_string()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_color(word: &str) -> bool {
+    let word = word.replace("grey", "gray");
+    if COLOR_NAMES.contains(&word.as_str()) {
+        return true;
+    }
+    if let Some(index) = word.strip_prefix("color") {
+        return index.parse::<u8>().is_ok();
+    }
+    if let Some(level) = word.strip_prefix("gray") {
+        return level.parse::<u8>().is_ok_and(|level| level < 24);
+    }
+    if let Some(rgb) = word.strip_prefix("rgb") {
+        return rgb.len() == 3 && rgb.chars().all(|c| ('0'..='5').contains(&c));
+    }
+    false
+}
+
+fn key_suggestion(keys: &str) -> Option<String> {
+    let last = keys
+        .trim_matches(|c| c == '<' || c == '>')
+        .rsplit(['-', '>', '<'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    did_you_mean(&last, KEY_NAMES.iter().copied())
+        .or_else(|| Some("keys look like <q>, <enter>, <ctrl-d> or <g><g>".to_string()))
+}
+
+/// Actions that can be written as a plain string in a binding.
+fn bindable_actions() -> impl Iterator<Item = &'static str> {
+    Action::VARIANTS
+        .iter()
+        .copied()
+        .filter(|name| serde_json::from_value::<Action>(Value::from(*name)).is_ok())
+}
+
+/// The style names the default config gives `mode`.
+fn default_style_names(mode: &str) -> Vec<String> {
+    let defaults: Value = json5::from_str(CONFIG).unwrap_or_default();
+    let mut names: Vec<String> = defaults["styles"][mode]
+        .as_object()
+        .map(|styles| styles.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn did_you_mean<'a>(word: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let word = word.to_lowercase();
+    candidates
+        .map(|candidate| (edit_distance(&word, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, candidate)| *distance <= (candidate.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!("did you mean \"{candidate}\"?"))
+}
+
+/// Edits (insertions, deletions, substitutions and swaps of neighbors) from `a` to `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in distances[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// Find each segment of `path` in turn, so `Lists.<q>` finds the `<q>` inside the Lists
+/// table rather than the Home one. Returns the 1-based line and column of the last.
+fn locate(source: &str, path: &[&str]) -> Option<(usize, usize)> {
+    // Keys match in any case, and ASCII lowercasing keeps byte offsets
+    let lowered = source.to_ascii_lowercase();
+    let mut start = 0;
+    let mut found = None;
+    for segment in path.iter().filter(|segment| !segment.is_empty()) {
+        let offset = find_token(&lowered[start..], &segment.to_ascii_lowercase())?;
+        found = Some(start + offset);
+        start += offset + segment.len();
+    }
+    let offset = found?;
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    Some((line, column))
+}
+
+/// The first occurrence of `token` that isn't part of a longer word.
+fn find_token(haystack: &str, token: &str) -> Option<usize> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    haystack
+        .match_indices(token)
+        .map(|(index, _)| index)
+        .find(|&index| {
+            let before = haystack[..index].chars().next_back();
+            let after = haystack[index + token.len()..].chars().next();
+            !before.is_some_and(is_word) && !after.is_some_and(is_word)
+        })
+}
+
+/// A JSON Schema for config files, for editor completion and checking.
+pub fn schema() -> Value {
+    let actions: Vec<&str> = bindable_actions().collect();
+    let key_sequence = r"^(<[^<>]+>)+$|^[^<>]+$";
+    let mut keybindings = Map::new();
+    let mut styles = Map::new();
+    for mode in Mode::VARIANTS {
+        keybindings.insert(
+            mode.to_string(),
+            json!({
+                "type": "object",
+                "description": format!("Keys on the {mode} screen, e.g. \"<ctrl-d>\" or \"<g><g>\""),
+                "propertyNames": {"pattern": key_sequence},
+                "additionalProperties": {"enum": actions},
+            }),
+        );
+        let names: Map<String, Value> = default_style_names(mode)
+            .into_iter()
+            .map(|name| (name, json!({"$ref": "#/$defs/style"})))
+            .collect();
+        styles.insert(
+            mode.to_string(),
+            json!({
+                "type": "object",
+                "properties": names,
+                "additionalProperties": {"$ref": "#/$defs/style"},
+            }),
+        );
+    }
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "rem config",
+        "type": "object",
+        "properties": {
+            "$schema": {"type": "string"},
+            "data_dir": {"type": "string"},
+            "config_dir": {"type": "string"},
+            "keybindings": {
+                "type": "object",
+                "properties": keybindings,
+                "additionalProperties": false,
+            },
+            "styles": {
+                "type": "object",
+                "properties": styles,
+                "additionalProperties": false,
+            },
+        },
+        "additionalProperties": false,
+        "$defs": {
+            "style": {
+                "type": "string",
+                "description": "[bold] [italic] [underline] [inverse] [crossed_out] <color> [on <color>]; colors are black, red, green, yellow, blue, magenta, cyan, white, grayN, colorN or rgbRGB",
+                "examples": ["bold cyan", "color15 on color8", "italic crossed_out color8"],
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn check_json5(name: &str, source: &str) -> Vec<Diagnostic> {
+        let dir =
+            std::env::temp_dir().join(format!("rem-config-check-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json5");
+        std::fs::write(&path, source).unwrap();
+        let diagnostics = check_file(&path, config::FileFormat::Json5);
+        let _ = std::fs::remove_dir_all(&dir);
+        diagnostics
+    }
+
+    #[test]
+    fn test_default_config_is_clean() {
+        assert_eq!(check_json5("defaults", CONFIG), Vec::new());
+    }
+
+    #[test]
+    fn test_reports_each_problem_with_its_position() {
+        let source = r#"{
+  "keybinding": {},
+  "keybindings": {
+    "Home": { "<q>": "Quit" },
+    "Lsts": { "<q>": "Quit" },
+    "Lists": {
+      "<q>": "Qiut",
+      "<entr>": "Enter",
+      "<hyphen>": "Up",
+      "<minus>": "Down",
+      "<g>": "Up",
+      "<g><g>": "First"
+    }
+  },
+  "styles": {
+    "Lists": { "cursr": "bold cyan", "item": "bold purple on color8" }
+  }
+}"#;
+        let diagnostics = check_json5("problems", source);
+        let summary: Vec<_> = diagnostics
+            .iter()
+            .map(|d| {
+                (
+                    d.severity,
+                    d.position,
+                    d.path.as_str(),
+                    d.suggestion.clone(),
+                )
+            })
+            .collect();
+        let did_you_mean = |word: &str| Some(format!("did you mean \"{word}\"?"));
+        assert_eq!(
+            summary,
+            [
+                (Severity::Error, Some((2, 4)), "keybinding", did_you_mean("keybindings")),
+                (Severity::Error, Some((8, 8)), "keybindings.Lists.<entr>", did_you_mean("enter")),
+                (
+                    Severity::Error,
+                    Some((10, 8)),
+                    "keybindings.Lists.<minus>",
+                    Some("keep one of the two bindings".to_string())
+                ),
+                (Severity::Error, Some((7, 15)), "keybindings.Lists.<q>.Qiut", did_you_mean("Quit")),
+                (
+                    Severity::Warning,
+                    Some((12, 8)),
+                    "keybindings.Lists.<g><g>",
+                    Some("bind \"<g>\" to something else or start the sequence with another key".to_string())
+                ),
+                (Severity::Error, Some((5, 6)), "keybindings.lsts", did_you_mean("Lists")),
+                (Severity::Warning, Some((16, 17)), "styles.Lists.cursr", did_you_mean("cursor")),
+                (
+                    Severity::Error,
+                    Some((16, 47)),
+                    "styles.Lists.item.bold purple on color8",
+                    Some("colors are black, red, green, yellow, blue, magenta, cyan, white, grayN (0-23), colorN (0-255) or rgbRGB (0-5 each)".to_string())
+                ),
+            ]
+        );
+        assert_eq!(
+            diagnostics[3].to_string(),
+            format!(
+                "{}:7:15: error: Unknown action \"Qiut\"\n  at keybindings.Lists.<q>.Qiut\n  help: did you mean \"Quit\"?",
+                diagnostics[3].file.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_style() {
+        for style in [
+            "",
+            "bold",
+            "red",
+            "bold color12",
+            "color15 on color8",
+            "bold on gray3",
+            "rgb123",
+            "BOLD Red",
+        ] {
+            assert_eq!(check_style(style), Ok(()), "{style}");
+        }
+        assert_eq!(
+            check_style("bld red").unwrap_err().1,
+            Some("did you mean \"bold\"?".to_string())
+        );
+        for style in [
+            "color256",
+            "gray24",
+            "rgb678",
+            "red blue",
+            "red on",
+            "italic on bold",
+        ] {
+            assert!(check_style(style).is_err(), "{style}");
+        }
+    }
+
+    #[test]
+    fn test_published_schema_is_current() {
+        let published: Value =
+            serde_json::from_str(include_str!("../../.config/config.schema.json")).unwrap();
+        assert_eq!(
+            published,
+            schema(),
+            "regenerate with `rem config schema > .config/config.schema.json`"
+        );
+        let actions = &published["properties"]["keybindings"]["properties"]["Lists"]["additionalProperties"]
+            ["enum"];
+        assert!(
+            actions
+                .as_array()
+                .unwrap()
+                .contains(&json!("ToggleCompleted"))
+        );
+        assert!(
+            !actions
+                .as_array()
+                .unwrap()
+                .contains(&json!("LoadReminders"))
+        );
+    }
+}
@@ -1,7 +1,12 @@
 pub mod action;
 pub mod app;
 pub mod cli;
+pub mod commands;
 pub mod components;
 pub mod config;
-pub mod eventkit;
-pub mod tui;
\ No newline at end of file
+pub mod notify;
+pub mod output;
+#[cfg(unix)]
+pub mod rpc;
+pub mod source;
+pub mod tui;
@@ -8,11 +8,16 @@ use crate::app::App;
 mod action;
 mod app;
 mod cli;
+mod commands;
 mod components;
 mod config;
 mod errors;
-mod eventkit;
 mod logging;
+mod notify;
+mod output;
+#[cfg(unix)]
+mod rpc;
+mod source;
 mod tui;
 
 #[tokio::main]
@@ -20,6 +25,13 @@ async fn main() -> Result<()> {
     crate::errors::init()?;
     crate::logging::init()?;
 
+    let args = Cli::parse();
+
+    // Subcommands run without the TUI, so they work in pipes and scripts
+    if let Some(command) = args.command {
+        std::process::exit(commands::run(command).await);
+    }
+
     // Check if we're running in a proper terminal
     if !std::io::IsTerminal::is_terminal(&std::io::stderr()) {
         eprintln!("Error: rem must be run in a terminal environment.");
@@ -28,7 +40,6 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
-    let args = Cli::parse();
     let mut app = App::new(args.tick_rate, args.frame_rate)?;
     app.run().await?;
     Ok(())
@@ -0,0 +1,537 @@
+//! `rem watch`: a background watcher that notifies when reminders come due.
+//!
+//! Every poll, reminders with a due date are checked against the due time and each lead
+//! time. What has already been announced (and what's snoozed) is kept in a state file in
+//! the data directory, so restarting the watcher doesn't repeat notifications.
+
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Arc,
+};
+
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use clap::ValueEnum;
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::{
+    config::get_data_dir,
+    source::{Reminder, ReminderSource},
+};
+
+/// Alerts that were missed by more than this (the watcher wasn't running, or the machine
+/// was asleep) are recorded as fired without being shown.
+const MISSED_GRACE: Duration = Duration::hours(1);
+
+/// All-day reminders have no time, so they are announced at this time on the day.
+const ALL_DAY_ALERT_TIME: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+/// Fired alerts are forgotten once their due date is this far in the past.
+const STATE_RETENTION: Duration = Duration::days(7);
+
+pub fn state_path() -> PathBuf {
+    get_data_dir().join("watch-state.json")
+}
+
+/// Parse durations like `30s`, `15m`, `2h` or `1d`.
+pub fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let invalid = || format!("Invalid duration '{input}'; use e.g. 15m, 2h or 1d");
+    let (number, unit) = input.split_at(input.len().saturating_sub(1));
+    let number: i64 = number.parse().map_err(|_| invalid())?;
+    if number < 0 {
+        return Err(invalid());
+    }
+    match unit {
+        "s" => Ok(Duration::seconds(number)),
+        "m" => Ok(Duration::minutes(number)),
+        "h" => Ok(Duration::hours(number)),
+        "d" => Ok(Duration::days(number)),
+        _ => Err(invalid()),
+    }
+}
+
+fn describe_duration(duration: Duration) -> String {
+    let plural = |n: i64, unit: &str| format!("{n} {unit}{}", if n == 1 { "" } else { "s" });
+    if duration.num_days() > 0 && duration.num_hours() % 24 == 0 {
+        plural(duration.num_days(), "day")
+    } else if duration.num_hours() > 0 && duration.num_minutes() % 60 == 0 {
+        plural(duration.num_hours(), "hour")
+    } else {
+        plural(duration.num_minutes().max(1), "minute")
+    }
+}
+
+/// A local due time, or an all-day date at [`ALL_DAY_ALERT_TIME`].
+fn parse_due(due_date: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(due_date, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(ALL_DAY_ALERT_TIME))
+        })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub reminder_id: String,
+    pub title: String,
+    pub body: String,
+    pub list: String,
+    pub due: NaiveDateTime,
+}
+
+/// Announced alerts and snoozes, persisted between runs.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WatchState {
+    /// Alert keys mapped to the due date they were for.
+    #[serde(default)]
+    fired: BTreeMap<String, NaiveDateTime>,
+    /// Reminder ids mapped to when their snooze ends.
+    #[serde(default)]
+    snoozed: BTreeMap<String, NaiveDateTime>,
+}
+
+impl WatchState {
+    /// Load the state, starting afresh if the file is missing or unreadable.
+    pub fn load(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                warn!("Ignoring unreadable watch state {}: {e}", path.display());
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write then rename, so a crash can't leave a truncated file behind
+        let temp = path.with_extension("json.tmp");
+        std::fs::write(&temp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    pub fn snooze(&mut self, reminder_id: &str, until: NaiveDateTime) {
+        self.snoozed.insert(reminder_id.to_string(), until);
+    }
+
+    /// Work out which notifications are due at `now` and record them as fired.
+    ///
+    /// Each reminder gets at most one notification per call: the most recent of its
+    /// alerts that became due since the last call.
+    pub fn take_due(
+        &mut self,
+        reminders: &[(Reminder, String)],
+        leads: &[Duration],
+        now: NaiveDateTime,
+    ) -> Vec<Notification> {
+        let mut leads = leads.to_vec();
+        leads.push(Duration::zero());
+        leads.sort();
+        leads.dedup();
+
+        let mut notifications = Vec::new();
+        for (reminder, list) in reminders {
+            if reminder.completed {
+                self.snoozed.remove(&reminder.id);
+                continue;
+            }
+            let Some(due) = reminder.due_date.as_deref().and_then(parse_due) else {
+                continue;
+            };
+
+            let snoozed_until = self.snoozed.get(&reminder.id).copied();
+            if snoozed_until.is_some_and(|until| now < until) {
+                continue;
+            }
+
+            // Leads are sorted, so the first one found is the most recent alert
+            let mut announce = None;
+            for lead in &leads {
+                let fire_at = due - *lead;
+                let key = format!(
+                    "{}|{}|{}|{}",
+                    reminder.id,
+                    reminder.title,
+                    due,
+                    lead.num_minutes()
+                );
+                if fire_at > now || self.fired.contains_key(&key) {
+                    continue;
+                }
+                self.fired.insert(key, due);
+                if announce.is_none() && now - fire_at <= MISSED_GRACE {
+                    announce = Some(*lead);
+                }
+            }
+
+            let body = match (snoozed_until, announce) {
+                (Some(_), _) => Some(format!("Snoozed, due {}", due.format("%a %H:%M"))),
+                (None, Some(lead)) if lead.is_zero() => Some("Due now".to_string()),
+                (None, Some(lead)) => Some(format!("Due in {}", describe_duration(lead))),
+                (None, None) => None,
+            };
+            self.snoozed.remove(&reminder.id);
+            if let Some(body) = body {
+                notifications.push(Notification {
+                    reminder_id: reminder.id.clone(),
+                    title: reminder.title.clone(),
+                    body: format!("{body} · {list}"),
+                    list: list.clone(),
+                    due,
+                });
+            }
+        }
+
+        self.fired.retain(|_, due| now - *due < STATE_RETENTION);
+        notifications
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum NotifierKind {
+    /// A desktop notification through the freedesktop D-Bus service
+    Dbus,
+    /// An OSC 9 escape and a bell on the terminal running the watcher
+    #[default]
+    Terminal,
+    /// Run a shell command with REM_TITLE, REM_BODY, REM_ID, REM_LIST and REM_DUE set
+    Command,
+}
+
+pub trait Notifier {
+    fn notify(&mut self, notification: &Notification) -> Result<()>;
+}
+
+pub fn notifier(kind: NotifierKind, command: Option<String>) -> Result<Box<dyn Notifier>> {
+    Ok(match kind {
+        NotifierKind::Dbus => Box::new(DbusNotifier),
+        NotifierKind::Terminal => Box::new(TerminalNotifier {
+            out: std::io::stdout(),
+        }),
+        NotifierKind::Command => Box::new(CommandNotifier {
+            command: command.ok_or_else(|| eyre!("The command notifier needs --command"))?,
+        }),
+    })
+}
+
+/// Sends `org.freedesktop.Notifications.Notify` through `gdbus`.
+pub struct DbusNotifier;
+
+impl DbusNotifier {
+    fn args(notification: &Notification) -> Vec<String> {
+        // gdbus parses each argument as GVariant text
+        let string =
+            |value: &str| format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"));
+        vec![
+            "call".to_string(),
+            "--session".to_string(),
+            "--dest=org.freedesktop.Notifications".to_string(),
+            "--object-path=/org/freedesktop/Notifications".to_string(),
+            "--method=org.freedesktop.Notifications.Notify".to_string(),
+            string("rem"),
+            "uint32 0".to_string(),
+            string(""),
+            string(&notification.title),
+            string(&notification.body),
+            "@as []".to_string(),
+            "@a{sv} {}".to_string(),
+            "int32 -1".to_string(),
+        ]
+    }
+}
+
+impl Notifier for DbusNotifier {
+    fn notify(&mut self, notification: &Notification) -> Result<()> {
+        let output = std::process::Command::new("gdbus")
+            .args(Self::args(notification))
+            .stdout(Stdio::null())
+            .output()
+            .map_err(|e| eyre!("Couldn't run gdbus: {e}"))?;
+        if !output.status.success() {
+            return Err(eyre!(
+                "D-Bus notification failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub struct TerminalNotifier<W: Write> {
+    out: W,
+}
+
+impl<W: Write> TerminalNotifier<W> {
+    /// OSC 9 shows a desktop notification in terminals that support it (iTerm2, kitty,
+    /// WezTerm...); the trailing bell covers the ones that don't.
+    fn escape(notification: &Notification) -> String {
+        let text = format!("{}: {}", notification.title, notification.body)
+            .replace(|c: char| c.is_control(), " ");
+        format!("\x1b]9;{text}\x07\x07")
+    }
+}
+
+impl<W: Write> Notifier for TerminalNotifier<W> {
+    fn notify(&mut self, notification: &Notification) -> Result<()> {
+        write!(self.out, "{}", Self::escape(notification))?;
+        writeln!(
+            self.out,
+            "{}  {} ({})",
+            Local::now().format("%H:%M"),
+            notification.title,
+            notification.body
+        )?;
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+pub struct CommandNotifier {
+    command: String,
+}
+
+impl Notifier for CommandNotifier {
+    fn notify(&mut self, notification: &Notification) -> Result<()> {
+        let status = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(&self.command)
+            .env("REM_TITLE", &notification.title)
+            .env("REM_BODY", &notification.body)
+            .env("REM_ID", &notification.reminder_id)
+            .env("REM_LIST", &notification.list)
+            .env(
+                "REM_DUE",
+                notification.due.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            )
+            .stdin(Stdio::null())
+            .status()?;
+        if !status.success() {
+            return Err(eyre!("Notification command exited with {status}"));
+        }
+        Ok(())
+    }
+}
+
+fn fetch_reminders(source: &dyn ReminderSource) -> Result<Vec<(Reminder, String)>> {
+    let mut reminders = Vec::new();
+    for list in source.get_reminder_lists()? {
+        for reminder in source.get_reminders_for_list(&list.id)? {
+            reminders.push((reminder, list.title.clone()));
+        }
+    }
+    Ok(reminders)
+}
+
+/// Poll every `interval` until interrupted, sending notifications through `notifier`.
+pub async fn watch(
+    source: Arc<dyn ReminderSource>,
+    mut notifier: Box<dyn Notifier>,
+    leads: &[Duration],
+    interval: std::time::Duration,
+) -> Result<()> {
+    let path = state_path();
+    info!("Watching due dates, state in {}", path.display());
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+
+        let reminders = match fetch_reminders(source.as_ref()) {
+            Ok(reminders) => reminders,
+            Err(e) => {
+                warn!("Couldn't fetch reminders: {e}");
+                continue;
+            }
+        };
+
+        // Reload each time to pick up snoozes made with `rem snooze`
+        let mut state = WatchState::load(&path);
+        let notifications = state.take_due(&reminders, leads, Local::now().naive_local());
+        for notification in &notifications {
+            debug!("Notifying: {notification:?}");
+            if let Err(e) = notifier.notify(notification) {
+                warn!("Notification for '{}' failed: {e}", notification.title);
+            }
+        }
+        state.save(&path)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 15)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn due(id: &str, due_date: &str) -> (Reminder, String) {
+        (
+            Reminder {
+                id: id.to_string(),
+                title: format!("Title of {id}"),
+                notes: None,
+                completed: false,
+                priority: 0,
+                due_date: Some(due_date.to_string()),
+            },
+            "Work".to_string(),
+        )
+    }
+
+    fn bodies(notifications: &[Notification]) -> Vec<&str> {
+        notifications.iter().map(|n| n.body.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("15m"), Ok(Duration::minutes(15)));
+        assert_eq!(parse_duration("2h"), Ok(Duration::hours(2)));
+        assert_eq!(parse_duration("1d"), Ok(Duration::days(1)));
+        assert!(parse_duration("15").is_err());
+        assert!(parse_duration("-5m").is_err());
+        assert!(parse_duration("m").is_err());
+    }
+
+    #[test]
+    fn test_lead_times_fire_once_each() {
+        let reminders = vec![due("r1", "2025-01-15T10:00:00")];
+        let leads = [Duration::minutes(15), Duration::hours(1)];
+        let mut state = WatchState::default();
+
+        assert!(state.take_due(&reminders, &leads, at(8, 30)).is_empty());
+        assert_eq!(
+            bodies(&state.take_due(&reminders, &leads, at(9, 0))),
+            ["Due in 1 hour · Work"]
+        );
+        assert!(state.take_due(&reminders, &leads, at(9, 1)).is_empty());
+        assert_eq!(
+            bodies(&state.take_due(&reminders, &leads, at(9, 46))),
+            ["Due in 15 minutes · Work"]
+        );
+        assert_eq!(
+            bodies(&state.take_due(&reminders, &leads, at(10, 0))),
+            ["Due now · Work"]
+        );
+        assert!(state.take_due(&reminders, &leads, at(10, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_only_latest_alert_and_no_stale_alerts() {
+        let leads = [Duration::minutes(15), Duration::hours(1)];
+        let mut state = WatchState::default();
+
+        // Started 10 minutes before due: the 1h alert is skipped in favour of the 15m one
+        let reminders = vec![due("r1", "2025-01-15T10:00:00")];
+        assert_eq!(
+            bodies(&state.take_due(&reminders, &leads, at(9, 50))),
+            ["Due in 15 minutes · Work"]
+        );
+
+        // Long overdue when first seen: nothing to announce
+        let reminders = vec![due("r2", "2025-01-15T06:00:00")];
+        assert!(state.take_due(&reminders, &leads, at(9, 50)).is_empty());
+    }
+
+    #[test]
+    fn test_completed_and_all_day() {
+        let mut state = WatchState::default();
+        let mut done = due("r1", "2025-01-15T10:00:00");
+        done.0.completed = true;
+        let all_day = due("r2", "2025-01-15");
+
+        let notifications = state.take_due(&[done, all_day], &[], at(9, 0));
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].reminder_id, "r2");
+        assert_eq!(notifications[0].due, at(9, 0));
+    }
+
+    #[test]
+    fn test_snooze() {
+        let reminders = vec![due("r1", "2025-01-15T10:00:00")];
+        let mut state = WatchState::default();
+        assert_eq!(state.take_due(&reminders, &[], at(10, 0)).len(), 1);
+
+        state.snooze("r1", at(10, 10));
+        assert!(state.take_due(&reminders, &[], at(10, 5)).is_empty());
+        assert_eq!(
+            bodies(&state.take_due(&reminders, &[], at(10, 10))),
+            ["Snoozed, due Wed 10:00 · Work"]
+        );
+        assert!(state.take_due(&reminders, &[], at(10, 11)).is_empty());
+        assert!(state.snoozed.is_empty());
+    }
+
+    #[test]
+    fn test_rescheduled_reminder_alerts_again() {
+        let mut state = WatchState::default();
+        assert_eq!(
+            state
+                .take_due(&[due("r1", "2025-01-15T10:00:00")], &[], at(10, 0))
+                .len(),
+            1
+        );
+        assert_eq!(
+            state
+                .take_due(&[due("r1", "2025-01-15T11:00:00")], &[], at(11, 0))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = std::env::temp_dir().join(format!("rem-watch-test-{}", std::process::id()));
+        let path = dir.join("watch-state.json");
+        let mut state = WatchState::default();
+        state.take_due(&[due("r1", "2025-01-15T10:00:00")], &[], at(10, 0));
+        state.snooze("r2", at(12, 0));
+
+        state.save(&path).unwrap();
+        assert_eq!(WatchState::load(&path), state);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(WatchState::load(&path), WatchState::default());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_terminal_and_dbus_formatting() {
+        let notification = Notification {
+            reminder_id: "r1".to_string(),
+            title: "Call O'Brien".to_string(),
+            body: "Due now · Work".to_string(),
+            list: "Work".to_string(),
+            due: at(10, 0),
+        };
+
+        let mut out = Vec::new();
+        TerminalNotifier { out: &mut out }
+            .notify(&notification)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\x1b]9;Call O'Brien: Due now · Work\x07\x07"));
+
+        let args = DbusNotifier::args(&notification);
+        assert_eq!(args[8], r"'Call O\'Brien'");
+        assert_eq!(args[9], "'Due now · Work'");
+    }
+}
@@ -0,0 +1,479 @@
+//! Rendering of command-line query results as tables, JSON, CSV and friends.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use clap::ValueEnum;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+use crate::source::{Reminder, ReminderList};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for reading in a terminal
+    #[default]
+    Table,
+    /// A single JSON array
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    Csv,
+    Tsv,
+    /// A GitHub-flavoured Markdown table
+    Markdown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Field {
+    Id,
+    Title,
+    Notes,
+    Completed,
+    Priority,
+    Due,
+    List,
+    Color,
+    Count,
+}
+
+impl Field {
+    pub fn name(self) -> &'static str {
+        match self {
+            Field::Id => "id",
+            Field::Title => "title",
+            Field::Notes => "notes",
+            Field::Completed => "completed",
+            Field::Priority => "priority",
+            Field::Due => "due",
+            Field::List => "list",
+            Field::Color => "color",
+            Field::Count => "count",
+        }
+    }
+}
+
+/// What a query returns, which decides the fields that are available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordKind {
+    List,
+    Reminder,
+}
+
+impl RecordKind {
+    fn available_fields(self) -> &'static [Field] {
+        match self {
+            RecordKind::List => &[Field::Id, Field::Title, Field::Color, Field::Count],
+            RecordKind::Reminder => &[
+                Field::Id,
+                Field::Title,
+                Field::Notes,
+                Field::Completed,
+                Field::Priority,
+                Field::Due,
+                Field::List,
+            ],
+        }
+    }
+
+    fn default_fields(self) -> &'static [Field] {
+        match self {
+            RecordKind::List => &[Field::Id, Field::Title, Field::Count],
+            RecordKind::Reminder => &[
+                Field::Id,
+                Field::Completed,
+                Field::Title,
+                Field::Due,
+                Field::List,
+            ],
+        }
+    }
+
+    /// The fields to print: `requested` if given, otherwise the defaults for this kind.
+    pub fn select_fields(self, requested: &[Field]) -> Result<Vec<Field>, String> {
+        if requested.is_empty() {
+            return Ok(self.default_fields().to_vec());
+        }
+        let available = self.available_fields();
+        match requested.iter().find(|field| !available.contains(field)) {
+            Some(field) => Err(format!(
+                "Field '{}' is not available here; choose from {}",
+                field.name(),
+                available
+                    .iter()
+                    .map(|field| field.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            None => Ok(requested.to_vec()),
+        }
+    }
+}
+
+/// One row of output, with every field of its kind filled in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record(Vec<(Field, Value)>);
+
+impl Record {
+    pub fn from_list(list: &ReminderList) -> Self {
+        Self(vec![
+            (Field::Id, Value::from(list.id.as_str())),
+            (Field::Title, Value::from(list.title.as_str())),
+            (Field::Color, Value::from(list.color.as_str())),
+            (Field::Count, Value::from(list.reminder_count)),
+        ])
+    }
+
+    pub fn from_reminder(reminder: &Reminder, list_name: &str) -> Self {
+        Self(vec![
+            (Field::Id, Value::from(reminder.id.as_str())),
+            (Field::Title, Value::from(reminder.title.as_str())),
+            (Field::Notes, Value::from(reminder.notes.as_deref())),
+            (Field::Completed, Value::from(reminder.completed)),
+            (Field::Priority, Value::from(reminder.priority)),
+            (
+                Field::Due,
+                Value::from(reminder.due_date.as_deref().map(iso_8601)),
+            ),
+            (Field::List, Value::from(list_name)),
+        ])
+    }
+
+    fn get(&self, field: Field) -> &Value {
+        self.0
+            .iter()
+            .find(|(f, _)| *f == field)
+            .map(|(_, value)| value)
+            .unwrap_or(&Value::Null)
+    }
+
+    fn project<'a>(&'a self, fields: &'a [Field]) -> Projected<'a> {
+        Projected {
+            record: self,
+            fields,
+        }
+    }
+}
+
+impl Serialize for Record {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (field, value) in &self.0 {
+            map.serialize_entry(field.name(), value)?;
+        }
+        map.end()
+    }
+}
+
+/// A record limited to the selected fields, serialized in field order.
+struct Projected<'a> {
+    record: &'a Record,
+    fields: &'a [Field],
+}
+
+impl Serialize for Projected<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in self.fields {
+            map.serialize_entry(field.name(), self.record.get(*field))?;
+        }
+        map.end()
+    }
+}
+
+/// Render `records` with the given fields. The result ends with a newline unless it's empty.
+pub fn render(format: OutputFormat, fields: &[Field], records: &[Record]) -> String {
+    match format {
+        OutputFormat::Json => {
+            let rows: Vec<_> = records.iter().map(|r| r.project(fields)).collect();
+            let mut out = serde_json::to_string_pretty(&rows).expect("records serialize");
+            out.push('\n');
+            out
+        }
+        OutputFormat::Ndjson => records
+            .iter()
+            .map(|r| serde_json::to_string(&r.project(fields)).expect("records serialize") + "\n")
+            .collect(),
+        OutputFormat::Csv => delimited(fields, records, ",", csv_escape),
+        OutputFormat::Tsv => delimited(fields, records, "\t", tsv_escape),
+        OutputFormat::Markdown => markdown(fields, records),
+        OutputFormat::Table => table(fields, records),
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn delimited(
+    fields: &[Field],
+    records: &[Record],
+    separator: &str,
+    escape: fn(&str) -> String,
+) -> String {
+    let mut out = fields
+        .iter()
+        .map(|field| field.name())
+        .collect::<Vec<_>>()
+        .join(separator);
+    out.push('\n');
+    for record in records {
+        let row: Vec<_> = fields
+            .iter()
+            .map(|field| escape(&text(record.get(*field))))
+            .collect();
+        out.push_str(&row.join(separator));
+        out.push('\n');
+    }
+    out
+}
+
+/// RFC 4180 quoting: wrap in quotes when needed and double embedded quotes.
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// TSV can't quote, so tabs and line breaks are written as escape sequences.
+fn tsv_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn markdown(fields: &[Field], records: &[Record]) -> String {
+    let cell = |value: &str| value.replace('|', "\\|").replace(['\r', '\n'], " ");
+    let mut out = format!(
+        "| {} |\n|{}|\n",
+        fields
+            .iter()
+            .map(|field| field.name())
+            .collect::<Vec<_>>()
+            .join(" | "),
+        vec!["---"; fields.len()].join("|")
+    );
+    for record in records {
+        let row: Vec<_> = fields
+            .iter()
+            .map(|field| cell(&text(record.get(*field))))
+            .collect();
+        out.push_str(&format!("| {} |\n", row.join(" | ")));
+    }
+    out
+}
+
+fn table(fields: &[Field], records: &[Record]) -> String {
+    let cell = |field: Field, value: &Value| match (field, value) {
+        (Field::Completed, Value::Bool(completed)) => {
+            if *completed { "[x]" } else { "[ ]" }.to_string()
+        }
+        _ => text(value).replace(['\r', '\n'], " "),
+    };
+
+    let header: Vec<String> = fields
+        .iter()
+        .map(|field| field.name().to_uppercase())
+        .collect();
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            fields
+                .iter()
+                .map(|field| cell(*field, record.get(*field)))
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..fields.len())
+        .map(|i| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{value:<width$}"))
+                .collect();
+            line.join("  ").trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+/// Normalize a date or date-time to ISO 8601, keeping the offset when there is one.
+/// Strings that aren't recognised are passed through unchanged.
+pub fn iso_8601(raw: &str) -> String {
+    let raw = raw.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(raw) {
+        return datetime.format("%Y-%m-%dT%H:%M:%S%:z").to_string();
+    }
+    for pattern in [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(raw, pattern) {
+            return datetime.format("%Y-%m-%dT%H:%M:%S").to_string();
+        }
+    }
+    match NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        Ok(date) => date.format("%Y-%m-%d").to_string(),
+        Err(_) => raw.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn reminders() -> Vec<Record> {
+        vec![
+            Record::from_reminder(
+                &Reminder {
+                    id: "reminder-groceries-0".to_string(),
+                    title: "Buy milk, eggs".to_string(),
+                    notes: Some("Oat \"barista\"".to_string()),
+                    completed: false,
+                    priority: 5,
+                    due_date: Some("2025-01-16 09:00".to_string()),
+                },
+                "Groceries",
+            ),
+            Record::from_reminder(
+                &Reminder {
+                    id: "reminder-groceries-1".to_string(),
+                    title: "Bread".to_string(),
+                    notes: None,
+                    completed: true,
+                    priority: 0,
+                    due_date: None,
+                },
+                "Groceries",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_select_fields() {
+        assert_eq!(
+            RecordKind::List.select_fields(&[]).unwrap(),
+            vec![Field::Id, Field::Title, Field::Count]
+        );
+        assert_eq!(
+            RecordKind::Reminder
+                .select_fields(&[Field::Title, Field::Due])
+                .unwrap(),
+            vec![Field::Title, Field::Due]
+        );
+        assert!(RecordKind::Reminder.select_fields(&[Field::Color]).is_err());
+        assert!(RecordKind::List.select_fields(&[Field::Due]).is_err());
+    }
+
+    #[test]
+    fn test_render_json_keeps_field_order() {
+        let fields = [Field::Title, Field::Due, Field::Completed, Field::Notes];
+        let json = render(OutputFormat::Json, &fields, &reminders());
+        let parsed: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!([
+                {"title": "Buy milk, eggs", "due": "2025-01-16T09:00:00", "completed": false, "notes": "Oat \"barista\""},
+                {"title": "Bread", "due": null, "completed": true, "notes": null},
+            ])
+        );
+        assert!(json.find("\"title\"").unwrap() < json.find("\"due\"").unwrap());
+
+        assert_eq!(render(OutputFormat::Json, &fields, &[]), "[]\n");
+    }
+
+    #[test]
+    fn test_render_ndjson() {
+        let out = render(
+            OutputFormat::Ndjson,
+            &[Field::Id, Field::Priority],
+            &reminders(),
+        );
+        assert_eq!(
+            out,
+            "{\"id\":\"reminder-groceries-0\",\"priority\":5}\n\
+             {\"id\":\"reminder-groceries-1\",\"priority\":0}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_csv_and_tsv_escaping() {
+        let fields = [Field::Title, Field::Notes];
+        assert_eq!(
+            render(OutputFormat::Csv, &fields, &reminders()),
+            "title,notes\n\"Buy milk, eggs\",\"Oat \"\"barista\"\"\"\nBread,\n"
+        );
+
+        let records = vec![Record::from_list(&ReminderList {
+            id: "l1".to_string(),
+            title: "Tabs\there".to_string(),
+            color: "#FF0000".to_string(),
+            reminder_count: 2,
+        })];
+        assert_eq!(
+            render(OutputFormat::Tsv, &[Field::Title, Field::Count], &records),
+            "title\tcount\nTabs\\there\t2\n"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let out = render(
+            OutputFormat::Markdown,
+            &[Field::Title, Field::List],
+            &reminders(),
+        );
+        assert_eq!(
+            out,
+            "| title | list |\n|---|---|\n| Buy milk, eggs | Groceries |\n| Bread | Groceries |\n"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let out = render(
+            OutputFormat::Table,
+            &[Field::Completed, Field::Title, Field::Due],
+            &reminders(),
+        );
+        assert_eq!(
+            out,
+            "COMPLETED  TITLE           DUE\n\
+             [ ]        Buy milk, eggs  2025-01-16T09:00:00\n\
+             [x]        Bread\n"
+        );
+    }
+
+    #[test]
+    fn test_iso_8601() {
+        assert_eq!(iso_8601("2025-01-31"), "2025-01-31");
+        assert_eq!(iso_8601("2025-01-31T09:00:00"), "2025-01-31T09:00:00");
+        assert_eq!(iso_8601("2025-01-31 09:00"), "2025-01-31T09:00:00");
+        assert_eq!(
+            iso_8601("2025-01-31T09:00:00Z"),
+            "2025-01-31T09:00:00+00:00"
+        );
+        assert_eq!(iso_8601("next week"), "next week");
+    }
+}
@@ -0,0 +1,789 @@
+//! `rem serve`: JSON-RPC 2.0 over a Unix domain socket.
+//!
+//! Messages are newline-delimited JSON, one request, response or batch per line. Methods:
+//!
+//! - `list {list?, all?}`: all lists, or the reminders in one list
+//! - `get {id}`
+//! - `create {title, list?, due?, priority?, notes?}`
+//! - `update {id, title?, notes?, due?, priority?}` (`null` clears notes or due)
+//! - `toggle {id, completed?}`: flips completion unless `completed` is given
+//! - `delete {id}`
+//! - `search {query, all?}`
+//! - `subscribe` / `unsubscribe`
+//! - `publish {kind, id, list}`: tells the other subscribers about a change the client
+//!   made to the reminder source itself
+//!
+//! Subscribed clients receive a `changed {kind, id, list}` notification after every
+//! create, update, toggle or delete made through the server or published to it, except
+//! for their own. The TUI connects as a [`Client`] while a server is running: it
+//! publishes its changes and reloads on everyone else's. Reminder ids are stable, so
+//! clients can keep them across reloads.
+
+use std::{
+    io::ErrorKind,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use chrono::Local;
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    net::{
+        UnixListener, UnixStream,
+        unix::{OwnedReadHalf, OwnedWriteHalf},
+    },
+    sync::broadcast,
+};
+use tracing::{debug, info, warn};
+
+use crate::{
+    commands::{self, CommandError},
+    config::get_data_dir,
+    source::{Change, ChangeKind, NewReminder, ReminderSource, ReminderUpdate},
+    output::Record,
+};
+
+/// Where `rem serve` listens unless `--socket` is given.
+pub fn default_socket_path() -> PathBuf {
+    get_data_dir().join("rem.sock")
+}
+
+pub mod error_code {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// The list or reminder doesn't exist.
+    pub const NOT_FOUND: i64 = -32001;
+    /// Access to Reminders was denied.
+    pub const PERMISSION_DENIED: i64 = -32002;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<CommandError> for RpcError {
+    fn from(error: CommandError) -> Self {
+        let code = match error {
+            CommandError::Usage(_) => error_code::INVALID_PARAMS,
+            CommandError::NotFound(_) => error_code::NOT_FOUND,
+            CommandError::PermissionDenied => error_code::PERMISSION_DENIED,
+            CommandError::InvalidConfig(_) | CommandError::Failed(_) => {
+                error_code::INTERNAL_ERROR
+            }
+        };
+        Self::new(code, error.to_string())
+    }
+}
+
+impl From<color_eyre::Report> for RpcError {
+    fn from(report: color_eyre::Report) -> Self {
+        Self::new(error_code::INTERNAL_ERROR, report.to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+    /// Absent for notifications, which get no response.
+    #[serde(default, deserialize_with = "present")]
+    id: Option<Value>,
+}
+
+/// Distinguish `"id": null` (a request) from a missing id (a notification).
+fn present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ListParams {
+    list: Option<String>,
+    all: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IdParams {
+    id: String,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CreateParams {
+    title: String,
+    #[serde(default)]
+    list: Option<String>,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    priority: u8,
+    #[serde(default)]
+    notes: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UpdateParams {
+    id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default, deserialize_with = "present")]
+    notes: Option<Option<String>>,
+    #[serde(default, deserialize_with = "present")]
+    due: Option<Option<String>>,
+    #[serde(default)]
+    priority: Option<u8>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ToggleParams {
+    id: String,
+    #[serde(default)]
+    completed: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SearchParams {
+    query: String,
+    #[serde(default)]
+    all: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Call {
+    List(ListParams),
+    Get(IdParams),
+    Create(CreateParams),
+    Update(UpdateParams),
+    Toggle(ToggleParams),
+    Delete(IdParams),
+    Search(SearchParams),
+    Subscribe,
+    Unsubscribe,
+    Publish(Change),
+}
+
+impl Call {
+    /// Parse a method and its params, which may be by name (an object) or by position.
+    fn parse(method: &str, params: Option<Value>) -> Result<Self, RpcError> {
+        fn params_as<T: DeserializeOwned>(params: Option<Value>) -> Result<T, RpcError> {
+            let params = match params {
+                None | Some(Value::Null) => json!({}),
+                Some(params) => params,
+            };
+            serde_json::from_value(params)
+                .map_err(|e| RpcError::new(error_code::INVALID_PARAMS, e.to_string()))
+        }
+
+        Ok(match method {
+            "list" => Call::List(params_as(params)?),
+            "get" => Call::Get(params_as(params)?),
+            "create" => Call::Create(params_as(params)?),
+            "update" => Call::Update(params_as(params)?),
+            "toggle" => Call::Toggle(params_as(params)?),
+            "delete" => Call::Delete(params_as(params)?),
+            "search" => Call::Search(params_as(params)?),
+            "subscribe" => Call::Subscribe,
+            "unsubscribe" => Call::Unsubscribe,
+            "publish" => Call::Publish(params_as(params)?),
+            other => {
+                return Err(RpcError::new(
+                    error_code::METHOD_NOT_FOUND,
+                    format!("Method not found: {other}"),
+                ));
+            }
+        })
+    }
+}
+
+fn success(id: Value, result: Value) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "result": result})
+}
+
+fn failure(id: Value, error: RpcError) -> Value {
+    json!({"jsonrpc": "2.0", "id": id, "error": error})
+}
+
+fn notification(change: &Change) -> Value {
+    json!({"jsonrpc": "2.0", "method": "changed", "params": change})
+}
+
+struct Server {
+    source: Mutex<Arc<dyn ReminderSource>>,
+    /// Each change with the connection it came from, which isn't told about it.
+    changes: broadcast::Sender<(u64, Change)>,
+    next_connection: AtomicU64,
+}
+
+/// Per-connection state.
+struct Session {
+    connection: u64,
+    subscribed: bool,
+}
+
+/// Listen on `socket_path` until interrupted, then remove the socket.
+pub async fn serve(source: Arc<dyn ReminderSource>, socket_path: PathBuf) -> Result<()> {
+    let listener = bind(&socket_path).await?;
+    info!("Listening on {}", socket_path.display());
+    eprintln!("rem: listening on {}", socket_path.display());
+
+    let (changes, _) = broadcast::channel(64);
+    let server = Arc::new(Server {
+        source: Mutex::new(source),
+        changes,
+        next_connection: AtomicU64::new(0),
+    });
+
+    let result = loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let server = server.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = server.handle_connection(stream).await {
+                            debug!("Connection closed with error: {e}");
+                        }
+                    });
+                }
+                Err(e) => break Err(e.into()),
+            },
+            _ = tokio::signal::ctrl_c() => break Ok(()),
+        }
+    };
+
+    let _ = std::fs::remove_file(&socket_path);
+    result
+}
+
+/// Bind the socket, replacing a stale one left by a server that didn't shut down cleanly.
+async fn bind(socket_path: &Path) -> Result<UnixListener> {
+    if socket_path.exists() {
+        if UnixStream::connect(socket_path).await.is_ok() {
+            return Err(eyre!(
+                "Another rem server is already listening on {}",
+                socket_path.display()
+            ));
+        }
+        std::fs::remove_file(socket_path)?;
+    }
+    if let Some(parent) = socket_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let listener = UnixListener::bind(socket_path)?;
+    // Only the current user may talk to their reminders
+    std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(listener)
+}
+
+impl Server {
+    async fn handle_connection(self: Arc<Self>, stream: UnixStream) -> Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut changes = self.changes.subscribe();
+        let mut session = Session {
+            connection: self.next_connection.fetch_add(1, Ordering::Relaxed),
+            subscribed: false,
+        };
+
+        loop {
+            let message = tokio::select! {
+                line = lines.next_line() => match line? {
+                    Some(line) => {
+                        let was_subscribed = session.subscribed;
+                        let reply = self.clone().handle_message(&line, &mut session).await;
+                        if session.subscribed && !was_subscribed {
+                            // Start from now rather than replaying earlier changes
+                            changes = changes.resubscribe();
+                        }
+                        match reply {
+                            Some(reply) => reply,
+                            None => continue,
+                        }
+                    }
+                    None => return Ok(()),
+                },
+                change = changes.recv(), if session.subscribed => match change {
+                    Ok((origin, _)) if origin == session.connection => continue,
+                    Ok((_, change)) => notification(&change),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Subscriber missed {skipped} change notifications");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                },
+            };
+
+            let mut bytes = serde_json::to_vec(&message)?;
+            bytes.push(b'\n');
+            match writer.write_all(&bytes).await {
+                Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+                result => result?,
+            }
+        }
+    }
+
+    /// Handle one line: a request, a notification or a batch. Returns the reply, if any.
+    async fn handle_message(self: Arc<Self>, line: &str, session: &mut Session) -> Option<Value> {
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                return Some(failure(
+                    Value::Null,
+                    RpcError::new(error_code::PARSE_ERROR, e.to_string()),
+                ));
+            }
+        };
+
+        match message {
+            Value::Array(batch) if batch.is_empty() => Some(failure(
+                Value::Null,
+                RpcError::new(error_code::INVALID_REQUEST, "Empty batch"),
+            )),
+            Value::Array(batch) => {
+                let mut replies = Vec::new();
+                for message in batch {
+                    replies.extend(self.clone().handle_request(message, session).await);
+                }
+                (!replies.is_empty()).then_some(Value::Array(replies))
+            }
+            message => self.handle_request(message, session).await,
+        }
+    }
+
+    async fn handle_request(
+        self: Arc<Self>,
+        message: Value,
+        session: &mut Session,
+    ) -> Option<Value> {
+        let request = match serde_json::from_value::<Request>(message) {
+            Ok(request) if request.jsonrpc == "2.0" => request,
+            Ok(request) => {
+                return Some(failure(
+                    request.id.unwrap_or(Value::Null),
+                    RpcError::new(error_code::INVALID_REQUEST, "jsonrpc must be \"2.0\""),
+                ));
+            }
+            Err(e) => {
+                return Some(failure(
+                    Value::Null,
+                    RpcError::new(error_code::INVALID_REQUEST, e.to_string()),
+                ));
+            }
+        };
+        debug!("RPC {} {:?}", request.method, request.params);
+
+        let result = match Call::parse(&request.method, request.params) {
+            Ok(Call::Subscribe) => {
+                session.subscribed = true;
+                Ok(Value::Bool(true))
+            }
+            Ok(Call::Unsubscribe) => {
+                session.subscribed = false;
+                Ok(Value::Bool(true))
+            }
+            Ok(Call::Publish(change)) => {
+                let _ = self.changes.send((session.connection, change));
+                Ok(Value::Bool(true))
+            }
+            Ok(call) => {
+                let server = self.clone();
+                let connection = session.connection;
+                tokio::task::spawn_blocking(move || server.execute(call, connection))
+                    .await
+                    .unwrap_or_else(|e| {
+                        Err(RpcError::new(error_code::INTERNAL_ERROR, e.to_string()))
+                    })
+            }
+            Err(error) => Err(error),
+        };
+
+        let id = request.id?;
+        Some(match result {
+            Ok(result) => success(id, result),
+            Err(error) => failure(id, error),
+        })
+    }
+
+    /// Run a data call against the reminder source. Calls are serialized because several read
+    /// before they write: a toggle without `completed` flips the state it just looked up, so two
+    /// interleaved toggles would both flip from the same state. Holding the lock until the change
+    /// is sent also keeps notifications in the order the changes were made.
+    fn execute(&self, call: Call, connection: u64) -> Result<Value, RpcError> {
+        let guard = self
+            .source
+            .lock()
+            .map_err(|_| RpcError::new(error_code::INTERNAL_ERROR, "Reminders are unavailable"))?;
+        let source = guard.as_ref();
+        let not_found = |id: &str| CommandError::NotFound(format!("No reminder with id '{id}'"));
+
+        let (result, change) = match call {
+            Call::List(ListParams { list: None, .. }) => {
+                let lists: Vec<_> = source
+                    .get_reminder_lists()?
+                    .iter()
+                    .map(Record::from_list)
+                    .collect();
+                (json!(lists), None)
+            }
+            Call::List(ListParams {
+                list: Some(list),
+                all,
+            }) => {
+                let list = commands::find_list(source, &list)?;
+                let reminders: Vec<_> = source
+                    .get_reminders_for_list(&list.id)?
+                    .iter()
+                    .filter(|r| all || !r.completed)
+                    .map(|r| Record::from_reminder(r, &list.title))
+                    .collect();
+                (json!(reminders), None)
+            }
+            Call::Get(IdParams { id }) => {
+                let (reminder, list) =
+                    source.find_reminder(&id)?.ok_or_else(|| not_found(&id))?;
+                (json!(Record::from_reminder(&reminder, &list.title)), None)
+            }
+            Call::Search(SearchParams { query, all }) => {
+                let matches: Vec<_> = commands::search(source, &query, all)?
+                    .iter()
+                    .map(|(reminder, list)| Record::from_reminder(reminder, &list.title))
+                    .collect();
+                (json!(matches), None)
+            }
+            Call::Create(params) => {
+                let list = match params.list {
+                    Some(list) => commands::find_list(source, &list)?,
+                    None => {
+                        let id = source.default_list_id().ok_or_else(|| {
+                            CommandError::Usage("No default list; pass one as \"list\"".to_string())
+                        })?;
+                        commands::find_list(source, &id)?
+                    }
+                };
+                let due_date = params
+                    .due
+                    .map(|due| commands::parse_due_date(&due, Local::now().naive_local()))
+                    .transpose()?;
+                let reminder = source.create_reminder(&NewReminder {
+                    title: params.title,
+                    notes: params.notes,
+                    due_date,
+                    list_id: list.id,
+                    priority: params.priority,
+                })?;
+                let change = Change {
+                    kind: ChangeKind::Created,
+                    id: reminder.id.clone(),
+                    list: list.title.clone(),
+                };
+                (
+                    json!(Record::from_reminder(&reminder, &list.title)),
+                    Some(change),
+                )
+            }
+            Call::Update(params) => {
+                let now = Local::now().naive_local();
+                let due_date = params
+                    .due
+                    .map(|due| {
+                        due.map(|due| commands::parse_due_date(&due, now))
+                            .transpose()
+                    })
+                    .transpose()?;
+                let update = ReminderUpdate {
+                    title: params.title,
+                    notes: params.notes,
+                    due_date,
+                    priority: params.priority,
+                };
+                let reminder = source
+                    .update_reminder(&params.id, &update)?
+                    .ok_or_else(|| not_found(&params.id))?;
+                self.changed_reminder(source, reminder.id.clone(), ChangeKind::Updated)?
+            }
+            Call::Toggle(ToggleParams { id, completed }) => {
+                let (reminder, _) = source.find_reminder(&id)?.ok_or_else(|| not_found(&id))?;
+                source.set_reminder_completed(&id, completed.unwrap_or(!reminder.completed))?;
+                self.changed_reminder(source, id, ChangeKind::Updated)?
+            }
+            Call::Delete(IdParams { id }) => {
+                let (_, list) = source.find_reminder(&id)?.ok_or_else(|| not_found(&id))?;
+                source.delete_reminder(&id)?;
+                let change = Change {
+                    kind: ChangeKind::Deleted,
+                    id,
+                    list: list.title,
+                };
+                (Value::Bool(true), Some(change))
+            }
+            Call::Subscribe | Call::Unsubscribe | Call::Publish(_) => {
+                unreachable!("handled per connection")
+            }
+        };
+
+        if let Some(change) = change {
+            // Nobody listening is fine
+            let _ = self.changes.send((connection, change));
+        }
+        Ok(result)
+    }
+
+    /// Reload a reminder after a write, for the response and the change notification.
+    fn changed_reminder(
+        &self,
+        source: &dyn ReminderSource,
+        id: String,
+        kind: ChangeKind,
+    ) -> Result<(Value, Option<Change>), RpcError> {
+        let (reminder, list) = source
+            .find_reminder(&id)?
+            .ok_or_else(|| CommandError::NotFound(format!("No reminder with id '{id}'")))?;
+        let change = Change {
+            kind,
+            id,
+            list: list.title.clone(),
+        };
+        Ok((
+            json!(Record::from_reminder(&reminder, &list.title)),
+            Some(change),
+        ))
+    }
+}
+
+/// A subscribed connection to a running `rem serve`, for keeping the TUI in sync with
+/// other clients.
+pub struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Client {
+    /// Connect to the server on `socket_path` and subscribe to its changes.
+    pub async fn subscribe(socket_path: &Path) -> Result<Self> {
+        let (reader, writer) = UnixStream::connect(socket_path).await?.into_split();
+        let mut client = Self {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        };
+        client
+            .send(&json!({"jsonrpc": "2.0", "id": 0, "method": "subscribe"}))
+            .await?;
+        // Changes are only sent from the reply on
+        match client.lines.next_line().await? {
+            Some(line) if serde_json::from_str::<Value>(&line)?["result"] == true => Ok(client),
+            _ => Err(eyre!(
+                "{} didn't accept the subscription",
+                socket_path.display()
+            )),
+        }
+    }
+
+    /// Tell the other subscribers about a change made to the reminder source directly.
+    pub async fn publish(&mut self, change: &Change) -> Result<()> {
+        self.send(&json!({"jsonrpc": "2.0", "method": "publish", "params": change}))
+            .await
+    }
+
+    /// Wait for the next change another client made, skipping responses. `None` once
+    /// the server has gone. Cancel-safe, so it can be raced against [`Client::publish`].
+    pub async fn next_change(&mut self) -> Result<Option<Change>> {
+        while let Some(line) = self.lines.next_line().await? {
+            let message: Value = serde_json::from_str(&line)?;
+            if message["method"] == "changed" {
+                return Ok(Some(serde_json::from_value(message["params"].clone())?));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn send(&mut self, message: &Value) -> Result<()> {
+        let mut bytes = serde_json::to_vec(message)?;
+        bytes.push(b'\n');
+        self.writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(message: Value) -> Result<Call, RpcError> {
+        let request: Request = serde_json::from_value(message).unwrap();
+        Call::parse(&request.method, request.params)
+    }
+
+    #[test]
+    fn test_parse_named_and_positional_params() {
+        assert_eq!(
+            parse(json!({"jsonrpc": "2.0", "id": 1, "method": "list"})).unwrap(),
+            Call::List(ListParams::default())
+        );
+        assert_eq!(
+            parse(json!({"jsonrpc": "2.0", "id": 1, "method": "get", "params": {"id": "r-1"}}))
+                .unwrap(),
+            Call::Get(IdParams {
+                id: "r-1".to_string()
+            })
+        );
+        assert_eq!(
+            parse(json!({"jsonrpc": "2.0", "id": 1, "method": "search", "params": ["milk", true]}))
+                .unwrap(),
+            Call::Search(SearchParams {
+                query: "milk".to_string(),
+                all: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_update_distinguishes_null_from_missing() {
+        let call = parse(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "update",
+            "params": {"id": "r-1", "title": "New", "due": null},
+        }))
+        .unwrap();
+        assert_eq!(
+            call,
+            Call::Update(UpdateParams {
+                id: "r-1".to_string(),
+                title: Some("New".to_string()),
+                notes: None,
+                due: Some(None),
+                priority: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse(json!({"jsonrpc": "2.0", "id": 1, "method": "frobnicate"})).unwrap_err();
+        assert_eq!(error.code, error_code::METHOD_NOT_FOUND);
+
+        let error = parse(json!({"jsonrpc": "2.0", "id": 1, "method": "get"})).unwrap_err();
+        assert_eq!(error.code, error_code::INVALID_PARAMS);
+
+        let error = parse(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "toggle",
+            "params": {"id": "r-1", "done": true},
+        }))
+        .unwrap_err();
+        assert_eq!(error.code, error_code::INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_request_id_presence() {
+        let request: Request =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "method": "subscribe"})).unwrap();
+        assert_eq!(request.id, None, "A missing id makes a notification");
+
+        let request: Request =
+            serde_json::from_value(json!({"jsonrpc": "2.0", "id": null, "method": "subscribe"}))
+                .unwrap();
+        assert_eq!(request.id, Some(Value::Null));
+    }
+
+    #[test]
+    fn test_command_errors_map_to_rpc_codes() {
+        let error = RpcError::from(CommandError::NotFound("No list named 'x'".to_string()));
+        assert_eq!(error.code, error_code::NOT_FOUND);
+        assert_eq!(error.message, "No list named 'x'");
+        assert_eq!(
+            RpcError::from(CommandError::Usage("bad".to_string())).code,
+            error_code::INVALID_PARAMS
+        );
+    }
+
+    /// A server on a fresh socket and reminders file, running until the task is aborted.
+    async fn test_server(name: &str) -> (PathBuf, tokio::task::JoinHandle<Result<()>>) {
+        let dir = std::env::temp_dir().join(format!("rem-rpc-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let source = Arc::new(crate::source::local::LocalSource::new(
+            dir.join("reminders.json"),
+        ));
+        let socket_path = dir.join("rem.sock");
+        let server = tokio::spawn(serve(source, socket_path.clone()));
+        while !socket_path.exists() {
+            tokio::task::yield_now().await;
+        }
+        (socket_path, server)
+    }
+
+    #[tokio::test]
+    async fn test_published_changes_reach_other_subscribers() {
+        let (socket_path, server) = test_server("publish").await;
+        let mut tui = Client::subscribe(&socket_path).await.unwrap();
+        let mut editor = Client::subscribe(&socket_path).await.unwrap();
+
+        let toggled = Change {
+            kind: ChangeKind::Updated,
+            id: "reminder-2".to_string(),
+            list: "Reminders".to_string(),
+        };
+        tui.publish(&toggled).await.unwrap();
+        assert_eq!(editor.next_change().await.unwrap(), Some(toggled));
+
+        // A client isn't told about its own changes, so the next one the TUI hears
+        // about is the editor's
+        let deleted = Change {
+            kind: ChangeKind::Deleted,
+            id: "reminder-3".to_string(),
+            list: "Reminders".to_string(),
+        };
+        editor.publish(&deleted).await.unwrap();
+        assert_eq!(tui.next_change().await.unwrap(), Some(deleted));
+
+        server.abort();
+    }
+
+    #[test]
+    fn test_change_notification_shape() {
+        let change = Change {
+            kind: ChangeKind::Deleted,
+            id: "reminder-groceries-0".to_string(),
+            list: "Groceries".to_string(),
+        };
+        assert_eq!(
+            notification(&change),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "changed",
+                "params": {"kind": "deleted", "id": "reminder-groceries-0", "list": "Groceries"},
+            })
+        );
+    }
+}
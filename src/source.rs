@@ -0,0 +1,158 @@
+//! Where reminders come from.
+//!
+//! The TUI, the subcommands, `rem watch` and `rem serve` all go through [`ReminderSource`].
+//! On macOS that's EventKit (with Reminders.app's AppleScript as a fallback for reading);
+//! everywhere else it's a JSON file in the data directory, so `rem` builds and runs on
+//! every platform.
+
+use std::sync::Arc;
+
+use color_eyre::Result;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(target_os = "macos", test))]
+mod applescript;
+#[cfg(target_os = "macos")]
+pub mod eventkit;
+pub mod local;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReminderList {
+    pub id: String,
+    pub title: String,
+    pub color: String,
+    pub reminder_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub title: String,
+    pub notes: Option<String>,
+    pub completed: bool,
+    pub priority: u8,
+    pub due_date: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewReminder {
+    pub title: String,
+    pub notes: Option<String>,
+    /// ISO 8601 date (`2025-01-31`) or local date-time (`2025-01-31T09:00:00`)
+    pub due_date: Option<String>,
+    pub list_id: String,
+    pub priority: u8,
+}
+
+/// Changes to an existing reminder. `None` leaves a field as it is; for `notes` and
+/// `due_date`, `Some(None)` clears it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReminderUpdate {
+    pub title: Option<String>,
+    pub notes: Option<Option<String>>,
+    /// ISO 8601 date (`2025-01-31`) or local date-time (`2025-01-31T09:00:00`)
+    pub due_date: Option<Option<String>>,
+    pub priority: Option<u8>,
+}
+
+/// A change to a reminder, as `rem serve` tells its subscribers about it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub id: String,
+    /// The title of the reminder's list.
+    pub list: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionStatus {
+    NotDetermined,
+    Denied,
+    Authorized,
+    Restricted,
+}
+
+impl From<i64> for PermissionStatus {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => PermissionStatus::NotDetermined,
+            1 => PermissionStatus::Restricted,
+            2 => PermissionStatus::Denied,
+            3 => PermissionStatus::Authorized,
+            _ => PermissionStatus::NotDetermined,
+        }
+    }
+}
+
+/// A store of reminder lists and their reminders.
+pub trait ReminderSource: Send + Sync {
+    fn check_permission_status(&self) -> PermissionStatus;
+
+    /// Ask the user for access. Resolves to whether it was granted.
+    fn request_permission(&self) -> BoxFuture<'_, Result<bool>>;
+
+    fn get_reminder_lists(&self) -> Result<Vec<ReminderList>>;
+
+    /// The lists, quickly, for showing them before their counts arrive from
+    /// [`ReminderSource::get_reminder_count_for_list`]. `reminder_count` may be 0.
+    fn get_reminder_lists_without_counts(&self) -> Result<Vec<ReminderList>> {
+        self.get_reminder_lists()
+    }
+
+    /// How many reminders, completed or not, `list_id` has.
+    fn get_reminder_count_for_list(&self, list_id: &str) -> Result<usize> {
+        Ok(self.get_reminders_for_list(list_id)?.len())
+    }
+
+    fn get_reminders_for_list(&self, list_id: &str) -> Result<Vec<Reminder>>;
+
+    /// The list new reminders go to when none is given.
+    fn default_list_id(&self) -> Option<String>;
+
+    fn create_reminder(&self, new_reminder: &NewReminder) -> Result<Reminder>;
+
+    /// Mark a reminder as completed or not. Returns `false` if no reminder has `reminder_id`.
+    fn set_reminder_completed(&self, reminder_id: &str, completed: bool) -> Result<bool>;
+
+    /// Delete a reminder. Returns `false` if no reminder has `reminder_id`.
+    fn delete_reminder(&self, reminder_id: &str) -> Result<bool>;
+
+    /// Apply `update` to a reminder and return it as stored. Returns `None` if no
+    /// reminder has `reminder_id`.
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        update: &ReminderUpdate,
+    ) -> Result<Option<Reminder>>;
+
+    /// Look up a reminder and the list it belongs to.
+    fn find_reminder(&self, reminder_id: &str) -> Result<Option<(Reminder, ReminderList)>> {
+        for list in self.get_reminder_lists()? {
+            let reminders = self.get_reminders_for_list(&list.id)?;
+            if let Some(reminder) = reminders.into_iter().find(|r| r.id == reminder_id) {
+                return Ok(Some((reminder, list)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The platform's reminder store: EventKit on macOS, the local file elsewhere.
+/// `REM_SOURCE=local` uses the local file on macOS too.
+pub fn open() -> Result<Arc<dyn ReminderSource>> {
+    #[cfg(target_os = "macos")]
+    if std::env::var("REM_SOURCE").as_deref() != Ok("local") {
+        return Ok(Arc::new(eventkit::EventKitManager::new()?));
+    }
+    Ok(Arc::new(local::LocalSource::new(local::default_path())))
+}
@@ -0,0 +1,348 @@
+//! AppleScript values as text, for talking to Reminders.app through `osascript`.
+//!
+//! Scripts get their arguments as AppleScript literals (see [`quote`]), and results are
+//! read back in the form `osascript -s s` prints them, which quotes and escapes every
+//! string. No field is ever split on a delimiter that could also appear in a title or
+//! a note.
+
+use std::fmt;
+
+use color_eyre::{Result, eyre::eyre};
+
+use super::Reminder;
+
+/// The subset of AppleScript values Reminders scripts exchange with us.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Integer(i64),
+    Boolean(bool),
+    /// `missing value`: a property that isn't set, like a reminder without notes.
+    Missing,
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// The text, or `None` for `missing value` and empty text.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Value::Text(text) if !text.is_empty() => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn boolean(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn items(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// AppleScript source form, which `parse` reads back.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => {
+                f.write_str("\"")?;
+                for c in text.chars() {
+                    match c {
+                        '"' => f.write_str("\\\"")?,
+                        '\\' => f.write_str("\\\\")?,
+                        '\n' => f.write_str("\\n")?,
+                        '\r' => f.write_str("\\r")?,
+                        '\t' => f.write_str("\\t")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                f.write_str("\"")
+            }
+            Value::Integer(value) => write!(f, "{value}"),
+            Value::Boolean(value) => write!(f, "{value}"),
+            Value::Missing => f.write_str("missing value"),
+            Value::List(items) => {
+                f.write_str("{")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+/// A Rust string as an AppleScript string literal.
+pub fn quote(text: &str) -> String {
+    Value::Text(text.to_string()).to_string()
+}
+
+/// Read one value printed by `osascript -s s`.
+pub fn parse(source: &str) -> Result<Value> {
+    let mut parser = Parser {
+        chars: source.chars().collect(),
+        position: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(&format!("unexpected '{c}' after the value"))),
+    }
+}
+
+/// A reminder from a `{id, name, completed, priority, dueString, body}` record.
+pub fn reminder(record: &Value) -> Result<Reminder> {
+    let fields = record
+        .items()
+        .filter(|fields| fields.len() == 6)
+        .ok_or_else(|| eyre!("Expected a reminder record, got {record}"))?;
+    let id = fields[0]
+        .text()
+        .ok_or_else(|| eyre!("Reminder without an id: {record}"))?;
+    Ok(Reminder {
+        // The same identifier EventKit uses, behind a URL scheme
+        id: id
+            .strip_prefix("x-apple-reminder://")
+            .unwrap_or(id)
+            .to_string(),
+        title: fields[1].text().unwrap_or_default().to_string(),
+        notes: fields[5].text().map(str::to_string),
+        completed: fields[2].boolean().unwrap_or(false),
+        priority: fields[3]
+            .integer()
+            .and_then(|priority| u8::try_from(priority).ok())
+            .unwrap_or(0),
+        due_date: fields[4].text().map(str::to_string),
+    })
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, message: &str) -> color_eyre::Report {
+        eyre!(
+            "Unreadable reply from Reminders at {}: {message}",
+            self.position
+        )
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('"') => self.text(),
+            Some('{') => self.list(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.integer(),
+            Some(c) if c.is_alphabetic() => self.word(),
+            Some(c) => Err(self.error(&format!("unexpected '{c}'"))),
+            None => Err(self.error("unexpected end")),
+        }
+    }
+
+    fn text(&mut self) -> Result<Value> {
+        self.next();
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(Value::Text(text)),
+                Some('\\') => match self.next() {
+                    Some('n') => text.push('\n'),
+                    Some('r') => text.push('\r'),
+                    Some('t') => text.push('\t'),
+                    Some(c @ ('"' | '\\')) => text.push(c),
+                    Some(c) => return Err(self.error(&format!("unknown escape '\\{c}'"))),
+                    None => return Err(self.error("unterminated escape")),
+                },
+                Some(c) => text.push(c),
+                None => return Err(self.error("unterminated text")),
+            }
+        }
+    }
+
+    fn list(&mut self) -> Result<Value> {
+        self.next();
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.next();
+            return Ok(Value::List(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => {}
+                Some('}') => return Ok(Value::List(items)),
+                _ => return Err(self.error("expected ',' or '}' in a list")),
+            }
+        }
+    }
+
+    fn integer(&mut self) -> Result<Value> {
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let digits: String = self.chars[start..self.position].iter().collect();
+        digits
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| self.error(&format!("bad number '{digits}'")))
+    }
+
+    fn word(&mut self) -> Result<Value> {
+        let start = self.position;
+        while self.peek().is_some_and(char::is_alphabetic) {
+            self.position += 1;
+        }
+        let word: String = self.chars[start..self.position].iter().collect();
+        match word.as_str() {
+            "true" => Ok(Value::Boolean(true)),
+            "false" => Ok(Value::Boolean(false)),
+            "missing" => {
+                self.skip_whitespace();
+                let start = self.position;
+                while self.peek().is_some_and(char::is_alphabetic) {
+                    self.position += 1;
+                }
+                let rest: String = self.chars[start..self.position].iter().collect();
+                if rest == "value" {
+                    Ok(Value::Missing)
+                } else {
+                    Err(self.error("expected 'missing value'"))
+                }
+            }
+            _ => Err(self.error(&format!("unexpected '{word}'"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn text(value: &str) -> Value {
+        Value::Text(value.to_string())
+    }
+
+    #[test]
+    fn test_delimiter_laden_text_round_trips() {
+        let titles = [
+            "Pay rent | utilities",
+            "a~REMINDER~b",
+            "quote \" and backslash \\ and \\\" both",
+            "line one\nline two\r\n\ttabbed",
+            "trailing backslash \\",
+            "{not, a, list}",
+            "missing value",
+            "émoji 🧾, commas, and }",
+            "",
+        ];
+        for title in titles {
+            assert_eq!(parse(&quote(title)).unwrap(), text(title), "{title:?}");
+        }
+    }
+
+    #[test]
+    fn test_reads_osascript_records() {
+        // As `osascript -s s` prints a list of reminder records
+        let output = r#"{{"x-apple-reminder://A1", "Call | Bob", false, 0, "2025-01-31T09:00:00", missing value}, {"x-apple-reminder://B2", "Say \"hi\"", true, 9, "", "notes
+over two lines"}}"#;
+        let value = parse(output).unwrap();
+        let records = value.items().unwrap();
+        assert_eq!(records.len(), 2);
+
+        let first = records[0].items().unwrap();
+        assert_eq!(first[1].text(), Some("Call | Bob"));
+        assert_eq!(first[2].boolean(), Some(false));
+        assert_eq!(first[4].text(), Some("2025-01-31T09:00:00"));
+        assert_eq!(first[5], Value::Missing);
+
+        let second = records[1].items().unwrap();
+        assert_eq!(second[1].text(), Some("Say \"hi\""));
+        assert_eq!(second[3].integer(), Some(9));
+        assert_eq!(second[4].text(), None);
+        assert_eq!(second[5].text(), Some("notes\nover two lines"));
+
+        let value = Value::List(vec![text("a|b"), Value::Integer(-3), Value::Missing]);
+        assert_eq!(parse(&value.to_string()).unwrap(), value);
+        assert_eq!(parse(" {} \n").unwrap(), Value::List(Vec::new()));
+    }
+
+    #[test]
+    fn test_rejects_malformed_output() {
+        for bad in [
+            r#""unterminated"#,
+            r#""bad \q escape""#,
+            r#"{"a" "b"}"#,
+            "{1, 2",
+            "missing values",
+            r#""a" trailing"#,
+            "date \"Friday\"",
+        ] {
+            assert!(parse(bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn test_decodes_reminder_records() {
+        let record =
+            parse(r#"{"x-apple-reminder://A1", "Call | Bob", true, 5, "2025-01-31", "a\nb"}"#)
+                .unwrap();
+        let decoded = reminder(&record).unwrap();
+        assert_eq!(decoded.id, "A1");
+        assert_eq!(decoded.title, "Call | Bob");
+        assert!(decoded.completed);
+        assert_eq!(decoded.priority, 5);
+        assert_eq!(decoded.due_date.as_deref(), Some("2025-01-31"));
+        assert_eq!(decoded.notes.as_deref(), Some("a\nb"));
+
+        let bare = parse(r#"{"B2", "", false, 0, missing value, missing value}"#).unwrap();
+        let decoded = reminder(&bare).unwrap();
+        assert_eq!(
+            (decoded.id.as_str(), decoded.notes, decoded.due_date),
+            ("B2", None, None)
+        );
+        assert!(reminder(&parse(r#"{"A1", "short"}"#).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+use std::collections::HashMap;
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use color_eyre::{Result, eyre::eyre};
+use futures::future::BoxFuture;
+use objc::runtime::{BOOL, Class, NO, Object, YES};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::{
+    NewReminder, PermissionStatus, Reminder, ReminderList, ReminderSource, ReminderUpdate,
+    applescript,
+};
+
+/// `EKEntityTypeReminder`
+const ENTITY_TYPE_REMINDER: i64 = 1;
+
+/// How long to wait for EventKit to return a fetch before asking Reminders.app instead.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `NSDateComponentUndefined`: the hour and minute of an all-day due date.
+const UNDEFINED_COMPONENT: isize = isize::MAX;
+
+// Macro for conditional debug logging based on DEBUG environment variable
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        if std::env::var("DEBUG").unwrap_or_default() == "true" {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+// Force link EventKit framework
+#[link(name = "EventKit", kind = "framework")]
+#[link(name = "Foundation", kind = "framework")]
+#[link(name = "CoreFoundation", kind = "framework")]
+unsafe extern "C" {}
+
+pub struct EventKitManager {
+    event_store: *mut Object,
+}
+
+impl EventKitManager {
+    pub fn new() -> Result<Self> {
+        unsafe {
+            // Check if EventKit framework is available
+            let event_store_class = Class::get("EKEventStore")
+                .ok_or_else(|| color_eyre::eyre::eyre!("Failed to get EKEventStore class - EventKit framework may not be linked or available"))?;
+
+            let event_store: *mut Object = msg_send![event_store_class, new];
+
+            if event_store.is_null() {
+                return Err(color_eyre::eyre::eyre!(
+                    "Failed to create EKEventStore instance"
+                ));
+            }
+
+            // Test basic functionality
+            let calendars: *mut Object = msg_send![event_store, calendarsForEntityType: 1i64];
+            if calendars.is_null() {
+                return Err(color_eyre::eyre::eyre!(
+                    "Failed to access calendars - EventKit may not be properly initialized"
+                ));
+            }
+
+            Ok(EventKitManager { event_store })
+        }
+    }
+}
+
+impl ReminderSource for EventKitManager {
+    fn check_permission_status(&self) -> PermissionStatus {
+        unsafe {
+            let event_store_class = match Class::get("EKEventStore") {
+                Some(class) => class,
+                None => return PermissionStatus::NotDetermined,
+            };
+            let entity_type_reminder: i64 = 1; // EKEntityTypeReminder
+            let status: i64 = msg_send![event_store_class, authorizationStatusForEntityType: entity_type_reminder];
+            PermissionStatus::from(status)
+        }
+    }
+
+    fn request_permission(&self) -> BoxFuture<'_, Result<bool>> {
+        Box::pin(async move {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            let tx = Arc::new(Mutex::new(Some(tx)));
+
+            unsafe {
+                let entity_type_reminder: i64 = 1; // EKEntityTypeReminder
+
+                // Create a completion block using ConcreteBlock
+                let completion_block =
+                    block::ConcreteBlock::new(move |granted: BOOL, _error: *mut Object| {
+                        if let Ok(mut tx_guard) = tx.lock() {
+                            if let Some(tx) = tx_guard.take() {
+                                let _ = tx.send(granted == YES);
+                            }
+                        }
+                    });
+
+                let completion_block = completion_block.copy();
+
+                let _: () = msg_send![self.event_store,
+                    requestAccessToEntityType: entity_type_reminder
+                    completion: completion_block
+                ];
+            }
+
+            // Wait for the callback with timeout
+            match tokio::time::timeout(std::time::Duration::from_secs(30), rx).await {
+                Ok(Ok(granted)) => Ok(granted),
+                Ok(Err(_)) => Ok(false),
+                Err(_) => Ok(false), // Timeout
+            }
+        })
+    }
+
+    fn get_reminder_lists(&self) -> Result<Vec<ReminderList>> {
+        // One fetch for every list's count, rather than one per list
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        match self.fetch(ptr::null_mut()) {
+            Ok(reminders) => {
+                for (_, list_id) in reminders {
+                    *counts.entry(list_id).or_default() += 1;
+                }
+            }
+            Err(e) => debug_log!("Debug: Couldn't count reminders: {}", e),
+        }
+
+        let mut lists = self.get_reminder_lists_without_counts()?;
+        for list in &mut lists {
+            list.reminder_count = counts.get(&list.id).copied().unwrap_or(0);
+        }
+        Ok(lists)
+    }
+
+    fn get_reminder_lists_without_counts(&self) -> Result<Vec<ReminderList>> {
+        unsafe {
+            let calendars: *mut Object =
+                msg_send![self.event_store, calendarsForEntityType: ENTITY_TYPE_REMINDER];
+            let count: usize = msg_send![calendars, count];
+            debug_log!("Debug: Found {} calendars", count);
+
+            let mut lists = Vec::new();
+            for i in 0..count {
+                let calendar: *mut Object = msg_send![calendars, objectAtIndex: i];
+                let id = to_string(msg_send![calendar, calendarIdentifier]).unwrap_or_default();
+                let title = to_string(msg_send![calendar, title]).unwrap_or_default();
+
+                // Get color (simplified - generating a color based on index)
+                let color = format!(
+                    "#{:02x}{:02x}{:02x}",
+                    (i * 60 + 100) % 255,
+                    (i * 120 + 50) % 255,
+                    (i * 180 + 150) % 255
+                );
+
+                lists.push(ReminderList {
+                    reminder_count: 0,
+                    id,
+                    title,
+                    color,
+                });
+            }
+            Ok(lists)
+        }
+    }
+
+    fn get_reminders_for_list(&self, list_id: &str) -> Result<Vec<Reminder>> {
+        let calendar = self.calendar(list_id)?;
+        let calendars: *mut Object = unsafe { msg_send![class!(NSArray), arrayWithObject: calendar] };
+        match self.fetch(calendars) {
+            Ok(reminders) => Ok(reminders.into_iter().map(|(reminder, _)| reminder).collect()),
+            Err(e) => {
+                debug_log!("Debug: {}, asking Reminders.app instead", e);
+                let title = unsafe { to_string(msg_send![calendar, title]) }.unwrap_or_default();
+                self.fetch_with_applescript(&title)
+            }
+        }
+    }
+
+    /// The list new reminders go to when none is given.
+    fn default_list_id(&self) -> Option<String> {
+        unsafe {
+            let calendar: *mut Object = msg_send![self.event_store, defaultCalendarForNewReminders];
+            if calendar.is_null() {
+                return None;
+            }
+            to_string(msg_send![calendar, calendarIdentifier])
+        }
+    }
+
+    fn create_reminder(&self, new_reminder: &NewReminder) -> Result<Reminder> {
+        let calendar = self.calendar(&new_reminder.list_id)?;
+        debug_log!("Debug: Creating reminder '{}'", new_reminder.title);
+        unsafe {
+            let reminder: *mut Object =
+                msg_send![class!(EKReminder), reminderWithEventStore: self.event_store];
+            let _: () = msg_send![reminder, setCalendar: calendar];
+            let _: () = msg_send![reminder, setTitle: ns_string(&new_reminder.title)?];
+            if let Some(notes) = &new_reminder.notes {
+                let _: () = msg_send![reminder, setNotes: ns_string(notes)?];
+            }
+            if let Some(due_date) = &new_reminder.due_date {
+                let _: () = msg_send![reminder, setDueDateComponents: date_components(due_date)?];
+            }
+            let _: () = msg_send![reminder, setPriority: usize::from(new_reminder.priority)];
+            self.save(reminder)?;
+            Ok(read_reminder(reminder).0)
+        }
+    }
+
+    fn set_reminder_completed(&self, reminder_id: &str, completed: bool) -> Result<bool> {
+        let Some(reminder) = self.reminder(reminder_id)? else {
+            return Ok(false);
+        };
+        debug_log!("Debug: Setting completed={} on reminder {}", completed, reminder_id);
+        unsafe {
+            let _: () = msg_send![reminder, setCompleted: if completed { YES } else { NO }];
+        }
+        self.save(reminder)?;
+        Ok(true)
+    }
+
+    fn delete_reminder(&self, reminder_id: &str) -> Result<bool> {
+        let Some(reminder) = self.reminder(reminder_id)? else {
+            return Ok(false);
+        };
+        debug_log!("Debug: Deleting reminder {}", reminder_id);
+        let mut error: *mut Object = ptr::null_mut();
+        let removed: BOOL = unsafe {
+            msg_send![self.event_store, removeReminder: reminder commit: YES error: &mut error]
+        };
+        if removed == YES {
+            Ok(true)
+        } else {
+            Err(eyre!("Reminders error: {}", unsafe { describe(error) }))
+        }
+    }
+
+    /// Look up a reminder by id directly, instead of searching every list.
+    fn find_reminder(&self, reminder_id: &str) -> Result<Option<(Reminder, ReminderList)>> {
+        let Some(reminder) = self.reminder(reminder_id)? else {
+            return Ok(None);
+        };
+        let (reminder, list_id) = unsafe { read_reminder(reminder) };
+        let list = self
+            .get_reminder_lists()?
+            .into_iter()
+            .find(|list| list.id == list_id);
+        Ok(list.map(|list| (reminder, list)))
+    }
+
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        update: &ReminderUpdate,
+    ) -> Result<Option<Reminder>> {
+        let Some(reminder) = self.reminder(reminder_id)? else {
+            return Ok(None);
+        };
+        debug_log!("Debug: Updating reminder {}", reminder_id);
+        unsafe {
+            if let Some(title) = &update.title {
+                let _: () = msg_send![reminder, setTitle: ns_string(title)?];
+            }
+            match &update.notes {
+                Some(Some(notes)) => {
+                    let _: () = msg_send![reminder, setNotes: ns_string(notes)?];
+                }
+                Some(None) => {
+                    let _: () = msg_send![reminder, setNotes: ptr::null_mut::<Object>()];
+                }
+                None => {}
+            }
+            match &update.due_date {
+                Some(Some(due_date)) => {
+                    let _: () =
+                        msg_send![reminder, setDueDateComponents: date_components(due_date)?];
+                }
+                Some(None) => {
+                    let _: () = msg_send![reminder, setDueDateComponents: ptr::null_mut::<Object>()];
+                }
+                None => {}
+            }
+            if let Some(priority) = update.priority {
+                let _: () = msg_send![reminder, setPriority: usize::from(priority)];
+            }
+        }
+        self.save(reminder)?;
+        Ok(Some(unsafe { read_reminder(reminder) }.0))
+    }
+}
+
+impl EventKitManager {
+    /// Every reminder in `calendars`, or in all lists when it's nil, with the id of
+    /// its list.
+    fn fetch(&self, calendars: *mut Object) -> Result<Vec<(Reminder, String)>> {
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(Some(tx));
+        unsafe {
+            let predicate: *mut Object =
+                msg_send![self.event_store, predicateForRemindersInCalendars: calendars];
+            // EventKit calls back once, on a queue of its own
+            let completion = block::ConcreteBlock::new(move |reminders: *mut Object| {
+                let mut fetched = Vec::new();
+                if !reminders.is_null() {
+                    let count: usize = msg_send![reminders, count];
+                    for i in 0..count {
+                        let reminder: *mut Object = msg_send![reminders, objectAtIndex: i];
+                        fetched.push(read_reminder(reminder));
+                    }
+                }
+                if let Some(tx) = tx.lock().ok().and_then(|mut tx| tx.take()) {
+                    let _ = tx.send(fetched);
+                }
+            })
+            .copy();
+            let _: *mut Object = msg_send![self.event_store,
+                fetchRemindersMatchingPredicate: predicate
+                completion: completion
+            ];
+        }
+        rx.recv_timeout(FETCH_TIMEOUT)
+            .map_err(|_| eyre!("EventKit didn't return reminders in time"))
+    }
+
+    /// Read a list through Reminders.app, for when EventKit doesn't answer.
+    fn fetch_with_applescript(&self, list_title: &str) -> Result<Vec<Reminder>> {
+        let script = format!(
+            r#"tell application "Reminders"
+                set output to {{}}
+                repeat with aReminder in reminders in list {list}
+                    set dueString to missing value
+                    if allday due date of aReminder is not missing value then
+                        set dueString to my isoDate(allday due date of aReminder)
+                    else if due date of aReminder is not missing value then
+                        set reminderDue to due date of aReminder
+                        set dueString to my isoDate(reminderDue) & "T" & my pad(hours of reminderDue) & ":" & my pad(minutes of reminderDue) & ":00"
+                    end if
+                    set end of output to {{id of aReminder, name of aReminder, completed of aReminder, priority of aReminder, dueString, body of aReminder}}
+                end repeat
+                return output
+            end tell
+
+            on pad(n)
+                return text -2 thru -1 of ("0" & (n as string))
+            end pad
+
+            -- Local date as YYYY-MM-DD, built from components to stay locale independent
+            on isoDate(d)
+                return (year of d as string) & "-" & pad(month of d as integer) & "-" & pad(day of d)
+            end isoDate"#,
+            list = applescript::quote(list_title),
+        );
+        let output = std::process::Command::new("osascript")
+            .args(["-s", "s", "-e", &script])
+            .output()?;
+        if !output.status.success() {
+            return Err(eyre!(
+                "Reminders error: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let records = applescript::parse(&String::from_utf8_lossy(&output.stdout))?;
+        records
+            .items()
+            .ok_or_else(|| eyre!("Expected a list of reminders from Reminders.app"))?
+            .iter()
+            .map(applescript::reminder)
+            .collect()
+    }
+
+    fn calendar(&self, list_id: &str) -> Result<*mut Object> {
+        let calendar: *mut Object =
+            unsafe { msg_send![self.event_store, calendarWithIdentifier: ns_string(list_id)?] };
+        if calendar.is_null() {
+            Err(eyre!("List not found: {list_id}"))
+        } else {
+            Ok(calendar)
+        }
+    }
+
+    /// The `EKReminder` with `reminder_id`, if there is one.
+    fn reminder(&self, reminder_id: &str) -> Result<Option<*mut Object>> {
+        unsafe {
+            let item: *mut Object =
+                msg_send![self.event_store, calendarItemWithIdentifier: ns_string(reminder_id)?];
+            if item.is_null() {
+                return Ok(None);
+            }
+            let is_reminder: BOOL = msg_send![item, isKindOfClass: class!(EKReminder)];
+            Ok((is_reminder == YES).then_some(item))
+        }
+    }
+
+    fn save(&self, reminder: *mut Object) -> Result<()> {
+        let mut error: *mut Object = ptr::null_mut();
+        let saved: BOOL = unsafe {
+            msg_send![self.event_store, saveReminder: reminder commit: YES error: &mut error]
+        };
+        if saved == YES {
+            Ok(())
+        } else {
+            Err(eyre!("Reminders error: {}", unsafe { describe(error) }))
+        }
+    }
+}
+
+/// An `NSString` as a Rust string, or `None` for nil.
+unsafe fn to_string(string: *mut Object) -> Option<String> {
+    if string.is_null() {
+        return None;
+    }
+    unsafe {
+        let utf8: *const c_char = msg_send![string, UTF8String];
+        (!utf8.is_null()).then(|| CStr::from_ptr(utf8).to_string_lossy().into_owned())
+    }
+}
+
+unsafe fn ns_string(text: &str) -> Result<*mut Object> {
+    let text = CString::new(text).map_err(|_| eyre!("Text can't contain NUL characters"))?;
+    unsafe { Ok(msg_send![class!(NSString), stringWithUTF8String: text.as_ptr()]) }
+}
+
+unsafe fn describe(error: *mut Object) -> String {
+    if error.is_null() {
+        return "unknown error".to_string();
+    }
+    unsafe { to_string(msg_send![error, localizedDescription]) }
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+/// An `EKReminder`'s fields, and the id of its list.
+unsafe fn read_reminder(reminder: *mut Object) -> (Reminder, String) {
+    unsafe {
+        let completed: BOOL = msg_send![reminder, isCompleted];
+        let priority: usize = msg_send![reminder, priority];
+        let calendar: *mut Object = msg_send![reminder, calendar];
+        let list_id = if calendar.is_null() {
+            String::new()
+        } else {
+            to_string(msg_send![calendar, calendarIdentifier]).unwrap_or_default()
+        };
+        let reminder = Reminder {
+            id: to_string(msg_send![reminder, calendarItemIdentifier]).unwrap_or_default(),
+            title: to_string(msg_send![reminder, title]).unwrap_or_default(),
+            notes: to_string(msg_send![reminder, notes]).filter(|notes| !notes.is_empty()),
+            completed: completed == YES,
+            priority: u8::try_from(priority).unwrap_or(0),
+            due_date: due_date(msg_send![reminder, dueDateComponents]),
+        };
+        (reminder, list_id)
+    }
+}
+
+/// A due date's `NSDateComponents` as an ISO 8601 date for all-day reminders, or a
+/// local date-time.
+unsafe fn due_date(components: *mut Object) -> Option<String> {
+    if components.is_null() {
+        return None;
+    }
+    let (year, month, day, hour, minute): (isize, isize, isize, isize, isize) = unsafe {
+        (
+            msg_send![components, year],
+            msg_send![components, month],
+            msg_send![components, day],
+            msg_send![components, hour],
+            msg_send![components, minute],
+        )
+    };
+    let date = NaiveDate::from_ymd_opt(
+        i32::try_from(year).ok()?,
+        u32::try_from(month).ok()?,
+        u32::try_from(day).ok()?,
+    )?;
+    if hour == UNDEFINED_COMPONENT {
+        return Some(date.format("%Y-%m-%d").to_string());
+    }
+    let minute = if minute == UNDEFINED_COMPONENT { 0 } else { minute };
+    let date_time = date.and_hms_opt(u32::try_from(hour).ok()?, u32::try_from(minute).ok()?, 0)?;
+    Some(date_time.format("%Y-%m-%dT%H:%M:%S").to_string())
+}
+
+/// `NSDateComponents` for an ISO 8601 date (all day) or local date-time.
+unsafe fn date_components(iso: &str) -> Result<*mut Object> {
+    let (date, time) = match NaiveDateTime::parse_from_str(iso, "%Y-%m-%dT%H:%M:%S") {
+        Ok(date_time) => (date_time.date(), Some(date_time.time())),
+        Err(_) => (
+            NaiveDate::parse_from_str(iso, "%Y-%m-%d")
+                .map_err(|e| eyre!("Invalid due date '{iso}': {e}"))?,
+            None,
+        ),
+    };
+    unsafe {
+        let components: *mut Object = msg_send![class!(NSDateComponents), new];
+        let _: () = msg_send![components, setYear: date.year() as isize];
+        let _: () = msg_send![components, setMonth: date.month() as isize];
+        let _: () = msg_send![components, setDay: date.day() as isize];
+        // Leaving the time unset makes an all-day reminder
+        if let Some(time) = time {
+            let _: () = msg_send![components, setHour: time.hour() as isize];
+            let _: () = msg_send![components, setMinute: time.minute() as isize];
+        }
+        Ok(components)
+    }
+}
+
+impl Drop for EventKitManager {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![self.event_store, release];
+        }
+    }
+}
+
+// Make it thread-safe
+unsafe impl Send for EventKitManager {}
+unsafe impl Sync for EventKitManager {}
@@ -0,0 +1,361 @@
+//! Reminders kept in a JSON file, for platforms without EventKit.
+//!
+//! Every call reads the file and every change writes it back under an exclusive lock,
+//! so several `rem` processes (the TUI, `rem watch`, `rem serve`) can share it. Ids are
+//! assigned once and never reused, like EventKit's.
+
+use std::{
+    fs::{File, OpenOptions},
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{Result, eyre::eyre};
+use futures::future::{self, BoxFuture};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    NewReminder, PermissionStatus, Reminder, ReminderList, ReminderSource, ReminderUpdate,
+};
+use crate::config::get_data_dir;
+
+/// Reminders.app's default blue.
+const DEFAULT_LIST_COLOR: &str = "#007AFF";
+
+/// `reminders.json` in the data directory.
+pub fn default_path() -> PathBuf {
+    get_data_dir().join("reminders.json")
+}
+
+pub struct LocalSource {
+    path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Store {
+    next_id: u64,
+    lists: Vec<StoredList>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredList {
+    id: String,
+    title: String,
+    color: String,
+    reminders: Vec<Reminder>,
+}
+
+impl Default for Store {
+    /// A fresh store has one empty list to add reminders to.
+    fn default() -> Self {
+        Self {
+            next_id: 2,
+            lists: vec![StoredList {
+                id: "list-1".to_string(),
+                title: "Reminders".to_string(),
+                color: DEFAULT_LIST_COLOR.to_string(),
+                reminders: Vec::new(),
+            }],
+        }
+    }
+}
+
+impl Store {
+    fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| eyre!("Unreadable reminders file {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write then rename, so a crash can't leave a truncated file behind
+        let temp = path.with_extension("json.tmp");
+        std::fs::write(&temp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    fn take_id(&mut self, prefix: &str) -> String {
+        let id = format!("{prefix}-{}", self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    fn reminder_mut(&mut self, reminder_id: &str) -> Option<&mut Reminder> {
+        self.lists
+            .iter_mut()
+            .flat_map(|list| list.reminders.iter_mut())
+            .find(|reminder| reminder.id == reminder_id)
+    }
+}
+
+impl StoredList {
+    fn summary(&self) -> ReminderList {
+        ReminderList {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            color: self.color.clone(),
+            reminder_count: self.reminders.len(),
+        }
+    }
+}
+
+impl LocalSource {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Load the store, let `change` edit it, and save it if `change` reports a change.
+    /// Another process's change can't land in between and be lost.
+    fn modify<T>(&self, change: impl FnOnce(&mut Store) -> Result<(T, bool)>) -> Result<T> {
+        let _lock = self.lock()?;
+        let mut store = Store::load(&self.path)?;
+        let (result, changed) = change(&mut store)?;
+        if changed {
+            store.save(&self.path)?;
+        }
+        Ok(result)
+    }
+
+    /// Take the writers' lock, held until the returned file is dropped. It is on a file
+    /// of its own because saving replaces the store. Readers don't take it: the rename
+    /// in `Store::save` means they see either the old store or the new one.
+    fn lock(&self) -> Result<File> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.path.with_extension("json.lock"))?;
+        file.lock()?;
+        Ok(file)
+    }
+}
+
+impl ReminderSource for LocalSource {
+    /// The file is the user's own, so there is nothing to ask for.
+    fn check_permission_status(&self) -> PermissionStatus {
+        PermissionStatus::Authorized
+    }
+
+    fn request_permission(&self) -> BoxFuture<'_, Result<bool>> {
+        Box::pin(future::ready(Ok(true)))
+    }
+
+    fn get_reminder_lists(&self) -> Result<Vec<ReminderList>> {
+        let store = Store::load(&self.path)?;
+        Ok(store.lists.iter().map(StoredList::summary).collect())
+    }
+
+    fn get_reminders_for_list(&self, list_id: &str) -> Result<Vec<Reminder>> {
+        let store = Store::load(&self.path)?;
+        Ok(store
+            .lists
+            .into_iter()
+            .find(|list| list.id == list_id)
+            .map(|list| list.reminders)
+            .unwrap_or_default())
+    }
+
+    /// The first list.
+    fn default_list_id(&self) -> Option<String> {
+        let store = Store::load(&self.path).ok()?;
+        store.lists.first().map(|list| list.id.clone())
+    }
+
+    fn create_reminder(&self, new_reminder: &NewReminder) -> Result<Reminder> {
+        self.modify(|store| {
+            let id = store.take_id("reminder");
+            let list = store
+                .lists
+                .iter_mut()
+                .find(|list| list.id == new_reminder.list_id)
+                .ok_or_else(|| eyre!("List not found: {}", new_reminder.list_id))?;
+            let reminder = Reminder {
+                id,
+                title: new_reminder.title.clone(),
+                notes: new_reminder.notes.clone(),
+                completed: false,
+                priority: new_reminder.priority,
+                due_date: new_reminder.due_date.clone(),
+            };
+            list.reminders.push(reminder.clone());
+            Ok((reminder, true))
+        })
+    }
+
+    fn set_reminder_completed(&self, reminder_id: &str, completed: bool) -> Result<bool> {
+        self.modify(|store| {
+            let found = match store.reminder_mut(reminder_id) {
+                Some(reminder) => {
+                    reminder.completed = completed;
+                    true
+                }
+                None => false,
+            };
+            Ok((found, found))
+        })
+    }
+
+    fn delete_reminder(&self, reminder_id: &str) -> Result<bool> {
+        self.modify(|store| {
+            let mut found = false;
+            for list in &mut store.lists {
+                let before = list.reminders.len();
+                list.reminders.retain(|reminder| reminder.id != reminder_id);
+                found |= list.reminders.len() != before;
+            }
+            Ok((found, found))
+        })
+    }
+
+    fn update_reminder(
+        &self,
+        reminder_id: &str,
+        update: &ReminderUpdate,
+    ) -> Result<Option<Reminder>> {
+        self.modify(|store| {
+            let Some(reminder) = store.reminder_mut(reminder_id) else {
+                return Ok((None, false));
+            };
+            if let Some(title) = &update.title {
+                reminder.title = title.clone();
+            }
+            if let Some(notes) = &update.notes {
+                reminder.notes = notes.clone();
+            }
+            if let Some(due_date) = &update.due_date {
+                reminder.due_date = due_date.clone();
+            }
+            if let Some(priority) = update.priority {
+                reminder.priority = priority;
+            }
+            Ok((Some(reminder.clone()), true))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn temp_source(name: &str) -> LocalSource {
+        let dir = std::env::temp_dir().join(format!("rem-local-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        LocalSource::new(dir.join("reminders.json"))
+    }
+
+    fn new_reminder(list_id: &str, title: &str) -> NewReminder {
+        NewReminder {
+            title: title.to_string(),
+            notes: None,
+            due_date: None,
+            list_id: list_id.to_string(),
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn test_local_source_round_trips_changes() {
+        let source = temp_source("round-trip");
+        let mut store = Store::default();
+        store.lists.push(StoredList {
+            id: "list-work".to_string(),
+            title: "Work".to_string(),
+            color: "#34C759".to_string(),
+            reminders: Vec::new(),
+        });
+        store.save(&source.path).unwrap();
+        let inbox = source.default_list_id().unwrap();
+        let work = "list-work";
+
+        let milk = source
+            .create_reminder(&new_reminder(&inbox, "Buy milk"))
+            .unwrap();
+        let report = source
+            .create_reminder(&new_reminder(work, "Report"))
+            .unwrap();
+        assert_ne!(milk.id, report.id);
+
+        assert!(source.set_reminder_completed(&milk.id, true).unwrap());
+        let update = ReminderUpdate {
+            due_date: Some(Some("2025-01-31".to_string())),
+            priority: Some(1),
+            ..ReminderUpdate::default()
+        };
+        let updated = source
+            .update_reminder(&report.id, &update)
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.due_date.as_deref(), Some("2025-01-31"));
+
+        let (found, list) = source.find_reminder(&report.id).unwrap().unwrap();
+        assert_eq!((found.priority, list.title.as_str()), (1, "Work"));
+        assert!(source.get_reminders_for_list(&inbox).unwrap()[0].completed);
+
+        assert!(source.delete_reminder(&milk.id).unwrap());
+        assert!(!source.delete_reminder(&milk.id).unwrap());
+        let counts: Vec<_> = source
+            .get_reminder_lists()
+            .unwrap()
+            .into_iter()
+            .map(|list| (list.title, list.reminder_count))
+            .collect();
+        assert_eq!(
+            counts,
+            [("Reminders".to_string(), 0), ("Work".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_local_source_keeps_concurrent_changes() {
+        let path = temp_source("concurrent").path;
+        let writers: Vec<_> = (0..4)
+            .map(|writer| {
+                // A source of its own per thread, like separate processes
+                let source = LocalSource::new(path.clone());
+                std::thread::spawn(move || {
+                    for i in 0..10 {
+                        source
+                            .create_reminder(&new_reminder("list-1", &format!("{writer}-{i}")))
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        let source = LocalSource::new(path);
+        assert_eq!(source.get_reminders_for_list("list-1").unwrap().len(), 40);
+    }
+
+    #[test]
+    fn test_local_source_reports_missing_reminders() {
+        let source = temp_source("missing");
+        assert!(!source.set_reminder_completed("nope", true).unwrap());
+        assert_eq!(
+            source
+                .update_reminder("nope", &ReminderUpdate::default())
+                .unwrap()
+                .map(|r| r.id),
+            None
+        );
+        // Nothing was changed, so nothing was written
+        assert!(!source.path.exists());
+        assert!(
+            source
+                .create_reminder(&new_reminder("nope", "Lost"))
+                .is_err()
+        );
+    }
+}